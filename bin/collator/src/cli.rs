@@ -42,6 +42,11 @@ pub struct Cli {
     #[clap(name = "enable-evm-rpc", long)]
     pub enable_evm_rpc: bool,
 
+    /// Enable the dApp staking indexer, which persists tier assignments & rewards of each era
+    /// into the offchain DB, and the related RPC methods (disabled by default).
+    #[clap(name = "enable-dapp-staking-indexer", long)]
+    pub enable_dapp_staking_indexer: bool,
+
     /// Relaychain arguments
     #[clap(raw = true)]
     pub relaychain_args: Vec<String>,
@@ -836,6 +836,7 @@ pub fn run() -> Result<()> {
                     #[cfg(feature = "evm-tracing")]
                     evm_tracing_config: evm_tracing_config,
                     enable_evm_rpc: cli.enable_evm_rpc,
+                    enable_dapp_staking_indexer: cli.enable_dapp_staking_indexer,
                     proposer_block_size_limit: cli.proposer_block_size_limit,
                     proposer_soft_deadline_percent: cli.proposer_soft_deadline_percent
                 };
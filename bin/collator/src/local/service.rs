@@ -368,13 +368,17 @@ pub fn start_node(
                 enable_evm_rpc: true, // enable EVM RPC for dev node by default
             };
 
-            crate::rpc::create_full(
+            let mut io = crate::rpc::create_full(
                 deps,
                 subscription,
                 pubsub_notification_sinks.clone(),
                 rpc_config.clone(),
             )
-            .map_err::<ServiceError, _>(Into::into)
+            .map_err::<ServiceError, _>(Into::into)?;
+            crate::rpc::create_xvm_rpc(&mut io, client.clone())
+                .map_err::<ServiceError, _>(Into::into)?;
+
+            Ok(io)
         })
     };
 
@@ -651,8 +655,13 @@ pub fn start_node(config: Configuration) -> Result<TaskManager, ServiceError> {
                 enable_evm_rpc: true, // enable EVM RPC for dev node by default
             };
 
-            crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
-                .map_err::<ServiceError, _>(Into::into)
+            let mut io =
+                crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
+                    .map_err::<ServiceError, _>(Into::into)?;
+            crate::rpc::create_xvm_rpc(&mut io, client.clone())
+                .map_err::<ServiceError, _>(Into::into)?;
+
+            Ok(io)
         })
     };
 
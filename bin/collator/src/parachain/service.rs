@@ -33,6 +33,7 @@ use cumulus_primitives_core::ParaId;
 use cumulus_relay_chain_inprocess_interface::build_inprocess_relay_chain;
 use cumulus_relay_chain_interface::{RelayChainInterface, RelayChainResult};
 use cumulus_relay_chain_minimal_node::build_minimal_relay_chain_node_with_rpc;
+use dapp_staking_v3_rpc::{DappStakingIndex, DappStakingIndexApiServer};
 use fc_consensus::FrontierBlockImport;
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
 use futures::{lock::Mutex, StreamExt};
@@ -296,6 +297,11 @@ async fn start_node_impl<RuntimeApi, Executor, BIQ, BIC>(
     collator_options: CollatorOptions,
     id: ParaId,
     additional_config: AdditionalConfig,
+    runtime_rpc: Option<
+        crate::rpc::RuntimeRpcBuilder<
+            TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<Executor>>,
+        >,
+    >,
     build_import_queue: BIQ,
     build_consensus: BIC,
 ) -> sc_service::error::Result<(
@@ -318,7 +324,9 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + cumulus_primitives_core::CollectCollationInfo<Block>
-        + AuraApi<Block, AuraId>,
+        + AuraApi<Block, AuraId>
+        + dapp_staking_v3_runtime_api::DappStakingApi<Block>
+        + inflation_runtime_api::InflationApi<Block>,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
     BIQ: FnOnce(
@@ -469,6 +477,28 @@ where
         prometheus_registry.clone(),
     ));
 
+    // Optional dApp staking indexer task.
+    // Persists the tier assignment & rewards of each finished era into the offchain DB.
+    let dapp_staking_index_storage = if additional_config.enable_dapp_staking_indexer {
+        let offchain_storage =
+            sc_client_api::Backend::offchain_storage(&*backend).ok_or_else(|| {
+                sc_service::Error::Other(
+                    "Offchain storage is required by the dApp staking indexer.".into(),
+                )
+            })?;
+        task_manager.spawn_handle().spawn(
+            "dapp-staking-indexer",
+            Some("dapp-staking"),
+            dapp_staking_v3_rpc::indexer::run_tier_rewards_indexer(
+                client.clone(),
+                offchain_storage.clone(),
+            ),
+        );
+        Some(offchain_storage)
+    } else {
+        None
+    };
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -494,8 +524,18 @@ where
                 enable_evm_rpc: additional_config.enable_evm_rpc,
             };
 
-            crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
-                .map_err(Into::into)
+            let mut io =
+                crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
+                    .map_err(sc_service::Error::Application)?;
+            if let Some(runtime_rpc) = runtime_rpc {
+                runtime_rpc(&mut io, client.clone()).map_err(sc_service::Error::Application)?;
+            }
+            if let Some(offchain_storage) = dapp_staking_index_storage.clone() {
+                io.merge(DappStakingIndex::new(offchain_storage).into_rpc())
+                    .map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+            }
+
+            Ok(io)
         })
     };
 
@@ -592,6 +632,9 @@ pub struct AdditionalConfig {
     /// Whether EVM RPC be enabled
     pub enable_evm_rpc: bool,
 
+    /// Whether dApp staking tier rewards indexer be enabled
+    pub enable_dapp_staking_indexer: bool,
+
     /// Maxium allowed block size limit to propose
     pub proposer_block_size_limit: usize,
 
@@ -610,6 +653,11 @@ async fn start_node_impl<RuntimeApi, Executor, BIQ, BIC>(
     collator_options: CollatorOptions,
     id: ParaId,
     additional_config: AdditionalConfig,
+    runtime_rpc: Option<
+        crate::rpc::RuntimeRpcBuilder<
+            TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<Executor>>,
+        >,
+    >,
     build_import_queue: BIQ,
     build_consensus: BIC,
 ) -> sc_service::error::Result<(
@@ -634,7 +682,9 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + cumulus_primitives_core::CollectCollationInfo<Block>
-        + AuraApi<Block, AuraId>,
+        + AuraApi<Block, AuraId>
+        + dapp_staking_v3_runtime_api::DappStakingApi<Block>
+        + inflation_runtime_api::InflationApi<Block>,
     sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_api::StateBackend<BlakeTwo256>,
     Executor: sc_executor::NativeExecutionDispatch + 'static,
     BIQ: FnOnce(
@@ -806,6 +856,28 @@ where
         prometheus_registry.clone(),
     ));
 
+    // Optional dApp staking indexer task.
+    // Persists the tier assignment & rewards of each finished era into the offchain DB.
+    let dapp_staking_index_storage = if additional_config.enable_dapp_staking_indexer {
+        let offchain_storage =
+            sc_client_api::Backend::offchain_storage(&*backend).ok_or_else(|| {
+                sc_service::Error::Other(
+                    "Offchain storage is required by the dApp staking indexer.".into(),
+                )
+            })?;
+        task_manager.spawn_handle().spawn(
+            "dapp-staking-indexer",
+            Some("dapp-staking"),
+            dapp_staking_v3_rpc::indexer::run_tier_rewards_indexer(
+                client.clone(),
+                offchain_storage.clone(),
+            ),
+        );
+        Some(offchain_storage)
+    } else {
+        None
+    };
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -836,13 +908,22 @@ where
                 enable_evm_rpc: additional_config.enable_evm_rpc,
             };
 
-            crate::rpc::create_full(
+            let mut io = crate::rpc::create_full(
                 deps,
                 subscription,
                 pubsub_notification_sinks.clone(),
                 rpc_config.clone(),
             )
-            .map_err(Into::into)
+            .map_err(sc_service::Error::Application)?;
+            if let Some(runtime_rpc) = runtime_rpc {
+                runtime_rpc(&mut io, client.clone()).map_err(sc_service::Error::Application)?;
+            }
+            if let Some(offchain_storage) = dapp_staking_index_storage.clone() {
+                io.merge(DappStakingIndex::new(offchain_storage).into_rpc())
+                    .map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+            }
+
+            Ok(io)
         })
     };
 
@@ -1029,6 +1110,7 @@ pub async fn start_astar_node(
         collator_options,
         id,
         additional_config.clone(),
+        None,
         |client,
          block_import,
          config,
@@ -1164,6 +1246,7 @@ pub async fn start_astar_node(
         collator_options,
         id,
         additional_config.clone(),
+        None,
         |client,
          block_import,
          config,
@@ -1299,6 +1382,7 @@ pub async fn start_shiden_node(
         collator_options,
         id,
         additional_config.clone(),
+        None,
         build_import_queue,
         |client,
          block_import,
@@ -1463,6 +1547,7 @@ pub async fn start_shiden_node(
         collator_options,
         id,
         additional_config.clone(),
+        None,
         build_import_queue,
         |client,
          block_import,
@@ -1626,6 +1711,7 @@ pub async fn start_shibuya_node(
         collator_options,
         id,
         additional_config.clone(),
+        Some(crate::rpc::create_xvm_rpc),
         |client,
          block_import,
          config,
@@ -1759,6 +1845,7 @@ pub async fn start_shibuya_node(
         collator_options,
         id,
         additional_config.clone(),
+        Some(crate::rpc::create_xvm_rpc),
         |client,
          block_import,
          config,
@@ -54,10 +54,16 @@ use moonbeam_rpc_trace::{Trace, TraceServer};
 use moonbeam_rpc_txpool::{TxPool as MoonbeamTxPool, TxPoolServer};
 
 use astar_primitives::*;
+use dapp_staking_v3_rpc::{DappStaking, DappStakingApiServer};
+use inflation_rpc::{Inflation, InflationApiServer};
+use xvm_rpc::{Xvm, XvmApiServer};
 
 #[cfg(feature = "evm-tracing")]
 pub mod tracing;
 
+#[cfg(test)]
+mod tests;
+
 #[cfg(feature = "evm-tracing")]
 #[derive(Clone)]
 pub struct EvmTracingConfig {
@@ -167,6 +173,8 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
         + AuraApi<Block, AuraId>
+        + dapp_staking_v3_runtime_api::DappStakingApi<Block>
+        + inflation_runtime_api::InflationApi<Block>
         + moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
@@ -231,7 +239,9 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
-        + AuraApi<Block, AuraId>,
+        + AuraApi<Block, AuraId>
+        + dapp_staking_v3_runtime_api::DappStakingApi<Block>
+        + inflation_runtime_api::InflationApi<Block>,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -268,7 +278,9 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
-        + AuraApi<Block, AuraId>,
+        + AuraApi<Block, AuraId>
+        + dapp_staking_v3_runtime_api::DappStakingApi<Block>
+        + inflation_runtime_api::InflationApi<Block>,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -296,6 +308,7 @@ where
     io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone(), deny_unsafe).into_rpc())?;
+    create_astar_rpc(&mut io, client.clone())?;
 
     if !enable_evm_rpc {
         return Ok(io);
@@ -362,3 +375,37 @@ where
 
     Ok(io)
 }
+
+/// Instantiate the RPC extensions of the Astar pallets, shared by all the runtimes.
+pub fn create_astar_rpc<C>(
+    io: &mut RpcModule<()>,
+    client: Arc<C>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: dapp_staking_v3_runtime_api::DappStakingApi<Block>
+        + inflation_runtime_api::InflationApi<Block>,
+{
+    io.merge(DappStaking::new(client.clone()).into_rpc())?;
+    io.merge(Inflation::new(client).into_rpc())?;
+
+    Ok(())
+}
+
+/// Instantiate the RPC extensions which are only supported by some of the runtimes.
+pub type RuntimeRpcBuilder<C> =
+    fn(&mut RpcModule<()>, Arc<C>) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+/// Instantiate the XVM RPC extension, for the runtimes which support XVM.
+pub fn create_xvm_rpc<C>(
+    io: &mut RpcModule<()>,
+    client: Arc<C>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: xvm_runtime_api::XvmApi<Block>,
+{
+    io.merge(Xvm::new(client).into_rpc())?;
+
+    Ok(())
+}
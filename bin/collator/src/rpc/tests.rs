@@ -0,0 +1,264 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::local::{development_config, Executor, RuntimeApi};
+
+use astar_primitives::dapp_staking::{DAppId, EraNumber, SmartContract, TierId};
+use dapp_staking_v3_rpc::{AccountLedgerInfo, BTreeMap, DAppTierRewardsInfo};
+use frame_support::{
+    assert_ok,
+    traits::{OnFinalize, OnInitialize},
+};
+use jsonrpsee::{core::Error as RpcError, types::error::CallError};
+use local_runtime::{DappStaking, Inflation, RuntimeOrigin, System, AST};
+use pallet_dapp_staking_v3::{ForcingType, Safeguard};
+use sc_executor::NativeElseWasmExecutor;
+use sp_core::{storage::Storage, H256};
+use sp_keyring::AccountKeyring;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::BuildStorage;
+use sp_state_machine::{Backend as _, IterArgs};
+use substrate_test_client::{client::LocalCallExecutor, Backend as TestBackend, GenesisInit};
+
+type TestClient = substrate_test_client::client::Client<
+    TestBackend<Block>,
+    LocalCallExecutor<Block, TestBackend<Block>, NativeElseWasmExecutor<Executor>>,
+    Block,
+    RuntimeApi,
+>;
+
+type TestClientBuilder = substrate_test_client::TestClientBuilder<
+    Block,
+    LocalCallExecutor<Block, TestBackend<Block>, NativeElseWasmExecutor<Executor>>,
+    TestBackend<Block>,
+    DappStakingGenesis,
+>;
+
+const LOCK_AMOUNT: Balance = 1_000 * AST;
+const STAKE_AMOUNT: Balance = 600 * AST;
+const CONTRACT: [u8; 32] = [7; 32];
+/// Era for which the staked dApp is assigned a tier & stakers are rewarded.
+const REWARDED_ERA: EraNumber = 2;
+
+/// Development genesis, with a staked dApp and the first build&earn era already finished.
+#[derive(Default)]
+struct DappStakingGenesis;
+
+impl GenesisInit for DappStakingGenesis {
+    fn genesis_storage(&self) -> Storage {
+        let storage = development_config()
+            .build_storage()
+            .expect("Development chain spec is valid.");
+
+        let mut ext = sp_io::TestExternalities::new(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            Safeguard::<local_runtime::Runtime>::put(false);
+
+            let staker = AccountKeyring::Alice.to_account_id();
+            let smart_contract = SmartContract::Wasm(CONTRACT.into());
+            assert_ok!(DappStaking::register(
+                RuntimeOrigin::root(),
+                staker.clone(),
+                smart_contract.clone()
+            ));
+            assert_ok!(DappStaking::lock(
+                RuntimeOrigin::signed(staker.clone()),
+                LOCK_AMOUNT
+            ));
+            assert_ok!(DappStaking::stake(
+                RuntimeOrigin::signed(staker),
+                smart_contract,
+                STAKE_AMOUNT
+            ));
+
+            // End the voting subperiod, followed by the first build&earn era.
+            assert_ok!(DappStaking::force(
+                RuntimeOrigin::root(),
+                ForcingType::Subperiod
+            ));
+            run_to_next_block();
+            assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+            run_to_next_block();
+        });
+
+        let top = ext
+            .as_backend()
+            .pairs(IterArgs::default())
+            .expect("In-memory backend can be iterated.")
+            .map(|pair| pair.expect("In-memory backend can be iterated."))
+            .collect();
+
+        Storage {
+            top,
+            children_default: Default::default(),
+        }
+    }
+}
+
+fn run_to_next_block() {
+    let block_number = System::block_number();
+    DappStaking::on_finalize(block_number);
+    Inflation::on_finalize(block_number);
+
+    System::set_block_number(block_number + 1);
+    Inflation::on_initialize(block_number + 1);
+    DappStaking::on_initialize(block_number + 1);
+}
+
+/// RPC module with the Astar & XVM extensions, backed by a local runtime client.
+fn astar_rpc() -> RpcModule<()> {
+    let (client, _) = TestClientBuilder::new()
+        .build_with_native_executor::<RuntimeApi, _>(None::<NativeElseWasmExecutor<Executor>>);
+    let client: Arc<TestClient> = Arc::new(client);
+
+    let mut io = RpcModule::new(());
+    create_astar_rpc(&mut io, client.clone()).expect("RPC extensions must not conflict.");
+    create_xvm_rpc(&mut io, client).expect("RPC extensions must not conflict.");
+    io
+}
+
+#[test]
+fn astar_rpc_merges_all_extensions() {
+    let io = astar_rpc();
+
+    for method in [
+        "dappStaking_accountLedger",
+        "dappStaking_pendingStakerRewards",
+        "dappStaking_tierAssignmentForEra",
+        "xvm_estimate",
+        "inflation_configuration",
+    ] {
+        assert!(
+            io.method_names().any(|name| name == method),
+            "{method} must be registered."
+        );
+    }
+}
+
+#[tokio::test]
+async fn account_ledger_works() {
+    let io = astar_rpc();
+
+    let ledger: AccountLedgerInfo = io
+        .call(
+            "dappStaking_accountLedger",
+            (AccountKeyring::Alice.to_account_id(), None::<H256>),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ledger.locked, LOCK_AMOUNT);
+    assert_eq!(ledger.contract_stake_count, 1);
+    assert_eq!(
+        ledger.staked_future.map(|stake| stake.voting),
+        Some(STAKE_AMOUNT)
+    );
+
+    let ledger: AccountLedgerInfo = io
+        .call(
+            "dappStaking_accountLedger",
+            (AccountKeyring::Bob.to_account_id(), None::<H256>),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ledger.locked, 0);
+    assert_eq!(ledger.contract_stake_count, 0);
+}
+
+#[tokio::test]
+async fn pending_staker_rewards_works() {
+    let io = astar_rpc();
+
+    let rewards: NumberOrHex = io
+        .call(
+            "dappStaking_pendingStakerRewards",
+            (AccountKeyring::Alice.to_account_id(), None::<H256>),
+        )
+        .await
+        .unwrap();
+    assert!(!rewards.into_u256().is_zero());
+
+    let rewards: NumberOrHex = io
+        .call(
+            "dappStaking_pendingStakerRewards",
+            (AccountKeyring::Bob.to_account_id(), None::<H256>),
+        )
+        .await
+        .unwrap();
+    assert!(rewards.into_u256().is_zero());
+}
+
+#[tokio::test]
+async fn tier_assignment_for_era_works() {
+    let io = astar_rpc();
+    let expected_tiers = BTreeMap::<DAppId, TierId>::from([(0, 0)]);
+
+    let tiers: Option<BTreeMap<DAppId, TierId>> = io
+        .call(
+            "dappStaking_tierAssignmentForEra",
+            (REWARDED_ERA, None::<H256>),
+        )
+        .await
+        .unwrap();
+    assert_eq!(tiers, Some(expected_tiers.clone()));
+
+    let tier_rewards: Option<DAppTierRewardsInfo> = io
+        .call(
+            "dappStaking_tierRewardsForEra",
+            (REWARDED_ERA, None::<H256>),
+        )
+        .await
+        .unwrap();
+    let tier_rewards = tier_rewards.expect("Era has finished, tier rewards must exist.");
+    assert_eq!(tier_rewards.dapps, expected_tiers);
+    assert_eq!(tier_rewards.period, 1);
+    assert!(tier_rewards.rewards[0] > 0);
+
+    // Current era hasn't finished yet, so there is no assignment for it.
+    let tiers: Option<BTreeMap<DAppId, TierId>> = io
+        .call(
+            "dappStaking_tierAssignmentForEra",
+            (REWARDED_ERA + 1, None::<H256>),
+        )
+        .await
+        .unwrap();
+    assert_eq!(tiers, None);
+}
+
+#[tokio::test]
+async fn runtime_error_is_mapped() {
+    let io = astar_rpc();
+
+    let result: Result<AccountLedgerInfo, _> = io
+        .call(
+            "dappStaking_accountLedger",
+            (
+                AccountKeyring::Alice.to_account_id(),
+                Some(H256::repeat_byte(0x42)),
+            ),
+        )
+        .await;
+    match result {
+        Err(RpcError::Call(CallError::Custom(error))) => {
+            assert_eq!(error.code(), 1);
+            assert_eq!(error.message(), "Unable to query dApp staking state.");
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
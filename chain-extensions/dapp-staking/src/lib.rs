@@ -0,0 +1,273 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::{
+    dapp_staking::{EraNumber, PeriodNumber},
+    Balance,
+};
+pub use dapp_staking_chain_extension_types::Command::{self, *};
+use dapp_staking_chain_extension_types::{Outcome, ProtocolStateInfo, Subperiod, LOG_TARGET};
+use frame_support::{dispatch::DispatchResultWithPostInfo, traits::Get, DefaultNoBound};
+use frame_system::RawOrigin;
+use pallet_contracts::chain_extension::{
+    BufInBufOutState, ChainExtension, ChargedAmount, Environment, Ext, InitState, RetVal,
+};
+use pallet_dapp_staking_v3::{ActiveProtocolState, Ledger, StakerInfo, WeightInfo};
+use parity_scale_codec::Encode;
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+
+type DSWeight<T> = <T as pallet_dapp_staking_v3::Config>::WeightInfo;
+
+/// dApp staking chain extension.
+///
+/// All of the state changing calls are executed on behalf of the calling contract.
+#[derive(DefaultNoBound)]
+pub struct DappStakingExtension<T>(PhantomData<T>);
+
+impl<T> ChainExtension<T> for DappStakingExtension<T>
+where
+    T: pallet_contracts::Config + pallet_dapp_staking_v3::Config,
+{
+    fn call<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+    where
+        E: Ext<T = T>,
+    {
+        let mut env = env.buf_in_buf_out();
+        match env.func_id().try_into().map_err(|_| {
+            DispatchError::Other("Unsupported func id in dApp Staking Chain Extension")
+        })? {
+            ProtocolState => {
+                env.charge_weight(T::DbWeight::get().reads(1))?;
+
+                let protocol_state = ActiveProtocolState::<T>::get();
+                let subperiod = match protocol_state.subperiod() {
+                    pallet_dapp_staking_v3::Subperiod::Voting => Subperiod::Voting,
+                    pallet_dapp_staking_v3::Subperiod::BuildAndEarn => Subperiod::BuildAndEarn,
+                };
+
+                ProtocolStateInfo {
+                    era: protocol_state.era,
+                    period: protocol_state.period_number(),
+                    subperiod,
+                    next_era_start: protocol_state.next_era_start,
+                    maintenance: protocol_state.maintenance,
+                }
+                .using_encoded(|r| env.write(r, false, None))?;
+            }
+            LockedAmount => {
+                env.charge_weight(T::DbWeight::get().reads(1))?;
+
+                let account: T::AccountId = env.read_as()?;
+
+                Ledger::<T>::get(&account)
+                    .active_locked_amount()
+                    .using_encoded(|r| env.write(r, false, None))?;
+            }
+            StakedAmount => {
+                env.charge_weight(T::DbWeight::get().reads(2))?;
+
+                let (account, smart_contract): (T::AccountId, T::SmartContract) = env.read_as()?;
+
+                // Stakes from the past periods are no longer relevant.
+                let current_period: PeriodNumber = ActiveProtocolState::<T>::get().period_number();
+                let staked_amount: Balance = match StakerInfo::<T>::get(&account, &smart_contract) {
+                    Some(staking_info) if staking_info.period_number() == current_period => {
+                        staking_info.total_staked_amount()
+                    }
+                    _ => 0,
+                };
+
+                staked_amount.using_encoded(|r| env.write(r, false, None))?;
+            }
+            Lock => {
+                let charged_weight = env.charge_weight(
+                    DSWeight::<T>::lock_new_account().max(DSWeight::<T>::lock_existing_account()),
+                )?;
+
+                let amount: Balance = env.read_as()?;
+
+                log::trace!(target: LOG_TARGET, "lock: raw arguments: amount: {:?}", amount);
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::lock(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    amount,
+                );
+                return handle_result(&mut env, charged_weight, call_result);
+            }
+            Unlock => {
+                let charged_weight = env.charge_weight(DSWeight::<T>::unlock())?;
+
+                let amount: Balance = env.read_as()?;
+
+                log::trace!(target: LOG_TARGET, "unlock: raw arguments: amount: {:?}", amount);
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::unlock(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    amount,
+                );
+                return handle_result(
+                    &mut env,
+                    charged_weight,
+                    call_result.map(Into::into).map_err(Into::into),
+                );
+            }
+            ClaimUnlocked => {
+                let charged_weight = env.charge_weight(DSWeight::<T>::claim_unlocked(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))?;
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_unlocked(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                );
+                return handle_result(&mut env, charged_weight, call_result);
+            }
+            Stake => {
+                let charged_weight = env.charge_weight(DSWeight::<T>::stake())?;
+
+                let (smart_contract, amount): (T::SmartContract, Balance) = env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "stake: raw arguments: smart_contract: {:?}, amount: {:?}",
+                    smart_contract,
+                    amount
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::stake(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    smart_contract,
+                    amount,
+                );
+                return handle_result(
+                    &mut env,
+                    charged_weight,
+                    call_result.map(Into::into).map_err(Into::into),
+                );
+            }
+            Unstake => {
+                let charged_weight = env.charge_weight(DSWeight::<T>::unstake())?;
+
+                let (smart_contract, amount): (T::SmartContract, Balance) = env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "unstake: raw arguments: smart_contract: {:?}, amount: {:?}",
+                    smart_contract,
+                    amount
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::unstake(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    smart_contract,
+                    amount,
+                );
+                return handle_result(
+                    &mut env,
+                    charged_weight,
+                    call_result.map(Into::into).map_err(Into::into),
+                );
+            }
+            ClaimStakerRewards => {
+                let max_span_length = T::EraRewardSpanLength::get();
+                let charged_weight = env.charge_weight(
+                    DSWeight::<T>::claim_staker_rewards_ongoing_period(max_span_length)
+                        .max(DSWeight::<T>::claim_staker_rewards_past_period(
+                            max_span_length,
+                        ))
+                        .saturating_add(DSWeight::<T>::restake_reward(
+                            T::MaxNumberOfStakedContracts::get(),
+                        )),
+                )?;
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_staker_rewards(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                );
+                return handle_result(&mut env, charged_weight, call_result);
+            }
+            ClaimBonusReward => {
+                let charged_weight =
+                    env.charge_weight(DSWeight::<T>::claim_bonus_reward().saturating_add(
+                        DSWeight::<T>::restake_reward(T::MaxNumberOfStakedContracts::get()),
+                    ))?;
+
+                let smart_contract: T::SmartContract = env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "claim_bonus_reward: raw arguments: smart_contract: {:?}",
+                    smart_contract
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_bonus_reward(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    smart_contract,
+                );
+                return handle_result(&mut env, charged_weight, call_result);
+            }
+            ClaimDappReward => {
+                let charged_weight = env.charge_weight(DSWeight::<T>::claim_dapp_reward())?;
+
+                let (smart_contract, era): (T::SmartContract, EraNumber) = env.read_as()?;
+
+                log::trace!(
+                    target: LOG_TARGET,
+                    "claim_dapp_reward: raw arguments: smart_contract: {:?}, era: {:?}",
+                    smart_contract,
+                    era
+                );
+
+                let call_result = pallet_dapp_staking_v3::Pallet::<T>::claim_dapp_reward(
+                    RawOrigin::Signed(env.ext().address().clone()).into(),
+                    smart_contract,
+                    era,
+                );
+                return handle_result(
+                    &mut env,
+                    charged_weight,
+                    call_result.map(Into::into).map_err(Into::into),
+                );
+            }
+        }
+
+        Ok(RetVal::Converging(Outcome::Success as u32))
+    }
+}
+
+/// Refunds the unused weight (if actual weight is known), and maps the call result into the chain extension return value.
+fn handle_result<E: Ext>(
+    env: &mut Environment<E, BufInBufOutState>,
+    charged_weight: ChargedAmount,
+    call_result: DispatchResultWithPostInfo,
+) -> Result<RetVal, DispatchError> {
+    let (outcome, actual_weight) = match call_result {
+        Ok(post_info) => (Outcome::Success, post_info.actual_weight),
+        Err(err) => {
+            log::trace!(target: LOG_TARGET, "err: {:?}", err);
+            (Outcome::from(err.error), err.post_info.actual_weight)
+        }
+    };
+
+    if let Some(actual_weight) = actual_weight {
+        env.adjust_weight(charged_weight, actual_weight);
+    }
+
+    Ok(RetVal::Converging(outcome as u32))
+}
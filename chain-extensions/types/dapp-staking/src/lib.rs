@@ -0,0 +1,162 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{DispatchError, ModuleError};
+
+pub const LOG_TARGET: &str = "pallet-chain-extension-dapp-staking";
+
+#[repr(u16)]
+#[derive(TryFromPrimitive, IntoPrimitive, Decode, Encode)]
+pub enum Command {
+    /// Get the ongoing protocol state
+    ProtocolState = 0,
+    /// Get the amount locked by the account
+    LockedAmount = 1,
+    /// Get the amount staked by the account on the smart contract, during the ongoing period
+    StakedAmount = 2,
+    /// Lock the amount of the contract's own funds
+    Lock = 3,
+    /// Start the unlocking process for the amount
+    Unlock = 4,
+    /// Claim all of the fully unlocked chunks
+    ClaimUnlocked = 5,
+    /// Stake the amount on the smart contract
+    Stake = 6,
+    /// Unstake the amount from the smart contract
+    Unstake = 7,
+    /// Claim staker rewards
+    ClaimStakerRewards = 8,
+    /// Claim bonus reward for the smart contract
+    ClaimBonusReward = 9,
+    /// Claim dApp reward for the smart contract & era
+    ClaimDappReward = 10,
+}
+
+/// Subperiod of the dApp staking protocol.
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Subperiod {
+    /// Stakers vote for dApps, no rewards are earned.
+    Voting = 0,
+    /// dApps and stakers earn rewards.
+    BuildAndEarn = 1,
+}
+
+/// Ongoing state of the dApp staking protocol.
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ProtocolStateInfo {
+    /// Ongoing era number.
+    pub era: u32,
+    /// Ongoing period number.
+    pub period: u32,
+    /// Ongoing subperiod.
+    pub subperiod: Subperiod,
+    /// Block number at which the next era should start.
+    pub next_era_start: u32,
+    /// `true` if protocol is in maintenance mode, `false` otherwise.
+    pub maintenance: bool,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Outcome {
+    /// Success
+    Success = 0,
+    /// Pallet is disabled/in maintenance mode.
+    Disabled = 1,
+    /// Specified smart contract does not exist in dApp staking.
+    ContractNotFound = 2,
+    /// Performing locking or staking with 0 amount.
+    ZeroAmount = 3,
+    /// Total locked amount for staker is below minimum threshold.
+    LockedAmountBelowThreshold = 4,
+    /// Account is not allowed to participate in dApp staking due to some external reason.
+    AccountNotAvailableForDappStaking = 5,
+    /// Cannot add additional unlocking chunks due to capacity limit.
+    TooManyUnlockingChunks = 6,
+    /// Remaining stake prevents entire balance of starting the unlocking process.
+    RemainingStakePreventsFullUnlock = 7,
+    /// There are no eligible unlocked chunks to claim.
+    NoUnlockedChunksToClaim = 8,
+    /// The amount being staked is too large compared to what's available for staking.
+    UnavailableStakeFunds = 9,
+    /// There are unclaimed rewards remaining from past eras or periods.
+    UnclaimedRewards = 10,
+    /// Total staked amount on contract is below the minimum required value.
+    InsufficientStakeAmount = 11,
+    /// Stake operation is rejected since period ends in the next era.
+    PeriodEndsInNextEra = 12,
+    /// Unstaking is rejected since the period in which past stake was active has passed.
+    UnstakeFromPastPeriod = 13,
+    /// Unstake amount is greater than the staked amount.
+    UnstakeAmountTooLarge = 14,
+    /// Account has no staking information for the contract.
+    NoStakingInfo = 15,
+    /// Rewards are no longer claimable since they are too old.
+    RewardExpired = 16,
+    /// There are no claimable rewards.
+    NoClaimableRewards = 17,
+    /// Account has no eligible stake amount for bonus reward.
+    NotEligibleForBonusReward = 18,
+    /// Claim era is invalid - it must be in history, and rewards must exist for it.
+    InvalidClaimEra = 19,
+    /// No dApp tier info exists for the specified era.
+    NoDAppTierInfo = 20,
+    /// There are too many contract stake entries for the account.
+    TooManyStakedContracts = 21,
+    /// Unknown error
+    RuntimeError = 99,
+}
+
+impl From<DispatchError> for Outcome {
+    fn from(input: DispatchError) -> Self {
+        let error_text = match input {
+            DispatchError::Module(ModuleError { message, .. }) => message,
+            _ => Some("No module error Info"),
+        };
+        match error_text {
+            Some("Disabled") => Outcome::Disabled,
+            Some("ContractNotFound") => Outcome::ContractNotFound,
+            Some("ZeroAmount") => Outcome::ZeroAmount,
+            Some("LockedAmountBelowThreshold") => Outcome::LockedAmountBelowThreshold,
+            Some("AccountNotAvailableForDappStaking") => Outcome::AccountNotAvailableForDappStaking,
+            Some("TooManyUnlockingChunks") => Outcome::TooManyUnlockingChunks,
+            Some("RemainingStakePreventsFullUnlock") => Outcome::RemainingStakePreventsFullUnlock,
+            Some("NoUnlockedChunksToClaim") => Outcome::NoUnlockedChunksToClaim,
+            Some("UnavailableStakeFunds") => Outcome::UnavailableStakeFunds,
+            Some("UnclaimedRewards") => Outcome::UnclaimedRewards,
+            Some("InsufficientStakeAmount") => Outcome::InsufficientStakeAmount,
+            Some("PeriodEndsInNextEra") => Outcome::PeriodEndsInNextEra,
+            Some("UnstakeFromPastPeriod") => Outcome::UnstakeFromPastPeriod,
+            Some("UnstakeAmountTooLarge") => Outcome::UnstakeAmountTooLarge,
+            Some("NoStakingInfo") => Outcome::NoStakingInfo,
+            Some("RewardExpired") => Outcome::RewardExpired,
+            Some("NoClaimableRewards") => Outcome::NoClaimableRewards,
+            Some("NotEligibleForBonusReward") => Outcome::NotEligibleForBonusReward,
+            Some("InvalidClaimEra") => Outcome::InvalidClaimEra,
+            Some("NoDAppTierInfo") => Outcome::NoDAppTierInfo,
+            Some("TooManyStakedContracts") => Outcome::TooManyStakedContracts,
+            _ => Outcome::RuntimeError,
+        }
+    }
+}
@@ -49,6 +49,10 @@ impl From<FailureReason> for XvmExecutionResult {
             FailureReason::Error(FailureError::ReentranceDenied) => 130,
             FailureReason::Error(FailureError::VmError(_)) => 131,
             FailureReason::Error(FailureError::OutOfGas) => 132,
+            FailureReason::Error(FailureError::CallDepthLimitReached) => 133,
+            FailureReason::Error(FailureError::ValueOverflow) => 134,
+            FailureReason::Error(FailureError::InsufficientBalance) => 135,
+            FailureReason::Error(FailureError::ValueBelowExistentialDeposit) => 136,
         };
         Self::Err(error_code)
     }
@@ -74,3 +78,13 @@ pub struct XvmCallArgs {
     /// Value to transfer
     pub value: Balance,
 }
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug)]
+pub struct XvmQueryArgs {
+    /// virtual machine identifier
+    pub vm_id: u8,
+    /// Call destination (e.g. address)
+    pub to: Vec<u8>,
+    /// Encoded call params
+    pub input: Vec<u8>,
+}
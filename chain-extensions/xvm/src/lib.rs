@@ -23,21 +23,23 @@ use alloc::format;
 
 use astar_primitives::{
     evm::UnifiedAddressMapper,
-    xvm::{CallFailure, Context, FailureError, VmId, XvmCall},
+    xvm::{CallFailure, CallResult, Context, FailureError, VmId, XvmCall},
 };
 use frame_support::weights::Weight;
 use frame_system::RawOrigin;
 use pallet_contracts::chain_extension::{
-    ChainExtension, Environment, Ext, InitState, RetVal, ReturnFlags,
+    BufInBufOutState, ChainExtension, ChargedAmount, Environment, Ext, InitState, RetVal,
+    ReturnFlags,
 };
 use pallet_unified_accounts::WeightInfo;
 use parity_scale_codec::Encode;
 use sp_runtime::DispatchError;
 use sp_std::marker::PhantomData;
-use xvm_chain_extension_types::{XvmCallArgs, XvmExecutionResult};
+use xvm_chain_extension_types::{XvmCallArgs, XvmExecutionResult, XvmQueryArgs};
 
 enum XvmFuncId {
     Call,
+    Query,
 }
 
 impl TryFrom<u16> for XvmFuncId {
@@ -46,6 +48,7 @@ impl TryFrom<u16> for XvmFuncId {
     fn try_from(value: u16) -> Result<Self, Self::Error> {
         match value {
             1 => Ok(XvmFuncId::Call),
+            2 => Ok(XvmFuncId::Query),
             _ => Err(DispatchError::Other(
                 "Unsupported func id in Xvm chain extension",
             )),
@@ -143,44 +146,84 @@ where
                 };
                 let call_result = XC::call(xvm_context, vm_id, source, to, input, value, None);
 
-                let used_weight = match call_result {
-                    Ok(ref info) => info.used_weight,
-                    Err(ref err) => err.used_weight,
+                handle_result(&mut env, charged_weight, actual_weight, call_result)
+            }
+
+            XvmFuncId::Query => {
+                // We need to immediately charge for the worst case scenario. Gas equals Weight in pallet-contracts context.
+                let weight_limit = env.ext().gas_meter().gas_left();
+                let charged_weight = env.charge_weight(weight_limit)?;
+
+                let XvmQueryArgs { vm_id, to, input } = env.read_as_unbounded(env.in_len())?;
+
+                // No value is transferred on query, hence no need to claim the default evm address.
+                let source = env.ext().address().clone();
+
+                let xvm_context = Context {
+                    source_vm_id: VmId::Wasm,
+                    weight_limit,
                 };
-                actual_weight.saturating_accrue(used_weight);
-                env.adjust_weight(charged_weight, actual_weight);
-
-                match call_result {
-                    Ok(info) => {
-                        log::trace!(
-                            target: "xvm-extension::xvm_call",
-                            "info: {:?}", info
-                        );
-
-                        let buffer: sp_std::vec::Vec<_> = info.output.encode();
-                        env.write(&buffer, false, None)?;
-                        Ok(RetVal::Converging(XvmExecutionResult::Ok.into()))
+                let vm_id = {
+                    match TryInto::<VmId>::try_into(vm_id) {
+                        Ok(id) => id,
+                        Err(err) => {
+                            return Ok(RetVal::Diverging {
+                                flags: ReturnFlags::REVERT,
+                                data: format!("{:?}", err).into(),
+                            });
+                        }
                     }
+                };
+                let query_result = XC::query(xvm_context, vm_id, source, to, input);
 
-                    Err(err) => {
-                        log::trace!(
-                            target: "xvm-extension::xvm_call",
-                            "err: {:?}", err
-                        );
-
-                        // `Diverging` is used instead of `Err` to make sure the control
-                        // doesn't return to the caller.
-                        Ok(RetVal::Diverging {
-                            flags: ReturnFlags::REVERT,
-                            data: format!("{:?}", err).into(),
-                        })
-                    }
-                }
+                handle_result(&mut env, charged_weight, Weight::zero(), query_result)
             }
         }
     }
 }
 
+/// Refunds the unused weight, and maps the XVM call result into the chain extension return value.
+fn handle_result<E: Ext>(
+    env: &mut Environment<E, BufInBufOutState>,
+    charged_weight: ChargedAmount,
+    mut actual_weight: Weight,
+    call_result: CallResult,
+) -> Result<RetVal, DispatchError> {
+    let used_weight = match call_result {
+        Ok(ref info) => info.used_weight,
+        Err(ref err) => err.used_weight,
+    };
+    actual_weight.saturating_accrue(used_weight);
+    env.adjust_weight(charged_weight, actual_weight);
+
+    match call_result {
+        Ok(info) => {
+            log::trace!(
+                target: "xvm-extension::xvm_call",
+                "info: {:?}", info
+            );
+
+            let buffer: sp_std::vec::Vec<_> = info.output.encode();
+            env.write(&buffer, false, None)?;
+            Ok(RetVal::Converging(XvmExecutionResult::Ok.into()))
+        }
+
+        Err(err) => {
+            log::trace!(
+                target: "xvm-extension::xvm_call",
+                "err: {:?}", err
+            );
+
+            // `Diverging` is used instead of `Err` to make sure the control
+            // doesn't return to the caller.
+            Ok(RetVal::Diverging {
+                flags: ReturnFlags::REVERT,
+                data: format!("{:?}", err).into(),
+            })
+        }
+    }
+}
+
 fn out_of_gas_err(actual_weight: Weight) -> Result<RetVal, DispatchError> {
     Ok(RetVal::Diverging {
         flags: ReturnFlags::REVERT,
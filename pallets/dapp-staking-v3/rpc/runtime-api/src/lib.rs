@@ -18,9 +18,14 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use astar_primitives::dapp_staking::{DAppId, EraNumber, PeriodNumber, TierId};
-use astar_primitives::BlockNumber;
+pub use astar_primitives::dapp_staking::{
+    AccountLedgerInfo, DAppTierRewardsInfo, ProtocolStateInfo, StakeAmountInfo, SubperiodInfo,
+    UnlockingChunkInfo,
+};
+use astar_primitives::dapp_staking::{DAppId, EraNumber, PeriodNumber, SmartContract, TierId};
+use astar_primitives::{AccountId, Balance, BlockNumber};
 pub use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 
@@ -43,5 +48,41 @@ sp_api::decl_runtime_apis! {
 
         /// Get dApp tier assignment for the given dApp.
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, TierId>;
+
+        /// Get dApp tier assignment for the specified past era, if it's still available.
+        #[api_version(2)]
+        fn get_dapp_tier_assignment_for_era(era: EraNumber) -> Option<BTreeMap<DAppId, TierId>>;
+
+        /// Get dApp tier assignment & tier rewards for the specified past era, if it's still available.
+        #[api_version(2)]
+        fn get_dapp_tier_rewards_for_era(era: EraNumber) -> Option<DAppTierRewardsInfo>;
+
+        /// General information about the dApp staking protocol state.
+        #[api_version(2)]
+        fn protocol_state() -> ProtocolStateInfo;
+
+        /// Locked & staked information about the specified account.
+        #[api_version(2)]
+        fn account_ledger(account: AccountId) -> AccountLedgerInfo;
+
+        /// Total staker rewards the specified account can claim at the moment.
+        #[api_version(2)]
+        fn pending_staker_rewards(account: AccountId) -> Balance;
+
+        /// Staker rewards the specified account can claim at the moment, per era.
+        #[api_version(2)]
+        fn staker_rewards_per_era(account: AccountId) -> Vec<(EraNumber, Balance)>;
+
+        /// Bonus reward the specified account can claim at the moment for the specified smart contract.
+        #[api_version(2)]
+        fn bonus_reward(account: AccountId, smart_contract: SmartContract<AccountId>) -> Balance;
+
+        /// Bonus rewards the specified account can claim at the moment, per smart contract.
+        #[api_version(2)]
+        fn pending_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)>;
+
+        /// Unclaimed dApp rewards for the specified smart contract, per era.
+        #[api_version(2)]
+        fn pending_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)>;
     }
 }
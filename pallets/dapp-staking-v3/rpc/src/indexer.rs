@@ -0,0 +1,282 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Node-side indexer of the dApp tier assignments & tier rewards.
+//!
+//! `DAppTiers` entries are pruned from the runtime storage once they expire, and dApps are removed
+//! from the assignment as soon as they claim their reward. To keep the full history available, the indexer
+//! follows the finalized blocks, and persists the tier assignment & rewards of each finished era
+//! into the node's offchain DB. The indexed entries can later be queried via the `DappStakingIndexApi` RPC.
+
+use std::{iter, ops::RangeInclusive, sync::Arc};
+
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::BlockchainEvents;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+use astar_primitives::dapp_staking::EraNumber;
+use dapp_staking_v3_runtime_api::{DAppTierRewardsInfo, DappStakingApi as DappStakingRuntimeApi};
+
+#[cfg(test)]
+mod tests;
+
+/// Log target of the indexer.
+const LOG_TARGET: &str = "dapp-staking-indexer";
+
+/// Offchain DB key under which the last indexed era is kept.
+const LAST_INDEXED_ERA_KEY: &[u8] = b"dapp-staking-v3::last-indexed-era";
+
+/// Offchain DB key prefix under which the tier rewards of each era are kept.
+const TIER_REWARDS_KEY_PREFIX: &[u8] = b"dapp-staking-v3::tier-rewards::";
+
+/// Offchain DB key of the indexed tier rewards for the specified era.
+pub fn tier_rewards_key(era: EraNumber) -> Vec<u8> {
+    let mut key = TIER_REWARDS_KEY_PREFIX.to_vec();
+    key.extend(era.encode());
+    key
+}
+
+/// Last era processed by the indexer, if any.
+///
+/// Tier rewards of all the eras up to it have been indexed, unless they were no longer available.
+pub fn last_indexed_era<S: OffchainStorage>(storage: &S) -> Option<EraNumber> {
+    storage
+        .get(STORAGE_PREFIX, LAST_INDEXED_ERA_KEY)
+        .and_then(|raw| EraNumber::decode(&mut &raw[..]).ok())
+}
+
+/// Indexed tier rewards for the specified era, if available.
+pub fn indexed_tier_rewards<S: OffchainStorage>(
+    storage: &S,
+    era: EraNumber,
+) -> Option<DAppTierRewardsInfo> {
+    storage
+        .get(STORAGE_PREFIX, &tier_rewards_key(era))
+        .and_then(|raw| DAppTierRewardsInfo::decode(&mut &raw[..]).ok())
+}
+
+/// Eras which should be indexed at a block in `current_era`, given the last indexed era & the era of the
+/// previously processed block.
+///
+/// All the finished eras after the last indexed one are returned, which also covers the eras missed while
+/// the node was down. In case nothing has been indexed yet, only the era which has just finished is returned,
+/// i.e. eras which ended before the indexer started for the first time are not indexed.
+pub fn eras_to_index(
+    last_indexed_era: Option<EraNumber>,
+    previous_era: Option<EraNumber>,
+    current_era: EraNumber,
+) -> RangeInclusive<EraNumber> {
+    let first_era = match (last_indexed_era, previous_era) {
+        (Some(last_indexed_era), _) => last_indexed_era.saturating_add(1),
+        (None, Some(previous_era)) if previous_era < current_era => previous_era,
+        // Nothing to index, the range is empty.
+        _ => current_era,
+    };
+
+    first_era.max(1)..=current_era.saturating_sub(1)
+}
+
+/// Follows the finalized blocks & persists the tier assignment & rewards of each finished era into the offchain DB.
+///
+/// An era is indexed at the first finalized block of the next era, when its tier assignment is complete and
+/// no reward has been claimed yet. Eras missed while the node was down are backfilled at the first finalized
+/// block processed after the restart, as long as their tier rewards are still in storage. Such entries don't
+/// include the dApps which claimed their reward in the meantime.
+pub async fn run_tier_rewards_indexer<Block, C, S>(client: Arc<C>, mut storage: S)
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>
+        + BlockchainEvents<Block>
+        + HeaderBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: DappStakingRuntimeApi<Block>,
+    S: OffchainStorage,
+{
+    let mut finality_notifications = client.finality_notification_stream();
+    // Era of the last processed finalized block.
+    let mut last_finalized_era = None;
+
+    while let Some(notification) = finality_notifications.next().await {
+        // Implicitly finalized blocks are processed first, from the oldest one.
+        for at_hash in notification
+            .tree_route
+            .iter()
+            .chain(iter::once(&notification.hash))
+        {
+            let Some(era) = era_at::<Block, _>(&*client, *at_hash) else {
+                last_finalized_era = None;
+                continue;
+            };
+            let previous_era = last_finalized_era.or_else(|| {
+                client
+                    .header(*at_hash)
+                    .ok()
+                    .flatten()
+                    .and_then(|header| era_at::<Block, _>(&*client, *header.parent_hash()))
+            });
+            last_finalized_era = Some(era);
+
+            for finished_era in eras_to_index(last_indexed_era(&storage), previous_era, era) {
+                // Eras are indexed in order, the remaining ones are retried at the next finalized block.
+                if !index_tier_rewards::<Block, _, _>(
+                    &*client,
+                    &mut storage,
+                    *at_hash,
+                    finished_era,
+                ) {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Current era at the specified block, if it can be read.
+fn era_at<Block, C>(client: &C, at_hash: Block::Hash) -> Option<EraNumber>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: DappStakingRuntimeApi<Block>,
+{
+    match client.runtime_api().protocol_state(at_hash) {
+        Ok(protocol_state) => Some(protocol_state.era),
+        Err(err) => {
+            log::warn!(
+                target: LOG_TARGET,
+                "Failed to read protocol state at {:?}: {:?}",
+                at_hash,
+                err
+            );
+            None
+        }
+    }
+}
+
+/// Persist the tier rewards of the finished `era`, read at the specified block.
+///
+/// Returns `false` if the tier rewards couldn't be read, in which case the era isn't marked as indexed.
+fn index_tier_rewards<Block, C, S>(
+    client: &C,
+    storage: &mut S,
+    at_hash: Block::Hash,
+    era: EraNumber,
+) -> bool
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: DappStakingRuntimeApi<Block>,
+    S: OffchainStorage,
+{
+    match client
+        .runtime_api()
+        .get_dapp_tier_rewards_for_era(at_hash, era)
+    {
+        Ok(tier_rewards) => {
+            store_tier_rewards(storage, era, tier_rewards);
+            true
+        }
+        Err(err) => {
+            log::warn!(
+                target: LOG_TARGET,
+                "Failed to read tier rewards for era {} at {:?}: {:?}",
+                era,
+                at_hash,
+                err
+            );
+            false
+        }
+    }
+}
+
+/// Persist the tier rewards of the specified `era`, and mark the era as indexed.
+///
+/// Eras whose tier rewards are no longer available are marked as indexed as well, since they can't be recovered.
+pub fn store_tier_rewards<S: OffchainStorage>(
+    storage: &mut S,
+    era: EraNumber,
+    tier_rewards: Option<DAppTierRewardsInfo>,
+) {
+    match tier_rewards {
+        Some(tier_rewards) => {
+            storage.set(
+                STORAGE_PREFIX,
+                &tier_rewards_key(era),
+                &tier_rewards.encode(),
+            );
+            log::debug!(target: LOG_TARGET, "Indexed tier rewards for era {}.", era);
+        }
+        None => {
+            log::warn!(
+                target: LOG_TARGET,
+                "Tier rewards for era {} are no longer available, skipping it.",
+                era
+            );
+        }
+    }
+
+    if last_indexed_era(storage).map_or(true, |last_era| last_era < era) {
+        storage.set(STORAGE_PREFIX, LAST_INDEXED_ERA_KEY, &era.encode());
+    }
+}
+
+/// dApp staking indexer RPC methods.
+#[rpc(client, server)]
+pub trait DappStakingIndexApi {
+    /// Last era processed by the node's indexer, if any.
+    #[method(name = "dappStaking_lastIndexedEra")]
+    fn last_indexed_era(&self) -> RpcResult<Option<EraNumber>>;
+
+    /// dApp tier assignment & tier rewards for the specified era, as indexed by the node.
+    #[method(name = "dappStaking_indexedTierRewardsForEra")]
+    fn indexed_tier_rewards_for_era(
+        &self,
+        era: EraNumber,
+    ) -> RpcResult<Option<DAppTierRewardsInfo>>;
+}
+
+/// Provides RPC methods to query the tier rewards indexed by the node.
+pub struct DappStakingIndex<S> {
+    storage: S,
+}
+
+impl<S> DappStakingIndex<S> {
+    /// Creates a new instance of the dApp staking indexer RPC helper.
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+}
+
+impl<S: OffchainStorage + 'static> DappStakingIndexApiServer for DappStakingIndex<S> {
+    fn last_indexed_era(&self) -> RpcResult<Option<EraNumber>> {
+        Ok(last_indexed_era(&self.storage))
+    }
+
+    fn indexed_tier_rewards_for_era(
+        &self,
+        era: EraNumber,
+    ) -> RpcResult<Option<DAppTierRewardsInfo>> {
+        Ok(indexed_tier_rewards(&self.storage, era))
+    }
+}
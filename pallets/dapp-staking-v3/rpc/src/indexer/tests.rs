@@ -0,0 +1,88 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use dapp_staking_v3_runtime_api::BTreeMap;
+use sp_core::offchain::storage::InMemOffchainStorage;
+
+fn tier_rewards(period: u32) -> DAppTierRewardsInfo {
+    DAppTierRewardsInfo {
+        dapps: BTreeMap::from([(0, 0), (1, 2)]),
+        rewards: vec![1_000, 100, 10],
+        period,
+    }
+}
+
+#[test]
+fn eras_to_index_without_era_change_is_empty() {
+    // Nothing indexed yet, era hasn't changed since the previous block.
+    assert!(eras_to_index(None, Some(5), 5).is_empty());
+    // Nothing indexed yet, previous block era is unknown.
+    assert!(eras_to_index(None, None, 5).is_empty());
+    // Last finished era has already been indexed.
+    assert!(eras_to_index(Some(4), Some(5), 5).is_empty());
+    assert!(eras_to_index(Some(4), None, 5).is_empty());
+}
+
+#[test]
+fn eras_to_index_at_era_boundary() {
+    // First block of the next era, the finished era is indexed.
+    assert_eq!(eras_to_index(None, Some(4), 5), 4..=4);
+    assert_eq!(eras_to_index(Some(3), Some(4), 5), 4..=4);
+    assert_eq!(eras_to_index(Some(3), None, 5), 4..=4);
+}
+
+#[test]
+fn eras_to_index_backfills_missed_eras() {
+    // Node was down during eras 5, 6 & 7, all of them are indexed after the restart.
+    assert_eq!(eras_to_index(Some(4), Some(8), 8), 5..=7);
+    assert_eq!(eras_to_index(Some(4), None, 8), 5..=7);
+    // Same, but the restart happened exactly at the era boundary.
+    assert_eq!(eras_to_index(Some(4), Some(7), 8), 5..=7);
+}
+
+#[test]
+fn eras_to_index_never_includes_genesis_era() {
+    assert!(eras_to_index(None, Some(0), 1).is_empty());
+    assert!(eras_to_index(None, Some(0), 0).is_empty());
+    assert_eq!(eras_to_index(None, Some(0), 2), 1..=1);
+}
+
+#[test]
+fn store_tier_rewards_is_ok() {
+    let mut storage = InMemOffchainStorage::default();
+    assert!(last_indexed_era(&storage).is_none());
+    assert!(indexed_tier_rewards(&storage, 3).is_none());
+
+    // Store tier rewards, expect the era to be marked as indexed.
+    store_tier_rewards(&mut storage, 3, Some(tier_rewards(1)));
+    assert_eq!(last_indexed_era(&storage), Some(3));
+    assert_eq!(indexed_tier_rewards(&storage, 3), Some(tier_rewards(1)));
+
+    // Unavailable tier rewards are skipped, but the era is still marked as indexed.
+    store_tier_rewards(&mut storage, 4, None);
+    assert_eq!(last_indexed_era(&storage), Some(4));
+    assert!(indexed_tier_rewards(&storage, 4).is_none());
+
+    // Re-indexing an older era doesn't move the last indexed era back.
+    store_tier_rewards(&mut storage, 2, Some(tier_rewards(1)));
+    assert_eq!(last_indexed_era(&storage), Some(4));
+    assert_eq!(indexed_tier_rewards(&storage, 2), Some(tier_rewards(1)));
+    assert_eq!(indexed_tier_rewards(&storage, 3), Some(tier_rewards(1)));
+}
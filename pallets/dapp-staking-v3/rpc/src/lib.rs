@@ -0,0 +1,332 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the dApp staking v3 pallet.
+//!
+//! All of the calls are thin wrappers around the `DappStakingApi` runtime API,
+//! executed against the best block in case no block hash is provided.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+use astar_primitives::{
+    dapp_staking::{DAppId, EraNumber, PeriodNumber, SmartContract, TierId},
+    AccountId, Balance, BlockNumber,
+};
+pub use dapp_staking_v3_runtime_api::{
+    AccountLedgerInfo, BTreeMap, DAppTierRewardsInfo, DappStakingApi as DappStakingRuntimeApi,
+    ProtocolStateInfo, StakeAmountInfo, SubperiodInfo, UnlockingChunkInfo,
+};
+
+pub mod indexer;
+pub use indexer::{DappStakingIndex, DappStakingIndexApiServer};
+
+/// dApp staking RPC methods.
+#[rpc(client, server)]
+pub trait DappStakingApi<BlockHash> {
+    /// How many periods are there in one cycle.
+    #[method(name = "dappStaking_periodsPerCycle")]
+    fn periods_per_cycle(&self, at: Option<BlockHash>) -> RpcResult<PeriodNumber>;
+
+    /// For how many standard era lengths does the voting subperiod last.
+    #[method(name = "dappStaking_erasPerVotingSubperiod")]
+    fn eras_per_voting_subperiod(&self, at: Option<BlockHash>) -> RpcResult<EraNumber>;
+
+    /// How many standard eras are there in the build&earn subperiod.
+    #[method(name = "dappStaking_erasPerBuildAndEarnSubperiod")]
+    fn eras_per_build_and_earn_subperiod(&self, at: Option<BlockHash>) -> RpcResult<EraNumber>;
+
+    /// How many blocks are there per standard era.
+    #[method(name = "dappStaking_blocksPerEra")]
+    fn blocks_per_era(&self, at: Option<BlockHash>) -> RpcResult<BlockNumber>;
+
+    /// General information about the dApp staking protocol state.
+    #[method(name = "dappStaking_protocolState")]
+    fn protocol_state(&self, at: Option<BlockHash>) -> RpcResult<ProtocolStateInfo>;
+
+    /// Locked & staked information about the specified account.
+    #[method(name = "dappStaking_accountLedger")]
+    fn account_ledger(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AccountLedgerInfo>;
+
+    /// Total staker rewards the specified account can claim at the moment.
+    #[method(name = "dappStaking_pendingStakerRewards")]
+    fn pending_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    /// Staker rewards the specified account can claim at the moment, per era.
+    #[method(name = "dappStaking_stakerRewardsPerEra")]
+    fn staker_rewards_per_era(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(EraNumber, NumberOrHex)>>;
+
+    /// Bonus reward the specified account can claim at the moment for the specified smart contract.
+    #[method(name = "dappStaking_bonusReward")]
+    fn bonus_reward(
+        &self,
+        account: AccountId,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+
+    /// Bonus rewards the specified account can claim at the moment, per smart contract.
+    #[method(name = "dappStaking_pendingBonusRewards")]
+    fn pending_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(SmartContract<AccountId>, NumberOrHex)>>;
+
+    /// Unclaimed dApp rewards for the specified smart contract, per era.
+    #[method(name = "dappStaking_pendingDAppRewards")]
+    fn pending_dapp_rewards(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(EraNumber, NumberOrHex)>>;
+
+    /// dApp tier assignment, based on the current stake amounts.
+    #[method(name = "dappStaking_tierAssignment")]
+    fn tier_assignment(&self, at: Option<BlockHash>) -> RpcResult<BTreeMap<DAppId, TierId>>;
+
+    /// dApp tier assignment for the specified past era, if it's still available.
+    #[method(name = "dappStaking_tierAssignmentForEra")]
+    fn tier_assignment_for_era(
+        &self,
+        era: EraNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<BTreeMap<DAppId, TierId>>>;
+
+    /// dApp tier assignment & tier rewards for the specified past era, if it's still available.
+    #[method(name = "dappStaking_tierRewardsForEra")]
+    fn tier_rewards_for_era(
+        &self,
+        era: EraNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DAppTierRewardsInfo>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Provides RPC methods to query dApp staking state.
+pub struct DappStaking<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> DappStaking<C, Block> {
+    /// Creates a new instance of the dApp staking RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts a runtime API call error into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query dApp staking state.",
+        Some(format!("{:?}", err)),
+    ))
+    .into()
+}
+
+impl<C, Block> DappStaking<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DappStakingRuntimeApi<Block>,
+{
+    /// Executes the provided runtime API call at the specified block, or at the best block if none is provided.
+    fn call_at<R, E: std::fmt::Debug>(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        call: impl FnOnce(&C::Api, <Block as BlockT>::Hash) -> Result<R, E>,
+    ) -> RpcResult<R> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        call(&*api, at_hash).map_err(runtime_error_into_rpc_err)
+    }
+}
+
+impl<C, Block> DappStakingApiServer<<Block as BlockT>::Hash> for DappStaking<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DappStakingRuntimeApi<Block>,
+{
+    fn periods_per_cycle(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<PeriodNumber> {
+        self.call_at(at, |api, at_hash| api.periods_per_cycle(at_hash))
+    }
+
+    fn eras_per_voting_subperiod(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<EraNumber> {
+        self.call_at(at, |api, at_hash| api.eras_per_voting_subperiod(at_hash))
+    }
+
+    fn eras_per_build_and_earn_subperiod(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<EraNumber> {
+        self.call_at(at, |api, at_hash| {
+            api.eras_per_build_and_earn_subperiod(at_hash)
+        })
+    }
+
+    fn blocks_per_era(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<BlockNumber> {
+        self.call_at(at, |api, at_hash| api.blocks_per_era(at_hash))
+    }
+
+    fn protocol_state(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<ProtocolStateInfo> {
+        self.call_at(at, |api, at_hash| api.protocol_state(at_hash))
+    }
+
+    fn account_ledger(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<AccountLedgerInfo> {
+        self.call_at(at, |api, at_hash| api.account_ledger(at_hash, account))
+    }
+
+    fn pending_staker_rewards(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        self.call_at(at, |api, at_hash| {
+            api.pending_staker_rewards(at_hash, account)
+        })
+        .map(Into::into)
+    }
+
+    fn staker_rewards_per_era(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(EraNumber, NumberOrHex)>> {
+        self.call_at(at, |api, at_hash| {
+            api.staker_rewards_per_era(at_hash, account)
+        })
+        .map(into_number_or_hex)
+    }
+
+    fn bonus_reward(
+        &self,
+        account: AccountId,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        self.call_at(at, |api, at_hash| {
+            api.bonus_reward(at_hash, account, smart_contract)
+        })
+        .map(Into::into)
+    }
+
+    fn pending_bonus_rewards(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(SmartContract<AccountId>, NumberOrHex)>> {
+        self.call_at(at, |api, at_hash| {
+            api.pending_bonus_rewards(at_hash, account)
+        })
+        .map(into_number_or_hex)
+    }
+
+    fn pending_dapp_rewards(
+        &self,
+        smart_contract: SmartContract<AccountId>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(EraNumber, NumberOrHex)>> {
+        self.call_at(at, |api, at_hash| {
+            api.pending_dapp_rewards(at_hash, smart_contract)
+        })
+        .map(into_number_or_hex)
+    }
+
+    fn tier_assignment(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BTreeMap<DAppId, TierId>> {
+        self.call_at(at, |api, at_hash| api.get_dapp_tier_assignment(at_hash))
+    }
+
+    fn tier_assignment_for_era(
+        &self,
+        era: EraNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<BTreeMap<DAppId, TierId>>> {
+        self.call_at(at, |api, at_hash| {
+            api.get_dapp_tier_assignment_for_era(at_hash, era)
+        })
+    }
+
+    fn tier_rewards_for_era(
+        &self,
+        era: EraNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<DAppTierRewardsInfo>> {
+        self.call_at(at, |api, at_hash| {
+            api.get_dapp_tier_rewards_for_era(at_hash, era)
+        })
+    }
+}
+
+/// Converts the balance part of `(key, balance)` pairs into `NumberOrHex`, so large values are safely represented.
+fn into_number_or_hex<K>(pairs: Vec<(K, Balance)>) -> Vec<(K, NumberOrHex)> {
+    pairs
+        .into_iter()
+        .map(|(key, balance)| (key, balance.into()))
+        .collect()
+}
@@ -400,6 +400,12 @@ mod benchmarks {
             amount,
         ));
 
+        // Worst case - loyalty streak from the previous period is continued.
+        let previous_period = ActiveProtocolState::<T>::get()
+            .period_number()
+            .saturating_sub(1);
+        LoyaltyStreaks::<T>::insert(&staker, &smart_contract, (previous_period, 1));
+
         #[extrinsic_call]
         _(
             RawOrigin::Signed(staker.clone()),
@@ -914,6 +920,9 @@ mod benchmarks {
 
         let pre_cleanup_marker = HistoryCleanupMarker::<T>::get();
 
+        // Schedule new tier parameters, so they are applied at the start of the new period.
+        NextTierParams::<T>::put(StaticTierParams::<T>::get());
+
         #[block]
         {
             DappStaking::<T>::era_and_period_handler(new_era_start_block, TierAssignment::Dummy);
@@ -923,6 +932,7 @@ mod benchmarks {
             ActiveProtocolState::<T>::get().subperiod(),
             Subperiod::Voting
         );
+        assert!(NextTierParams::<T>::get().is_none());
         assert_eq!(
             ActiveProtocolState::<T>::get().period_number(),
             snapshot_state.period_number() + 1,
@@ -1068,6 +1078,316 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_auto_restake() {
+        initial_config::<T>();
+
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(staker.clone()), true);
+
+        assert_last_event::<T>(
+            Event::<T>::AutoRestakeSet {
+                account: staker,
+                enabled: true,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn restake_reward(x: Linear<0, { T::MaxNumberOfStakedContracts::get() }>) {
+        initial_config::<T>();
+
+        // Prepare staker & lock some amount
+        let staker: T::AccountId = whitelisted_caller();
+        let amount = T::MinimumLockedAmount::get()
+            * Into::<Balance>::into(T::MaxNumberOfStakedContracts::get());
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+
+        // Register dApps & stake on them
+        for idx in 0..x {
+            let owner: T::AccountId = account("dapp_owner", idx.into(), SEED);
+            let smart_contract = T::BenchmarkHelper::get_smart_contract(idx as u32);
+            assert_ok!(DappStaking::<T>::register(
+                RawOrigin::Root.into(),
+                owner.clone().into(),
+                smart_contract.clone(),
+            ));
+
+            assert_ok!(DappStaking::<T>::stake(
+                RawOrigin::Signed(staker.clone()).into(),
+                smart_contract.clone(),
+                T::MinimumStakeAmount::get(),
+            ));
+        }
+        assert_ok!(DappStaking::<T>::set_auto_restake(
+            RawOrigin::Signed(staker.clone()).into(),
+            true,
+        ));
+
+        // Simulate the reward payout
+        let reward = T::MinimumStakeAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount + reward);
+
+        let mut ledger = Ledger::<T>::get(&staker);
+        let protocol_state = ActiveProtocolState::<T>::get();
+
+        #[block]
+        {
+            DappStaking::<T>::maybe_restake_reward(&staker, &mut ledger, reward, &protocol_state);
+        }
+
+        assert_eq!(ledger.active_locked_amount(), amount + reward);
+    }
+
+    #[benchmark]
+    fn claim_staker_rewards_for_past_period(x: Linear<1, { max_claim_size_past_period::<T>() }>) {
+        initial_config::<T>();
+
+        // Prepare staker & register smart contract
+        let staker: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Lock & stake some amount by the staker
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            smart_contract.clone(),
+            amount
+        ));
+
+        // Hacky era advancement to ensure we have the exact number of eras to claim, but are already in the next period.
+        force_advance_to_era::<T>(max_claim_size_past_period::<T>() - 1);
+        force_advance_to_next_period::<T>();
+
+        // Hack - modify staker's stake so it seems as if stake was valid from the 'first stake era'.
+        // Also fill up the reward span.
+        //
+        // This allows us to easily control how many rewards are claimed, without having to advance large amount of blocks/eras/periods
+        // to find an appropriate scenario.
+        let first_stake_era = max_claim_size_past_period::<T>() - x;
+        Ledger::<T>::mutate(&staker, |ledger| {
+            ledger.staked = ledger.staked_future.unwrap();
+            ledger.staked_future = None;
+            ledger.staked.era = first_stake_era;
+        });
+
+        // Just fill them up, the ledger entry will control how much claims we can make
+        let mut reward_span = EraRewardSpan::<_>::new();
+        for era in 0..(T::EraRewardSpanLength::get()) {
+            assert_ok!(reward_span.push(
+                era as EraNumber,
+                EraReward {
+                    staker_reward_pool: 1_000_000_000_000,
+                    staked: amount,
+                    dapp_reward_pool: 1_000_000_000_000,
+                },
+            ));
+        }
+        EraRewards::<T>::insert(&0, reward_span);
+
+        // For testing purposes
+        System::<T>::reset_events();
+
+        // Claim is made by a third party, on behalf of the staker
+        let claimer: T::AccountId = account("claimer", 0, SEED);
+
+        #[extrinsic_call]
+        claim_staker_rewards_for(RawOrigin::Signed(claimer), staker.clone());
+
+        // No need to do precise check of values, but predetermined amount of 'Reward' events is expected.
+        let dapp_staking_events = dapp_staking_events::<T>();
+        assert_eq!(dapp_staking_events.len(), x as usize);
+        dapp_staking_events.iter().for_each(|e| {
+            assert_matches!(e, Event::Reward { .. });
+        });
+    }
+
+    #[benchmark]
+    fn claim_staker_rewards_for_ongoing_period(
+        x: Linear<1, { max_claim_size_ongoing_period::<T>() }>,
+    ) {
+        initial_config::<T>();
+
+        // Prepare staker & register smart contract
+        let staker: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Lock & stake some amount by the staker
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            smart_contract.clone(),
+            amount
+        ));
+
+        // Advance to era at the end of the first period or first span.
+        force_advance_to_era::<T>(max_claim_size_ongoing_period::<T>());
+        assert_eq!(
+            ActiveProtocolState::<T>::get().period_number(),
+            1,
+            "Sanity check, we must still be in the first period."
+        );
+
+        // Hack - modify staker's stake so it seems as if stake was valid from the 'first stake era'/
+        // Also fill up the reward span.
+        //
+        // This allows us to easily control how many rewards are claimed, without having to advance large amount of blocks/eras/periods
+        // to find an appropriate scenario.
+        let first_stake_era = max_claim_size_ongoing_period::<T>() - x;
+        Ledger::<T>::mutate(&staker, |ledger| {
+            ledger.staked = ledger.staked_future.unwrap();
+            ledger.staked_future = None;
+            ledger.staked.era = first_stake_era;
+        });
+
+        // Just fill them up, the ledger entry will control how much claims we can make
+        let mut reward_span = EraRewardSpan::<_>::new();
+        for era in 0..(T::EraRewardSpanLength::get()) {
+            assert_ok!(reward_span.push(
+                era as EraNumber,
+                EraReward {
+                    staker_reward_pool: 1_000_000_000_000,
+                    staked: amount,
+                    dapp_reward_pool: 1_000_000_000_000,
+                },
+            ));
+        }
+        EraRewards::<T>::insert(&0, reward_span);
+
+        // For testing purposes
+        System::<T>::reset_events();
+
+        // Claim is made by a third party, on behalf of the staker
+        let claimer: T::AccountId = account("claimer", 0, SEED);
+
+        #[extrinsic_call]
+        claim_staker_rewards_for(RawOrigin::Signed(claimer), staker.clone());
+
+        // No need to do precise check of values, but predetermined amount of 'Reward' events is expected.
+        let dapp_staking_events = dapp_staking_events::<T>();
+        assert_eq!(dapp_staking_events.len(), x as usize);
+        dapp_staking_events.iter().for_each(|e| {
+            assert_matches!(e, Event::Reward { .. });
+        });
+    }
+
+    #[benchmark]
+    fn transfer_locks() {
+        initial_config::<T>();
+
+        // Worst case is when `from` has the max number of unlocking chunks, and `to` is a new account.
+        let from: T::AccountId = whitelisted_caller();
+        let to: T::AccountId = account("to", 0, SEED);
+        let amount =
+            T::MinimumLockedAmount::get() + Into::<Balance>::into(T::MaxUnlockingChunks::get());
+        T::BenchmarkHelper::set_balance(&from, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(from.clone()).into(),
+            amount,
+        ));
+
+        for _ in 0..T::MaxUnlockingChunks::get() {
+            assert_ok!(DappStaking::<T>::unlock(
+                RawOrigin::Signed(from.clone()).into(),
+                1,
+            ));
+            run_for_blocks::<T>(One::one());
+        }
+        assert_eq!(
+            Ledger::<T>::get(&from).unlocking.len(),
+            T::MaxUnlockingChunks::get() as usize
+        );
+
+        #[block]
+        {
+            assert_ok!(DappStaking::<T>::transfer_locks(&from, &to), amount);
+        }
+
+        assert!(Ledger::<T>::get(&from).is_empty());
+        assert_last_event::<T>(Event::<T>::LocksTransferred { from, to, amount }.into());
+    }
+
+    #[benchmark]
+    fn claim_bonus_reward_for() {
+        initial_config::<T>();
+
+        // Prepare staker & register smart contract
+        let staker: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("dapp_owner", 0, SEED);
+        let smart_contract = T::BenchmarkHelper::get_smart_contract(1);
+        assert_ok!(DappStaking::<T>::register(
+            RawOrigin::Root.into(),
+            owner.clone().into(),
+            smart_contract.clone(),
+        ));
+
+        // Lock & stake some amount by the staker
+        let amount = T::MinimumLockedAmount::get();
+        T::BenchmarkHelper::set_balance(&staker, amount);
+        assert_ok!(DappStaking::<T>::lock(
+            RawOrigin::Signed(staker.clone()).into(),
+            amount,
+        ));
+        assert_ok!(DappStaking::<T>::stake(
+            RawOrigin::Signed(staker.clone()).into(),
+            smart_contract.clone(),
+            amount
+        ));
+
+        // Advance to the next period so we can claim the bonus reward.
+        force_advance_to_next_period::<T>();
+
+        let claimer: T::AccountId = account("claimer", 0, SEED);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(claimer),
+            staker.clone(),
+            smart_contract.clone(),
+        );
+
+        // No need to do precise check of values, but last event must be 'BonusReward'.
+        assert_matches!(
+            dapp_staking_events::<T>().last(),
+            Some(Event::BonusReward { .. })
+        );
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
@@ -38,7 +38,8 @@
 use frame_support::{
     pallet_prelude::*,
     traits::{
-        fungible::{Inspect as FunInspect, MutateFreeze as FunMutateFreeze},
+        fungible::{Inspect as FunInspect, Mutate as FunMutate, MutateFreeze as FunMutateFreeze},
+        tokens::Preservation,
         StorageVersion,
     },
     weights::Weight,
@@ -52,8 +53,9 @@ pub use sp_std::vec::Vec;
 
 use astar_primitives::{
     dapp_staking::{
-        AccountCheck, CycleConfiguration, DAppId, EraNumber, Observer as DAppStakingObserver,
-        PeriodNumber, SmartContractHandle, StakingRewardHandler, TierId, TierSlots as TierSlotFunc,
+        AccountCheck, CycleConfiguration, DAppId, EraNumber, LocksTransfer,
+        Observer as DAppStakingObserver, PeriodNumber, SmartContractHandle, StakingRewardHandler,
+        TierId, TierSlots as TierSlotFunc,
     },
     oracle::PriceProvider,
     Balance, BlockNumber,
@@ -73,6 +75,8 @@ pub use types::*;
 pub mod weights;
 pub use weights::WeightInfo;
 
+pub mod migration;
+
 const LOG_TARGET: &str = "dapp-staking";
 
 /// Helper enum for benchmarking.
@@ -90,7 +94,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -115,11 +119,8 @@ pub mod pallet {
 
         /// Currency used for staking.
         /// Reference: <https://github.com/paritytech/substrate/pull/12951/>
-        type Currency: FunMutateFreeze<
-            Self::AccountId,
-            Id = Self::RuntimeFreezeReason,
-            Balance = Balance,
-        >;
+        type Currency: FunMutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason, Balance = Balance>
+            + FunMutate<Self::AccountId>;
 
         /// Describes smart contract in the context required by dApp staking.
         type SmartContract: Parameter
@@ -152,6 +153,14 @@ pub mod pallet {
         #[pallet::constant]
         type EraRewardSpanLength: Get<u32>;
 
+        /// Maximum number of era reward spans that can be claimed in a single batch staker rewards claim.
+        #[pallet::constant]
+        type MaxRewardSpansPerClaim: Get<u32>;
+
+        /// Maximum number of staker & bonus reward claims that can be made on behalf of other accounts in a single block.
+        #[pallet::constant]
+        type MaxDelegatedClaimsPerBlock: Get<u32>;
+
         /// Number of periods for which we keep rewards available for claiming.
         /// After that period, they are no longer claimable.
         #[pallet::constant]
@@ -186,6 +195,15 @@ pub mod pallet {
         #[pallet::constant]
         type NumberOfTiers: Get<u32>;
 
+        /// Increase of the bonus reward multiplier for each additional consecutive period in which the staker remained loyal to a contract.
+        #[pallet::constant]
+        type LoyaltyMultiplierStep: Get<Perbill>;
+
+        /// Maximum number of consecutive loyal periods taken into account when calculating the bonus reward multiplier.
+        /// Value of `1` effectively disables the loyalty multiplier.
+        #[pallet::constant]
+        type MaxLoyaltyPeriods: Get<u8>;
+
         /// Weight info for various calls & operations in the pallet.
         type WeightInfo: WeightInfo;
 
@@ -265,6 +283,12 @@ pub mod pallet {
             era: EraNumber,
             amount: Balance,
         },
+        /// Account has claimed stake rewards for multiple eras at once, up to & including `last_era`.
+        BatchReward {
+            account: T::AccountId,
+            last_era: EraNumber,
+            amount: Balance,
+        },
         /// Bonus reward has been paid out to a loyal staker.
         BonusReward {
             account: T::AccountId,
@@ -290,6 +314,28 @@ pub mod pallet {
         ExpiredEntriesRemoved { account: T::AccountId, count: u16 },
         /// Privileged origin has forced a new era and possibly a subperiod to start from next block.
         Force { forcing_type: ForcingType },
+        /// Account has enabled or disabled automatic restaking of the claimed rewards.
+        AutoRestakeSet {
+            account: T::AccountId,
+            enabled: bool,
+        },
+        /// Account has moved some stake from one smart contract to another.
+        StakeMoved {
+            account: T::AccountId,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            amount: Balance,
+        },
+        /// New tier parameters have been scheduled, and will be applied at the start of the specified period.
+        TierParamsScheduled { period: PeriodNumber },
+        /// Scheduled tier parameters have been applied at the start of the specified period.
+        TierParamsApplied { period: PeriodNumber },
+        /// Locked & unlocking funds of an account have been transferred to another account.
+        LocksTransferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: Balance,
+        },
     }
 
     #[pallet::error]
@@ -366,6 +412,16 @@ pub mod pallet {
         NoExpiredEntries,
         /// Force call is not allowed in production.
         ForceNotAllowed,
+        /// Account has no active ledger, hence the automatic restaking preference cannot be set.
+        AccountNotStaker,
+        /// Stake cannot be moved from a smart contract to itself.
+        SameContracts,
+        /// Limit of reward claims on behalf of other accounts has been reached for this block.
+        TooManyDelegatedClaims,
+        /// Provided tier parameters are not valid.
+        InvalidTierParams,
+        /// Account has staked funds which prevent the locks from being transferred.
+        RemainingStakePreventsLocksTransfer,
     }
 
     /// General information about dApp staking protocol state.
@@ -407,6 +463,22 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Loyalty streak of a staker on a smart contract, kept after the bonus reward has been claimed.
+    ///
+    /// Holds the period in which the staker was loyal, and the number of consecutive loyal periods up to & including it.
+    /// Consumed when staker stakes on the same contract again, during the voting subperiod of the following period.
+    /// Expired streaks are removed by `cleanup_expired_entries`, and unstaking from an unregistered contract removes its streak.
+    #[pallet::storage]
+    pub type LoyaltyStreaks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::SmartContract,
+        (PeriodNumber, u8),
+        OptionQuery,
+    >;
+
     /// Information about how much has been staked on a smart contract in some era or period.
     #[pallet::storage]
     pub type ContractStake<T: Config> = StorageMap<
@@ -444,6 +516,11 @@ pub mod pallet {
     pub type StaticTierParams<T: Config> =
         StorageValue<_, TierParameters<T::NumberOfTiers>, ValueQuery>;
 
+    /// Tier parameters scheduled to replace the static tier parameters at the start of the next period.
+    #[pallet::storage]
+    pub type NextTierParams<T: Config> =
+        StorageValue<_, TierParameters<T::NumberOfTiers>, OptionQuery>;
+
     /// Tier configuration user for current & preceding eras.
     #[pallet::storage]
     pub type TierConfig<T: Config> =
@@ -458,6 +535,11 @@ pub mod pallet {
     #[pallet::storage]
     pub type HistoryCleanupMarker<T: Config> = StorageValue<_, CleanupMarker, ValueQuery>;
 
+    /// Number of staker & bonus reward claims made on behalf of other accounts in the current block.
+    #[pallet::storage]
+    #[pallet::whitelist_storage]
+    pub type DelegatedClaimsInBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     #[pallet::type_value]
     pub fn DefaultSafeguard<T: Config>() -> bool {
         // In production, safeguard is enabled by default.
@@ -546,8 +628,12 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            // Reset the delegated claims budget for the new block
+            DelegatedClaimsInBlock::<T>::kill();
+
             let now = now.saturated_into();
             Self::era_and_period_handler(now, TierAssignment::Real)
+                .saturating_add(T::DbWeight::get().writes(1))
         }
 
         fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
@@ -558,11 +644,14 @@ pub mod pallet {
             // dApp staking params
             // Sanity checks
             assert!(T::EraRewardSpanLength::get() > 0);
+            assert!(T::MaxRewardSpansPerClaim::get() > 0);
+            assert!(T::MaxDelegatedClaimsPerBlock::get() > 0);
             assert!(T::RewardRetentionInPeriods::get() > 0);
             assert!(T::MaxNumberOfContracts::get() > 0);
             assert!(T::MaxUnlockingChunks::get() > 0);
             assert!(T::UnlockingPeriod::get() > 0);
             assert!(T::MaxNumberOfStakedContracts::get() > 0);
+            assert!(T::MaxLoyaltyPeriods::get() > 0);
 
             assert!(T::MinimumLockedAmount::get() > 0);
             assert!(T::MinimumStakeAmount::get() > 0);
@@ -1039,6 +1128,22 @@ pub mod pallet {
                         true,
                     ),
                 };
+
+            // In case staker was loyal to the contract in the previous period, the loyalty streak continues.
+            let old_loyalty_stake = if is_new_entry {
+                if let Some((loyal_period, loyal_periods)) =
+                    LoyaltyStreaks::<T>::take(&account, &smart_contract)
+                {
+                    if loyal_period.saturating_add(1) == protocol_state.period_number() {
+                        new_staking_info
+                            .continue_loyalty(loyal_periods, T::MaxLoyaltyPeriods::get());
+                    }
+                }
+                Balance::zero()
+            } else {
+                Self::loyalty_stake(&new_staking_info)
+            };
+
             new_staking_info.stake(amount, current_era, protocol_state.subperiod());
             ensure!(
                 new_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
@@ -1062,6 +1167,10 @@ pub mod pallet {
             // Update total staked amount for the next era.
             CurrentEraInfo::<T>::mutate(|era_info| {
                 era_info.add_stake_amount(amount, protocol_state.subperiod());
+                era_info.update_loyalty_stake(
+                    old_loyalty_stake,
+                    Self::loyalty_stake(&new_staking_info),
+                );
             });
 
             // 5.
@@ -1110,7 +1219,7 @@ pub mod pallet {
 
             // 1.
             // Update `StakerInfo` storage with the reduced stake amount on the specified contract.
-            let (new_staking_info, amount, era_and_amount_pairs) =
+            let (new_staking_info, amount, era_and_amount_pairs, old_loyalty_stake) =
                 match StakerInfo::<T>::get(&account, &smart_contract) {
                     Some(mut staking_info) => {
                         ensure!(
@@ -1132,10 +1241,16 @@ pub mod pallet {
                             amount
                         };
 
+                        let old_loyalty_stake = Self::loyalty_stake(&staking_info);
                         let era_and_amount_pairs =
                             staking_info.unstake(amount, current_era, protocol_state.subperiod());
 
-                        (staking_info, amount, era_and_amount_pairs)
+                        (
+                            staking_info,
+                            amount,
+                            era_and_amount_pairs,
+                            old_loyalty_stake,
+                        )
                     }
                     None => {
                         return Err(Error::<T>::NoStakingInfo.into());
@@ -1170,6 +1285,10 @@ pub mod pallet {
             // Update total staked amount for the next era.
             CurrentEraInfo::<T>::mutate(|era_info| {
                 era_info.unstake_amount(amount);
+                era_info.update_loyalty_stake(
+                    old_loyalty_stake,
+                    Self::loyalty_stake(&new_staking_info),
+                );
             });
 
             // 5.
@@ -1201,157 +1320,30 @@ pub mod pallet {
             let max_span_length = T::EraRewardSpanLength::get();
             T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
                 .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::restake_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
         })]
         pub fn claim_staker_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let mut ledger = Ledger::<T>::get(&account);
-            let staked_period = ledger
-                .staked_period()
-                .ok_or(Error::<T>::NoClaimableRewards)?;
-
-            // Check if the rewards have expired
-            let protocol_state = ActiveProtocolState::<T>::get();
-            ensure!(
-                staked_period >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            // Calculate the reward claim span
-            let earliest_staked_era = ledger
-                .earliest_staked_era()
-                .ok_or(Error::<T>::InternalClaimStakerError)?;
-            let era_rewards =
-                EraRewards::<T>::get(Self::era_reward_span_index(earliest_staked_era))
-                    .ok_or(Error::<T>::NoClaimableRewards)?;
-
-            // The last era for which we can theoretically claim rewards.
-            // And indicator if we know the period's ending era.
-            let (last_period_era, period_end) = if staked_period == protocol_state.period_number() {
-                (protocol_state.era.saturating_sub(1), None)
-            } else {
-                PeriodEnd::<T>::get(&staked_period)
-                    .map(|info| (info.final_era, Some(info.final_era)))
-                    .ok_or(Error::<T>::InternalClaimStakerError)?
-            };
-
-            // The last era for which we can claim rewards for this account.
-            let last_claim_era = era_rewards.last_era().min(last_period_era);
-
-            // Get chunks for reward claiming
-            let rewards_iter =
-                ledger
-                    .claim_up_to_era(last_claim_era, period_end)
-                    .map_err(|err| match err {
-                        AccountLedgerError::NothingToClaim => Error::<T>::NoClaimableRewards,
-                        _ => Error::<T>::InternalClaimStakerError,
-                    })?;
-
-            // Calculate rewards
-            let mut rewards: Vec<_> = Vec::new();
-            let mut reward_sum = Balance::zero();
-            for (era, amount) in rewards_iter {
-                let era_reward = era_rewards
-                    .get(era)
-                    .ok_or(Error::<T>::InternalClaimStakerError)?;
-
-                // Optimization, and zero-division protection
-                if amount.is_zero() || era_reward.staked.is_zero() {
-                    continue;
-                }
-                let staker_reward = Perbill::from_rational(amount, era_reward.staked)
-                    * era_reward.staker_reward_pool;
-
-                rewards.push((era, staker_reward));
-                reward_sum.saturating_accrue(staker_reward);
-            }
-            let rewards_len: u32 = rewards.len().unique_saturated_into();
-
-            T::StakingRewardHandler::payout_reward(&account, reward_sum)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
-
-            Self::update_ledger(&account, ledger)?;
-
-            rewards.into_iter().for_each(|(era, reward)| {
-                Self::deposit_event(Event::<T>::Reward {
-                    account: account.clone(),
-                    era,
-                    amount: reward,
-                });
-            });
-
-            Ok(Some(if period_end.is_some() {
-                T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
-            } else {
-                T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
-            })
-            .into())
+            Self::internal_claim_staker_rewards(&account, false)
         }
 
         /// Used to claim bonus reward for a smart contract, if eligible.
         #[pallet::call_index(14)]
-        #[pallet::weight(T::WeightInfo::claim_bonus_reward())]
+        #[pallet::weight(T::WeightInfo::claim_bonus_reward().saturating_add(
+            T::WeightInfo::restake_reward(T::MaxNumberOfStakedContracts::get())
+        ))]
         pub fn claim_bonus_reward(
             origin: OriginFor<T>,
             smart_contract: T::SmartContract,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             Self::ensure_pallet_enabled()?;
             let account = ensure_signed(origin)?;
 
-            let staker_info = StakerInfo::<T>::get(&account, &smart_contract)
-                .ok_or(Error::<T>::NoClaimableRewards)?;
-            let protocol_state = ActiveProtocolState::<T>::get();
-
-            // Ensure:
-            // 1. Period for which rewards are being claimed has ended.
-            // 2. Account has been a loyal staker.
-            // 3. Rewards haven't expired.
-            let staked_period = staker_info.period_number();
-            ensure!(
-                staked_period < protocol_state.period_number(),
-                Error::<T>::NoClaimableRewards
-            );
-            ensure!(
-                staker_info.is_loyal(),
-                Error::<T>::NotEligibleForBonusReward
-            );
-            ensure!(
-                staker_info.period_number()
-                    >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            let period_end_info =
-                PeriodEnd::<T>::get(&staked_period).ok_or(Error::<T>::InternalClaimBonusError)?;
-            // Defensive check - we should never get this far in function if no voting period stake exists.
-            ensure!(
-                !period_end_info.total_vp_stake.is_zero(),
-                Error::<T>::InternalClaimBonusError
-            );
-
-            let eligible_amount = staker_info.staked_amount(Subperiod::Voting);
-            let bonus_reward =
-                Perbill::from_rational(eligible_amount, period_end_info.total_vp_stake)
-                    * period_end_info.bonus_reward_pool;
-
-            T::StakingRewardHandler::payout_reward(&account, bonus_reward)
-                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
-
-            // Cleanup entry since the reward has been claimed
-            StakerInfo::<T>::remove(&account, &smart_contract);
-            Ledger::<T>::mutate(&account, |ledger| {
-                ledger.contract_stake_count.saturating_dec();
-            });
-
-            Self::deposit_event(Event::<T>::BonusReward {
-                account: account.clone(),
-                smart_contract,
-                period: staked_period,
-                amount: bonus_reward,
-            });
-
-            Ok(())
+            Self::internal_claim_bonus_reward(&account, &smart_contract, false)
         }
 
         /// Used to claim dApp reward for the specified era.
@@ -1370,24 +1362,10 @@ pub mod pallet {
             let dapp_info =
                 IntegratedDApps::<T>::get(&smart_contract).ok_or(Error::<T>::ContractNotFound)?;
 
-            // Make sure provided era has ended
-            let protocol_state = ActiveProtocolState::<T>::get();
-            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
-
             // 'Consume' dApp reward for the specified era, if possible.
-            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
-            ensure!(
-                dapp_tiers.period >= Self::oldest_claimable_period(protocol_state.period_number()),
-                Error::<T>::RewardExpired
-            );
-
-            let (amount, tier_id) =
-                dapp_tiers
-                    .try_claim(dapp_info.id)
-                    .map_err(|error| match error {
-                        DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
-                        _ => Error::<T>::InternalClaimDAppError,
-                    })?;
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let (amount, tier_id, dapp_tiers) =
+                Self::internal_calculate_dapp_reward(dapp_info.id, era, &protocol_state)?;
 
             // Get reward destination, and deposit the reward.
             let beneficiary = dapp_info.reward_beneficiary();
@@ -1428,14 +1406,17 @@ pub mod pallet {
             let current_era = protocol_state.era;
 
             // Extract total staked amount on the specified unregistered contract
-            let amount = match StakerInfo::<T>::get(&account, &smart_contract) {
+            let (amount, loyalty_stake) = match StakerInfo::<T>::get(&account, &smart_contract) {
                 Some(staking_info) => {
                     ensure!(
                         staking_info.period_number() == protocol_state.period_number(),
                         Error::<T>::UnstakeFromPastPeriod
                     );
 
-                    staking_info.total_staked_amount()
+                    (
+                        staking_info.total_staked_amount(),
+                        Self::loyalty_stake(&staking_info),
+                    )
                 }
                 None => {
                     return Err(Error::<T>::NoStakingInfo.into());
@@ -1459,11 +1440,13 @@ pub mod pallet {
             // Although strange, it's been requested to keep it like this from the team.
             CurrentEraInfo::<T>::mutate(|era_info| {
                 era_info.unstake_amount(amount);
+                era_info.update_loyalty_stake(loyalty_stake, Balance::zero());
             });
 
             // Update remaining storage entries
             Self::update_ledger(&account, ledger)?;
             StakerInfo::<T>::remove(&account, &smart_contract);
+            LoyaltyStreaks::<T>::remove(&account, &smart_contract);
 
             Self::deposit_event(Event::<T>::UnstakeFromUnregistered {
                 account,
@@ -1479,6 +1462,8 @@ pub mod pallet {
         /// Entry is considered to be expired if:
         /// 1. It's from a past period & the account wasn't a loyal staker, meaning there's no claimable bonus reward.
         /// 2. It's from a period older than the oldest claimable period, regardless whether the account was loyal or not.
+        ///
+        /// Loyalty streaks which can no longer be continued are removed as well.
         #[pallet::call_index(17)]
         #[pallet::weight(T::WeightInfo::cleanup_expired_entries(
             T::MaxNumberOfStakedContracts::get()
@@ -1506,12 +1491,32 @@ pub mod pallet {
                 .collect();
             let entries_to_delete = to_be_deleted.len();
 
-            ensure!(!entries_to_delete.is_zero(), Error::<T>::NoExpiredEntries);
+            // Loyalty streaks which can no longer be continued are removed as well, within the same bound.
+            let streaks_to_delete: Vec<T::SmartContract> =
+                LoyaltyStreaks::<T>::iter_prefix(&account)
+                    .filter_map(|(smart_contract, (loyal_period, _))| {
+                        if loyal_period.saturating_add(1) < current_period {
+                            Some(smart_contract)
+                        } else {
+                            None
+                        }
+                    })
+                    .take(
+                        (T::MaxNumberOfStakedContracts::get() as usize)
+                            .saturating_sub(entries_to_delete),
+                    )
+                    .collect();
+            let removed_entries = entries_to_delete.saturating_add(streaks_to_delete.len());
+
+            ensure!(!removed_entries.is_zero(), Error::<T>::NoExpiredEntries);
 
             // Remove all expired entries.
             for smart_contract in to_be_deleted {
                 StakerInfo::<T>::remove(&account, &smart_contract);
             }
+            for smart_contract in streaks_to_delete {
+                LoyaltyStreaks::<T>::remove(&account, &smart_contract);
+            }
 
             // Remove expired stake entries from the ledger.
             let mut ledger = Ledger::<T>::get(&account);
@@ -1527,7 +1532,7 @@ pub mod pallet {
             });
 
             Ok(Some(T::WeightInfo::cleanup_expired_entries(
-                entries_to_delete.unique_saturated_into(),
+                removed_entries.unique_saturated_into(),
             ))
             .into())
         }
@@ -1570,45 +1575,434 @@ pub mod pallet {
 
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// `true` if the account is a staker, `false` otherwise.
-        pub fn is_staker(account: &T::AccountId) -> bool {
-            Ledger::<T>::contains_key(account)
-        }
+        /// Used to claim staker rewards across multiple era reward spans, up to & including the specified era.
+        ///
+        /// At most `MaxRewardSpansPerClaim` spans are claimed in a single call, and the total reward is paid out at once.
+        #[pallet::call_index(19)]
+        #[pallet::weight({
+            let max_span_length = T::EraRewardSpanLength::get();
+            T::WeightInfo::claim_staker_rewards_ongoing_period(max_span_length)
+                .max(T::WeightInfo::claim_staker_rewards_past_period(max_span_length))
+                .saturating_mul(T::MaxRewardSpansPerClaim::get().into())
+                .saturating_add(T::WeightInfo::restake_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+        })]
+        pub fn claim_staker_rewards_up_to(
+            origin: OriginFor<T>,
+            #[pallet::compact] era: EraNumber,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
 
-        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
-        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
-            if ActiveProtocolState::<T>::get().maintenance {
-                Err(Error::<T>::Disabled)
-            } else {
-                Ok(())
+            let mut ledger = Ledger::<T>::get(&account);
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let mut reward_sum = Balance::zero();
+            let mut last_era = EraNumber::zero();
+            let mut claimed_spans = 0_u32;
+            let mut consumed_weight = Weight::zero();
+
+            // Claim span by span, until either nothing is left to claim, or the max number of spans is reached.
+            while claimed_spans < T::MaxRewardSpansPerClaim::get() {
+                let (rewards, period_end) = match Self::internal_calculate_staker_rewards(
+                    &mut ledger,
+                    &protocol_state,
+                    era,
+                ) {
+                    Ok(result) => result,
+                    Err(Error::<T>::NoClaimableRewards) if claimed_spans > 0 => break,
+                    Err(error) => return Err(error.into()),
+                };
+                let rewards_len: u32 = rewards.len().unique_saturated_into();
+
+                for (reward_era, reward) in rewards {
+                    reward_sum.saturating_accrue(reward);
+                    last_era = last_era.max(reward_era);
+                }
+
+                consumed_weight.saturating_accrue(if period_end.is_some() {
+                    T::WeightInfo::claim_staker_rewards_past_period(rewards_len)
+                } else {
+                    T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
+                });
+                claimed_spans.saturating_inc();
             }
+
+            T::StakingRewardHandler::payout_reward(&account, reward_sum)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            Self::deposit_event(Event::<T>::BatchReward {
+                account: account.clone(),
+                last_era,
+                amount: reward_sum,
+            });
+
+            consumed_weight.saturating_accrue(Self::maybe_restake_reward(
+                &account,
+                &mut ledger,
+                reward_sum,
+                &protocol_state,
+            ));
+            Self::update_ledger(&account, ledger)?;
+
+            Ok(Some(consumed_weight).into())
         }
 
-        /// Ensure that the origin is either the `ManagerOrigin` or a signed origin.
+        /// Used to enable or disable automatic restaking of the claimed staker & bonus rewards.
         ///
-        /// In case of manager, `Ok(None)` is returned, and if signed origin `Ok(Some(AccountId))` is returned.
-        pub(crate) fn ensure_signed_or_manager(
-            origin: T::RuntimeOrigin,
-        ) -> Result<Option<T::AccountId>, BadOrigin> {
-            if T::ManagerOrigin::ensure_origin(origin.clone()).is_ok() {
-                return Ok(None);
-            }
-            let who = ensure_signed(origin)?;
-            Ok(Some(who))
+        /// When enabled, claimed rewards are locked, and restaked on the dApps the account has staked on
+        /// in the ongoing period, proportionally to the existing stake amounts.
+        ///
+        /// The preference is kept in the account ledger, and is removed together with it.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_auto_restake())]
+        pub fn set_auto_restake(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            let mut ledger = Ledger::<T>::get(&account);
+            ensure!(!ledger.is_empty(), Error::<T>::AccountNotStaker);
+
+            ledger.auto_restake = enabled;
+            Ledger::<T>::insert(&account, ledger);
+
+            Self::deposit_event(Event::<T>::AutoRestakeSet { account, enabled });
+
+            Ok(())
         }
 
-        /// Update the account ledger, and dApp staking balance freeze.
+        /// Moves the specified amount of stake from one smart contract to another, in the ongoing period.
         ///
-        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        /// Unlike `unstake` followed by `stake`, the moved amount keeps its voting & build&earn subperiod composition,
+        /// and the loyalty streak of the source entry is preserved. The destination entry inherits the loyalty streak,
+        /// or keeps the shorter one in case it already has a voting subperiod stake.
         ///
-        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
-        /// runtime definition must ensure it supports necessary freezes.
-        pub(crate) fn update_ledger(
-            account: &T::AccountId,
-            ledger: AccountLedgerFor<T>,
+        /// If moving the specified `amount` would take the source stake below the minimum stake threshold, everything is moved.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::unstake().saturating_add(T::WeightInfo::stake()))]
+        pub fn move_stake(
+            origin: OriginFor<T>,
+            source_contract: T::SmartContract,
+            destination_contract: T::SmartContract,
+            #[pallet::compact] amount: Balance,
+        ) -> DispatchResult {
+            Self::ensure_pallet_enabled()?;
+            let account = ensure_signed(origin)?;
+
+            ensure!(amount > 0, Error::<T>::ZeroAmount);
+            ensure!(
+                source_contract != destination_contract,
+                Error::<T>::SameContracts
+            );
+
+            let source_dapp_info =
+                IntegratedDApps::<T>::get(&source_contract).ok_or(Error::<T>::ContractNotFound)?;
+            let destination_dapp_info = IntegratedDApps::<T>::get(&destination_contract)
+                .ok_or(Error::<T>::ContractNotFound)?;
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let current_era = protocol_state.era;
+            ensure!(
+                !protocol_state
+                    .period_info
+                    .is_next_period(current_era.saturating_add(1)),
+                Error::<T>::PeriodEndsInNextEra
+            );
+
+            let mut ledger = Ledger::<T>::get(&account);
+
+            // 1.
+            // Move the stake out of the source contract entry.
+            let mut source_staking_info = StakerInfo::<T>::get(&account, &source_contract)
+                .ok_or(Error::<T>::NoStakingInfo)?;
+            ensure!(
+                source_staking_info.period_number() == protocol_state.period_number(),
+                Error::<T>::UnstakeFromPastPeriod
+            );
+            ensure!(
+                source_staking_info.total_staked_amount() >= amount,
+                Error::<T>::UnstakeAmountTooLarge
+            );
+
+            // If moving would take the remaining staked amount below the minimum required value, move everything.
+            let amount = if source_staking_info
+                .total_staked_amount()
+                .saturating_sub(amount)
+                < T::MinimumStakeAmount::get()
+            {
+                source_staking_info.total_staked_amount()
+            } else {
+                amount
+            };
+
+            let source_loyal_periods = source_staking_info.loyal_periods();
+            let old_loyalty_stake = Self::loyalty_stake(&source_staking_info);
+            let (moved_amount, era_and_amount_pairs) =
+                source_staking_info.move_out(amount, current_era, protocol_state.subperiod());
+
+            // 2.
+            // Move the stake into the destination contract entry, following the same rules as `stake`.
+            let threshold_period = Self::oldest_claimable_period(protocol_state.period_number());
+            let (mut destination_staking_info, is_new_entry) =
+                match StakerInfo::<T>::get(&account, &destination_contract) {
+                    // Entry with matching period exists
+                    Some(staking_info)
+                        if staking_info.period_number() == protocol_state.period_number() =>
+                    {
+                        (staking_info, false)
+                    }
+                    // Entry exists but period doesn't match. Bonus reward might still be claimable.
+                    Some(staking_info)
+                        if staking_info.period_number() >= threshold_period
+                            && staking_info.is_loyal() =>
+                    {
+                        return Err(Error::<T>::UnclaimedRewards.into());
+                    }
+                    // No valid entry exists
+                    _ => (
+                        SingularStakingInfo::new(
+                            protocol_state.period_number(),
+                            protocol_state.subperiod(),
+                        ),
+                        true,
+                    ),
+                };
+            let old_loyalty_stake =
+                old_loyalty_stake.saturating_add(Self::loyalty_stake(&destination_staking_info));
+            destination_staking_info.move_in(moved_amount, source_loyal_periods, current_era);
+            ensure!(
+                destination_staking_info.total_staked_amount() >= T::MinimumStakeAmount::get(),
+                Error::<T>::InsufficientStakeAmount
+            );
+
+            if is_new_entry {
+                ledger.contract_stake_count.saturating_inc();
+            }
+            if source_staking_info.is_empty() {
+                ledger.contract_stake_count.saturating_dec();
+            }
+            ensure!(
+                ledger.contract_stake_count <= T::MaxNumberOfStakedContracts::get(),
+                Error::<T>::TooManyStakedContracts
+            );
+
+            // 3.
+            // Update `ContractStake` storage for both contracts.
+            // Total stake of the account remains the same, so neither the ledger stake nor the era stake amounts are updated.
+            // Loyalty of the moved voting subperiod stake might change though.
+            //
+            // Stake which was already active in the current era remains active for the destination contract,
+            // mirroring what's removed from the source contract.
+            let current_era_amount: Balance = era_and_amount_pairs
+                .iter()
+                .filter(|(era, _)| *era == current_era)
+                .map(|(_, amount)| *amount)
+                .sum();
+
+            let mut source_contract_stake = ContractStake::<T>::get(&source_dapp_info.id);
+            source_contract_stake.unstake(
+                era_and_amount_pairs,
+                protocol_state.period_info,
+                current_era,
+            );
+
+            let mut destination_contract_stake = ContractStake::<T>::get(&destination_dapp_info.id);
+            for (subperiod_amount, subperiod) in [
+                (moved_amount.voting, Subperiod::Voting),
+                (moved_amount.build_and_earn, Subperiod::BuildAndEarn),
+            ] {
+                if !subperiod_amount.is_zero() {
+                    destination_contract_stake.stake(
+                        subperiod_amount,
+                        PeriodInfo {
+                            subperiod,
+                            ..protocol_state.period_info
+                        },
+                        current_era,
+                    );
+                }
+            }
+
+            // Build&earn subperiod stake is removed first, so the same order is followed here.
+            let current_era_bep_amount = current_era_amount.min(moved_amount.build_and_earn);
+            for (subperiod_amount, subperiod) in [
+                (
+                    current_era_amount.saturating_sub(current_era_bep_amount),
+                    Subperiod::Voting,
+                ),
+                (current_era_bep_amount, Subperiod::BuildAndEarn),
+            ] {
+                if !subperiod_amount.is_zero() {
+                    destination_contract_stake.stake_current_era(
+                        subperiod_amount,
+                        PeriodInfo {
+                            subperiod,
+                            ..protocol_state.period_info
+                        },
+                        current_era,
+                    );
+                }
+            }
+
+            let new_loyalty_stake = Self::loyalty_stake(&source_staking_info)
+                .saturating_add(Self::loyalty_stake(&destination_staking_info));
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.update_loyalty_stake(old_loyalty_stake, new_loyalty_stake);
+            });
+
+            // 4.
+            // Update remaining storage entries
+            ContractStake::<T>::insert(&source_dapp_info.id, source_contract_stake);
+            ContractStake::<T>::insert(&destination_dapp_info.id, destination_contract_stake);
+
+            if source_staking_info.is_empty() {
+                StakerInfo::<T>::remove(&account, &source_contract);
+            } else {
+                StakerInfo::<T>::insert(&account, &source_contract, source_staking_info);
+            }
+            StakerInfo::<T>::insert(&account, &destination_contract, destination_staking_info);
+            Ledger::<T>::insert(&account, ledger);
+
+            Self::deposit_event(Event::<T>::StakeMoved {
+                account,
+                source_contract,
+                destination_contract,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Claims staker rewards on behalf of the specified account.
+        ///
+        /// Can be called by anyone, but the rewards are always paid out to the staker account, never to the caller.
+        /// Behaves the same as `claim_staker_rewards`, including the automatic restaking if the staker enabled it.
+        ///
+        /// The number of such claims is limited per block, to prevent anyone from filling up the blocks with them.
+        #[pallet::call_index(22)]
+        #[pallet::weight({
+            let max_span_length = T::EraRewardSpanLength::get();
+            T::WeightInfo::claim_staker_rewards_for_ongoing_period(max_span_length)
+                .max(T::WeightInfo::claim_staker_rewards_for_past_period(max_span_length))
+                .saturating_add(T::WeightInfo::restake_reward(
+                    T::MaxNumberOfStakedContracts::get(),
+                ))
+        })]
+        pub fn claim_staker_rewards_for(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            ensure_signed(origin)?;
+
+            let delegated_claims = DelegatedClaimsInBlock::<T>::get();
+            ensure!(
+                delegated_claims < T::MaxDelegatedClaimsPerBlock::get(),
+                Error::<T>::TooManyDelegatedClaims
+            );
+
+            let post_info = Self::internal_claim_staker_rewards(&account, true)?;
+            DelegatedClaimsInBlock::<T>::put(delegated_claims.saturating_add(1));
+
+            Ok(post_info)
+        }
+
+        /// Claims bonus reward for a smart contract on behalf of the specified account, if eligible.
+        ///
+        /// Can be called by anyone, but the reward is always paid out to the staker account, never to the caller.
+        /// Behaves the same as `claim_bonus_reward`, including the automatic restaking if the staker enabled it.
+        ///
+        /// Shares the per-block limit with `claim_staker_rewards_for`.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::claim_bonus_reward_for().saturating_add(
+            T::WeightInfo::restake_reward(T::MaxNumberOfStakedContracts::get())
+        ))]
+        pub fn claim_bonus_reward_for(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            smart_contract: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            ensure_signed(origin)?;
+
+            let delegated_claims = DelegatedClaimsInBlock::<T>::get();
+            ensure!(
+                delegated_claims < T::MaxDelegatedClaimsPerBlock::get(),
+                Error::<T>::TooManyDelegatedClaims
+            );
+
+            let post_info = Self::internal_claim_bonus_reward(&account, &smart_contract, true)?;
+            DelegatedClaimsInBlock::<T>::put(delegated_claims.saturating_add(1));
+
+            Ok(post_info)
+        }
+
+        /// Used to schedule new tier parameters, which will replace the existing ones at the start of the next period.
+        ///
+        /// Tier thresholds of the tier configuration are reset to the new values at that point,
+        /// and are dynamically adjusted afterwards, same as before.
+        /// Scheduling new parameters again overwrites the previously scheduled ones.
+        ///
+        /// Can only be called by manager origin.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::maintenance_mode().saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn set_next_tier_params(
+            origin: OriginFor<T>,
+            params: TierParameters<T::NumberOfTiers>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(params.is_valid(), Error::<T>::InvalidTierParams);
+
+            NextTierParams::<T>::put(params);
+
+            let period = ActiveProtocolState::<T>::get()
+                .period_number()
+                .saturating_add(1);
+            Self::deposit_event(Event::<T>::TierParamsScheduled { period });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `true` if the account is a staker, `false` otherwise.
+        pub fn is_staker(account: &T::AccountId) -> bool {
+            Ledger::<T>::contains_key(account)
+        }
+
+        /// `Err` if pallet disabled for maintenance, `Ok` otherwise.
+        pub(crate) fn ensure_pallet_enabled() -> Result<(), Error<T>> {
+            if ActiveProtocolState::<T>::get().maintenance {
+                Err(Error::<T>::Disabled)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Ensure that the origin is either the `ManagerOrigin` or a signed origin.
+        ///
+        /// In case of manager, `Ok(None)` is returned, and if signed origin `Ok(Some(AccountId))` is returned.
+        pub(crate) fn ensure_signed_or_manager(
+            origin: T::RuntimeOrigin,
+        ) -> Result<Option<T::AccountId>, BadOrigin> {
+            if T::ManagerOrigin::ensure_origin(origin.clone()).is_ok() {
+                return Ok(None);
+            }
+            let who = ensure_signed(origin)?;
+            Ok(Some(who))
+        }
+
+        /// Update the account ledger, and dApp staking balance freeze.
+        ///
+        /// In case account ledger is empty, entries from the DB are removed and freeze is thawed.
+        ///
+        /// This call can fail if the `freeze` or `thaw` operations fail. This should never happen since
+        /// runtime definition must ensure it supports necessary freezes.
+        pub(crate) fn update_ledger(
+            account: &T::AccountId,
+            ledger: AccountLedgerFor<T>,
         ) -> Result<(), DispatchError> {
             if ledger.is_empty() {
                 Ledger::<T>::remove(&account);
@@ -1625,6 +2019,131 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Locks & restakes the claimed reward, in case the account has enabled automatic restaking.
+        ///
+        /// Reward is always locked, but it's only restaked if it's possible to stake at the moment, i.e.
+        /// the period doesn't end in the next era, there are no unclaimed staker rewards remaining,
+        /// and the account has an active stake on at least one registered dApp in the ongoing period.
+        /// Reward is split between the dApps proportionally to the existing stake amounts,
+        /// so no new stake entries are ever created.
+        ///
+        /// Returns the consumed weight.
+        pub(crate) fn maybe_restake_reward(
+            account: &T::AccountId,
+            ledger: &mut AccountLedgerFor<T>,
+            amount: Balance,
+            protocol_state: &ProtocolState,
+        ) -> Weight {
+            if !ledger.auto_restake {
+                return Weight::zero();
+            }
+            if amount.is_zero() {
+                return T::WeightInfo::restake_reward(0);
+            }
+
+            // 1.
+            // Lock the reward, so it can be used for staking.
+            ledger.add_lock_amount(amount);
+            CurrentEraInfo::<T>::mutate(|era_info| {
+                era_info.add_locked(amount);
+            });
+            Self::deposit_event(Event::<T>::Locked {
+                account: account.clone(),
+                amount,
+            });
+
+            // 2.
+            // Check whether staking is possible at all.
+            let current_era = protocol_state.era;
+            if protocol_state
+                .period_info
+                .is_next_period(current_era.saturating_add(1))
+            {
+                return T::WeightInfo::restake_reward(0);
+            }
+
+            let stake_entries: Vec<_> = StakerInfo::<T>::iter_prefix(account)
+                .filter(|(_, staking_info)| {
+                    staking_info.period_number() == protocol_state.period_number()
+                })
+                .filter_map(|(smart_contract, staking_info)| {
+                    IntegratedDApps::<T>::get(&smart_contract)
+                        .map(|dapp_info| (smart_contract, dapp_info.id, staking_info))
+                })
+                .collect();
+            let entries_len: u32 = stake_entries.len().unique_saturated_into();
+
+            let total_staked = stake_entries
+                .iter()
+                .fold(Balance::zero(), |sum, (_, _, staking_info)| {
+                    sum.saturating_add(staking_info.total_staked_amount())
+                });
+            if total_staked.is_zero()
+                || ledger
+                    .add_stake_amount(amount, current_era, protocol_state.period_info)
+                    .is_err()
+            {
+                return T::WeightInfo::restake_reward(entries_len);
+            }
+
+            // 3.
+            // Split the reward between the staked dApps, with any rounding remainder going to the last one.
+            let mut remaining_amount = amount;
+            for (idx, (smart_contract, dapp_id, mut staking_info)) in
+                stake_entries.into_iter().enumerate()
+            {
+                let stake_amount = if idx.saturating_add(1) == entries_len as usize {
+                    remaining_amount
+                } else {
+                    Perbill::from_rational(staking_info.total_staked_amount(), total_staked)
+                        * amount
+                };
+                remaining_amount.saturating_reduce(stake_amount);
+
+                if stake_amount.is_zero() {
+                    continue;
+                }
+
+                let old_loyalty_stake = Self::loyalty_stake(&staking_info);
+                staking_info.stake(stake_amount, current_era, protocol_state.subperiod());
+                let new_loyalty_stake = Self::loyalty_stake(&staking_info);
+                StakerInfo::<T>::insert(account, &smart_contract, staking_info);
+
+                ContractStake::<T>::mutate(&dapp_id, |contract_stake_info| {
+                    contract_stake_info.stake(
+                        stake_amount,
+                        protocol_state.period_info,
+                        current_era,
+                    );
+                });
+                CurrentEraInfo::<T>::mutate(|era_info| {
+                    era_info.add_stake_amount(stake_amount, protocol_state.subperiod());
+                    era_info.update_loyalty_stake(old_loyalty_stake, new_loyalty_stake);
+                });
+
+                Self::deposit_event(Event::<T>::Stake {
+                    account: account.clone(),
+                    smart_contract,
+                    amount: stake_amount,
+                });
+            }
+
+            T::WeightInfo::restake_reward(entries_len)
+        }
+
+        /// Additional voting subperiod stake attributed to the staking info entry, due to the loyalty multiplier.
+        ///
+        /// For `n` consecutive loyal periods, the multiplier is `1 + step * (n - 1)`, with `n` capped at `T::MaxLoyaltyPeriods`.
+        pub(crate) fn loyalty_stake(staking_info: &SingularStakingInfo) -> Balance {
+            let extra_periods = staking_info
+                .loyal_periods()
+                .min(T::MaxLoyaltyPeriods::get())
+                .saturating_sub(1);
+
+            (T::LoyaltyMultiplierStep::get() * staking_info.staked_amount(Subperiod::Voting))
+                .saturating_mul(extra_periods.into())
+        }
+
         /// Returns the number of blocks per voting period.
         pub(crate) fn blocks_per_voting_period() -> BlockNumber {
             T::CycleConfiguration::blocks_per_era()
@@ -1660,6 +2179,353 @@ pub mod pallet {
             dapp_tiers.dapps.into_inner()
         }
 
+        /// Returns the dApp tier assignment for the specified past era, if it's still available in storage.
+        ///
+        /// dApps which have already claimed their reward for the era are no longer part of the assignment.
+        pub fn get_dapp_tier_assignment_for_era(
+            era: EraNumber,
+        ) -> Option<BTreeMap<DAppId, TierId>> {
+            DAppTiers::<T>::get(&era).map(|dapp_tiers| dapp_tiers.dapps.into_inner())
+        }
+
+        /// Returns the dApp tier assignment & tier rewards for the specified past era, if it's still available in storage.
+        ///
+        /// Entries are removed once they expire, so callers interested in the full history must persist them on their own.
+        pub fn get_dapp_tier_rewards_for_era(era: EraNumber) -> Option<DAppTierRewardsFor<T>> {
+            DAppTiers::<T>::get(&era)
+        }
+
+        /// Returns the total amount of staker rewards the account could claim at the moment.
+        ///
+        /// Covers all of the claimable eras, even if multiple `claim_staker_rewards` calls would be required to claim them.
+        pub fn pending_staker_rewards(account: &T::AccountId) -> Balance {
+            Self::staker_rewards_per_era(account)
+                .into_iter()
+                .fold(Balance::zero(), |sum, (_, reward)| {
+                    sum.saturating_add(reward)
+                })
+        }
+
+        /// Returns the staker rewards the account could claim at the moment, per era.
+        ///
+        /// Covers all of the claimable eras, even if multiple `claim_staker_rewards` calls would be required to claim them.
+        pub fn staker_rewards_per_era(account: &T::AccountId) -> Vec<(EraNumber, Balance)> {
+            let mut ledger = Ledger::<T>::get(account);
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let mut rewards_per_era = Vec::new();
+            while let Ok((rewards, _)) = Self::internal_calculate_staker_rewards(
+                &mut ledger,
+                &protocol_state,
+                EraNumber::MAX,
+            ) {
+                rewards_per_era.extend(rewards);
+            }
+
+            rewards_per_era
+        }
+
+        /// Returns the bonus reward the account could claim at the moment for the specified smart contract.
+        ///
+        /// In case no bonus reward can be claimed, zero is returned.
+        pub fn bonus_reward(account: &T::AccountId, smart_contract: &T::SmartContract) -> Balance {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            StakerInfo::<T>::get(account, smart_contract)
+                .and_then(|staker_info| {
+                    Self::internal_calculate_bonus_reward(&staker_info, &protocol_state).ok()
+                })
+                .unwrap_or_default()
+        }
+
+        /// Returns all of the bonus rewards the account could claim at the moment, per smart contract.
+        pub fn pending_bonus_rewards(account: &T::AccountId) -> Vec<(T::SmartContract, Balance)> {
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            StakerInfo::<T>::iter_prefix(account)
+                .filter_map(|(smart_contract, staker_info)| {
+                    Self::internal_calculate_bonus_reward(&staker_info, &protocol_state)
+                        .ok()
+                        .map(|bonus_reward| (smart_contract, bonus_reward))
+                })
+                .collect()
+        }
+
+        /// Returns all of the unclaimed dApp rewards for the specified smart contract, per era.
+        pub fn pending_dapp_rewards(
+            smart_contract: &T::SmartContract,
+        ) -> Vec<(EraNumber, Balance)> {
+            let dapp_id = match IntegratedDApps::<T>::get(smart_contract) {
+                Some(dapp_info) => dapp_info.id,
+                None => return Vec::new(),
+            };
+
+            let protocol_state = ActiveProtocolState::<T>::get();
+            let mut rewards: Vec<_> = DAppTiers::<T>::iter_keys()
+                .filter_map(|era| {
+                    Self::internal_calculate_dapp_reward(dapp_id, era, &protocol_state)
+                        .ok()
+                        .map(|(amount, _, _)| (era, amount))
+                })
+                .collect();
+            rewards.sort_unstable_by_key(|(era, _)| *era);
+
+            rewards
+        }
+
+        /// Claims staker rewards for the oldest claimable reward span of the specified account,
+        /// and pays them out to the account itself.
+        ///
+        /// `delegated` indicates whether the claim was made on behalf of the account by a third party.
+        /// Returns the actual consumed weight.
+        pub(crate) fn internal_claim_staker_rewards(
+            account: &T::AccountId,
+            delegated: bool,
+        ) -> DispatchResultWithPostInfo {
+            let mut ledger = Ledger::<T>::get(account);
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let (rewards, period_end) = Self::internal_calculate_staker_rewards(
+                &mut ledger,
+                &protocol_state,
+                EraNumber::MAX,
+            )?;
+            let reward_sum = rewards.iter().fold(Balance::zero(), |sum, (_, reward)| {
+                sum.saturating_add(*reward)
+            });
+            let rewards_len: u32 = rewards.len().unique_saturated_into();
+
+            T::StakingRewardHandler::payout_reward(account, reward_sum)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            rewards.into_iter().for_each(|(era, reward)| {
+                Self::deposit_event(Event::<T>::Reward {
+                    account: account.clone(),
+                    era,
+                    amount: reward,
+                });
+            });
+
+            let restake_weight =
+                Self::maybe_restake_reward(account, &mut ledger, reward_sum, &protocol_state);
+            Self::update_ledger(account, ledger)?;
+
+            Ok(Some(
+                match (period_end.is_some(), delegated) {
+                    (true, false) => T::WeightInfo::claim_staker_rewards_past_period(rewards_len),
+                    (false, false) => {
+                        T::WeightInfo::claim_staker_rewards_ongoing_period(rewards_len)
+                    }
+                    (true, true) => {
+                        T::WeightInfo::claim_staker_rewards_for_past_period(rewards_len)
+                    }
+                    (false, true) => {
+                        T::WeightInfo::claim_staker_rewards_for_ongoing_period(rewards_len)
+                    }
+                }
+                .saturating_add(restake_weight),
+            )
+            .into())
+        }
+
+        /// Claims the bonus reward for the specified smart contract, and pays it out to the account itself.
+        ///
+        /// `delegated` indicates whether the claim was made on behalf of the account by a third party.
+        /// Returns the actual consumed weight.
+        pub(crate) fn internal_claim_bonus_reward(
+            account: &T::AccountId,
+            smart_contract: &T::SmartContract,
+            delegated: bool,
+        ) -> DispatchResultWithPostInfo {
+            let staker_info = StakerInfo::<T>::get(account, smart_contract)
+                .ok_or(Error::<T>::NoClaimableRewards)?;
+            let protocol_state = ActiveProtocolState::<T>::get();
+
+            let staked_period = staker_info.period_number();
+            let bonus_reward =
+                Self::internal_calculate_bonus_reward(&staker_info, &protocol_state)?;
+
+            T::StakingRewardHandler::payout_reward(account, bonus_reward)
+                .map_err(|_| Error::<T>::RewardPayoutFailed)?;
+
+            // Cleanup entry since the reward has been claimed, but keep the loyalty streak
+            StakerInfo::<T>::remove(account, smart_contract);
+            LoyaltyStreaks::<T>::insert(
+                account,
+                smart_contract,
+                (staked_period, staker_info.loyal_periods()),
+            );
+            let mut ledger = Ledger::<T>::get(account);
+            ledger.contract_stake_count.saturating_dec();
+
+            Self::deposit_event(Event::<T>::BonusReward {
+                account: account.clone(),
+                smart_contract: smart_contract.clone(),
+                period: staked_period,
+                amount: bonus_reward,
+            });
+
+            let restake_weight = if ledger.auto_restake {
+                let restake_weight =
+                    Self::maybe_restake_reward(account, &mut ledger, bonus_reward, &protocol_state);
+                Self::update_ledger(account, ledger)?;
+                restake_weight
+            } else {
+                Ledger::<T>::insert(account, ledger);
+                Weight::zero()
+            };
+
+            let claim_weight = if delegated {
+                T::WeightInfo::claim_bonus_reward_for()
+            } else {
+                T::WeightInfo::claim_bonus_reward()
+            };
+
+            Ok(Some(claim_weight.saturating_add(restake_weight)).into())
+        }
+
+        /// Calculates the staker rewards for the next claimable span of eras, up to & including `up_to_era`,
+        /// and updates the ledger accordingly.
+        ///
+        /// Returns the `(era, reward)` pairs, alongside the period end era in case the staked period has ended.
+        /// Used by both the claim extrinsic and the read-only reward queries, to ensure they never drift apart.
+        pub(crate) fn internal_calculate_staker_rewards(
+            ledger: &mut AccountLedgerFor<T>,
+            protocol_state: &ProtocolState,
+            up_to_era: EraNumber,
+        ) -> Result<(Vec<(EraNumber, Balance)>, Option<EraNumber>), Error<T>> {
+            let staked_period = ledger
+                .staked_period()
+                .ok_or(Error::<T>::NoClaimableRewards)?;
+
+            // Check if the rewards have expired
+            ensure!(
+                staked_period >= Self::oldest_claimable_period(protocol_state.period_number()),
+                Error::<T>::RewardExpired
+            );
+
+            // Calculate the reward claim span
+            let earliest_staked_era = ledger
+                .earliest_staked_era()
+                .ok_or(Error::<T>::InternalClaimStakerError)?;
+            let era_rewards =
+                EraRewards::<T>::get(Self::era_reward_span_index(earliest_staked_era))
+                    .ok_or(Error::<T>::NoClaimableRewards)?;
+
+            // The last era for which we can theoretically claim rewards.
+            // And indicator if we know the period's ending era.
+            let (last_period_era, period_end) = if staked_period == protocol_state.period_number() {
+                (protocol_state.era.saturating_sub(1), None)
+            } else {
+                PeriodEnd::<T>::get(&staked_period)
+                    .map(|info| (info.final_era, Some(info.final_era)))
+                    .ok_or(Error::<T>::InternalClaimStakerError)?
+            };
+
+            // The last era for which we can claim rewards for this account.
+            let last_claim_era = era_rewards.last_era().min(last_period_era).min(up_to_era);
+
+            // Get chunks for reward claiming
+            let rewards_iter =
+                ledger
+                    .claim_up_to_era(last_claim_era, period_end)
+                    .map_err(|err| match err {
+                        AccountLedgerError::NothingToClaim => Error::<T>::NoClaimableRewards,
+                        _ => Error::<T>::InternalClaimStakerError,
+                    })?;
+
+            // Calculate rewards
+            let mut rewards: Vec<_> = Vec::new();
+            for (era, amount) in rewards_iter {
+                let era_reward = era_rewards
+                    .get(era)
+                    .ok_or(Error::<T>::InternalClaimStakerError)?;
+
+                // Optimization, and zero-division protection
+                if amount.is_zero() || era_reward.staked.is_zero() {
+                    continue;
+                }
+                let staker_reward = Perbill::from_rational(amount, era_reward.staked)
+                    * era_reward.staker_reward_pool;
+
+                rewards.push((era, staker_reward));
+            }
+
+            Ok((rewards, period_end))
+        }
+
+        /// Calculates the bonus reward for the provided staking info entry, if it's eligible for one.
+        ///
+        /// Used by both the claim extrinsic and the read-only reward queries, to ensure they never drift apart.
+        pub(crate) fn internal_calculate_bonus_reward(
+            staker_info: &SingularStakingInfo,
+            protocol_state: &ProtocolState,
+        ) -> Result<Balance, Error<T>> {
+            // Ensure:
+            // 1. Period for which rewards are being claimed has ended.
+            // 2. Account has been a loyal staker.
+            // 3. Rewards haven't expired.
+            let staked_period = staker_info.period_number();
+            ensure!(
+                staked_period < protocol_state.period_number(),
+                Error::<T>::NoClaimableRewards
+            );
+            ensure!(
+                staker_info.is_loyal(),
+                Error::<T>::NotEligibleForBonusReward
+            );
+            ensure!(
+                staked_period >= Self::oldest_claimable_period(protocol_state.period_number()),
+                Error::<T>::RewardExpired
+            );
+
+            let period_end_info =
+                PeriodEnd::<T>::get(&staked_period).ok_or(Error::<T>::InternalClaimBonusError)?;
+            // Defensive check - we should never get this far in function if no voting period stake exists.
+            ensure!(
+                !period_end_info.total_vp_stake.is_zero(),
+                Error::<T>::InternalClaimBonusError
+            );
+
+            // Loyalty multiplier increases the staker's share of the bonus reward pool.
+            let eligible_amount = staker_info
+                .staked_amount(Subperiod::Voting)
+                .saturating_add(Self::loyalty_stake(staker_info));
+            let total_eligible_amount = period_end_info
+                .total_vp_stake
+                .saturating_add(period_end_info.total_loyalty_stake);
+            Ok(
+                Perbill::from_rational(eligible_amount, total_eligible_amount)
+                    * period_end_info.bonus_reward_pool,
+            )
+        }
+
+        /// Calculates the dApp reward for the specified era.
+        ///
+        /// Returns the reward amount, the tier the dApp was assigned to, and the era's tier rewards with the dApp entry consumed.
+        /// Used by both the claim extrinsic and the read-only reward queries, to ensure they never drift apart.
+        pub(crate) fn internal_calculate_dapp_reward(
+            dapp_id: DAppId,
+            era: EraNumber,
+            protocol_state: &ProtocolState,
+        ) -> Result<(Balance, TierId, DAppTierRewardsFor<T>), Error<T>> {
+            // Make sure provided era has ended
+            ensure!(era < protocol_state.era, Error::<T>::InvalidClaimEra);
+
+            let mut dapp_tiers = DAppTiers::<T>::get(&era).ok_or(Error::<T>::NoDAppTierInfo)?;
+            ensure!(
+                dapp_tiers.period >= Self::oldest_claimable_period(protocol_state.period_number()),
+                Error::<T>::RewardExpired
+            );
+
+            let (amount, tier_id) = dapp_tiers.try_claim(dapp_id).map_err(|error| match error {
+                DAppTierError::NoDAppInTiers => Error::<T>::NoClaimableRewards,
+                _ => Error::<T>::InternalClaimDAppError,
+            })?;
+
+            Ok((amount, tier_id, dapp_tiers))
+        }
+
         /// Assign eligible dApps into appropriate tiers, and calculate reward for each tier.
         ///
         /// ### Algorithm
@@ -1886,6 +2752,7 @@ pub mod pallet {
                                 bonus_reward_pool,
                                 total_vp_stake: era_info.staked_amount(Subperiod::Voting),
                                 final_era: current_era,
+                                total_loyalty_stake: era_info.loyalty_stake,
                             },
                         );
 
@@ -1906,6 +2773,19 @@ pub mod pallet {
                         // Must be called with the new period number.
                         Self::update_cleanup_marker(protocol_state.period_number());
 
+                        // Apply the scheduled tier parameters, if any.
+                        // Tier thresholds are reset, so the new values are used as the basis for the dynamic adjustment.
+                        if let Some(next_tier_params) = NextTierParams::<T>::take() {
+                            TierConfig::<T>::mutate(|config| {
+                                config.tier_thresholds = next_tier_params.tier_thresholds.clone()
+                            });
+                            StaticTierParams::<T>::put(next_tier_params);
+
+                            Self::deposit_event(Event::<T>::TierParamsApplied {
+                                period: protocol_state.period_number(),
+                            });
+                        }
+
                         consumed_weight.saturating_accrue(
                             T::WeightInfo::on_initialize_build_and_earn_to_voting(),
                         );
@@ -2063,3 +2943,54 @@ pub mod pallet {
         }
     }
 }
+
+impl<T: Config> LocksTransfer<T::AccountId> for Pallet<T> {
+    fn transfer_locks(from: &T::AccountId, to: &T::AccountId) -> Result<Balance, DispatchError> {
+        let from_ledger = Ledger::<T>::get(from);
+        if from_ledger.is_empty() || from == to {
+            return Ok(Zero::zero());
+        }
+        Self::ensure_pallet_enabled()?;
+
+        // Stake entries are bound to the account, hence only locks without any stake can be transferred.
+        ensure!(
+            from_ledger.staked.is_empty()
+                && from_ledger.staked_future.is_none()
+                && from_ledger.contract_stake_count.is_zero(),
+            Error::<T>::RemainingStakePreventsLocksTransfer
+        );
+
+        let mut to_ledger = Ledger::<T>::get(to);
+        if to_ledger.is_empty() {
+            ensure!(
+                T::AccountCheck::allowed_to_stake(to),
+                Error::<T>::AccountNotAvailableForDappStaking
+            );
+        }
+
+        to_ledger.add_lock_amount(from_ledger.active_locked_amount());
+        for chunk in from_ledger.unlocking.iter() {
+            to_ledger
+                .add_unlocking_chunk(chunk.amount, chunk.unlock_block)
+                .map_err(|_| Error::<T>::TooManyUnlockingChunks)?;
+        }
+
+        // Release the funds of `from` so they can be transferred, and lock them again for `to`.
+        let amount = from_ledger.total_locked_amount();
+        Self::update_ledger(from, Default::default())?;
+        T::Currency::transfer(from, to, amount, Preservation::Expendable)?;
+        Self::update_ledger(to, to_ledger)?;
+
+        Self::deposit_event(Event::<T>::LocksTransferred {
+            from: from.clone(),
+            to: to.clone(),
+            amount,
+        });
+
+        Ok(amount)
+    }
+
+    fn transfer_locks_weight() -> Weight {
+        T::WeightInfo::transfer_locks()
+    }
+}
@@ -0,0 +1,213 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migration from storage version `6` to `7`.
+///
+/// Introduces the `auto_restake` preference into the `AccountLedger`.
+pub mod v7 {
+    use super::*;
+
+    /// `AccountLedger` layout used up to storage version `6`.
+    #[derive(Encode, Decode, Clone)]
+    pub struct AccountLedgerV6<UnlockingLen: Get<u32>> {
+        #[codec(compact)]
+        pub locked: Balance,
+        pub unlocking: BoundedVec<UnlockingChunk, UnlockingLen>,
+        pub staked: StakeAmount,
+        pub staked_future: Option<StakeAmount>,
+        #[codec(compact)]
+        pub contract_stake_count: u32,
+    }
+
+    /// Translates all `Ledger` entries into the new layout, with automatic restaking disabled.
+    pub struct MigrateToV7<T>(PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 7 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0_u64;
+            Ledger::<T>::translate::<AccountLedgerV6<T::MaxUnlockingChunks>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(AccountLedger {
+                    locked: old.locked,
+                    unlocking: old.unlocking,
+                    staked: old.staked,
+                    staked_future: old.staked_future,
+                    contract_stake_count: old.contract_stake_count,
+                    auto_restake: false,
+                })
+            });
+
+            StorageVersion::new(7).put::<Pallet<T>>();
+            log::info!(target: LOG_TARGET, "Migrated {} ledger entries to v7.", translated);
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 6,
+                "Expected storage version 6."
+            );
+            let count = Ledger::<T>::iter_keys().count() as u32;
+
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 7,
+                "Expected storage version 7."
+            );
+
+            let old_count: u32 = Decode::decode(&mut state.as_ref())
+                .map_err(|_| "Cannot decode pre-upgrade state.")?;
+            let new_count = Ledger::<T>::iter_values()
+                .filter(|ledger| !ledger.auto_restake)
+                .count() as u32;
+            ensure!(old_count == new_count, "Ledger entry count mismatch.");
+
+            Ok(())
+        }
+    }
+}
+
+/// Migration from storage version `7` to `8`.
+///
+/// Introduces the multi-period loyalty multiplier.
+/// `SingularStakingInfo` entries don't need to be translated since the new `loyal_periods` counter
+/// is encoded the same as the old `loyal_staker` flag, meaning all existing loyal stakers start with a single loyal period.
+/// Consequently, no loyalty stake exists yet, and the new fields of `EraInfo` & `PeriodEndInfo` are set to zero.
+pub mod v8 {
+    use super::*;
+
+    /// `EraInfo` layout used up to storage version `7`.
+    #[derive(Encode, Decode, Clone)]
+    pub struct EraInfoV7 {
+        #[codec(compact)]
+        pub total_locked: Balance,
+        #[codec(compact)]
+        pub unlocking: Balance,
+        pub current_stake_amount: StakeAmount,
+        pub next_stake_amount: StakeAmount,
+    }
+
+    /// `PeriodEndInfo` layout used up to storage version `7`.
+    #[derive(Encode, Decode, Clone)]
+    pub struct PeriodEndInfoV7 {
+        #[codec(compact)]
+        pub bonus_reward_pool: Balance,
+        #[codec(compact)]
+        pub total_vp_stake: Balance,
+        #[codec(compact)]
+        pub final_era: EraNumber,
+    }
+
+    /// Translates the `CurrentEraInfo` & all `PeriodEnd` entries into the new layout, without any loyalty stake.
+    pub struct MigrateToV8<T>(PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 8 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let _ = CurrentEraInfo::<T>::translate::<EraInfoV7, _>(|maybe_old| {
+                maybe_old.map(|old| EraInfo {
+                    total_locked: old.total_locked,
+                    unlocking: old.unlocking,
+                    current_stake_amount: old.current_stake_amount,
+                    next_stake_amount: old.next_stake_amount,
+                    loyalty_stake: Zero::zero(),
+                })
+            })
+            .map_err(|_| {
+                log::error!(target: LOG_TARGET, "Failed to translate CurrentEraInfo.");
+            });
+
+            let mut translated = 0_u64;
+            PeriodEnd::<T>::translate::<PeriodEndInfoV7, _>(|_, old| {
+                translated.saturating_inc();
+                Some(PeriodEndInfo {
+                    bonus_reward_pool: old.bonus_reward_pool,
+                    total_vp_stake: old.total_vp_stake,
+                    final_era: old.final_era,
+                    total_loyalty_stake: Zero::zero(),
+                })
+            });
+
+            StorageVersion::new(8).put::<Pallet<T>>();
+            log::info!(
+                target: LOG_TARGET,
+                "Migrated era info & {} period end entries to v8.",
+                translated
+            );
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(2), translated.saturating_add(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 7,
+                "Expected storage version 7."
+            );
+            let count = PeriodEnd::<T>::iter_keys().count() as u32;
+
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 8,
+                "Expected storage version 8."
+            );
+
+            let old_count: u32 = Decode::decode(&mut state.as_ref())
+                .map_err(|_| "Cannot decode pre-upgrade state.")?;
+            let new_count = PeriodEnd::<T>::iter_values()
+                .filter(|period_end_info| period_end_info.total_loyalty_stake.is_zero())
+                .count() as u32;
+            ensure!(old_count == new_count, "PeriodEnd entry count mismatch.");
+            ensure!(
+                CurrentEraInfo::<T>::get().loyalty_stake.is_zero(),
+                "No loyalty stake should exist."
+            );
+            ensure!(
+                StakerInfo::<T>::iter_values()
+                    .all(|staking_info| staking_info.loyal_periods() <= 1),
+                "Staking info entries must have at most a single loyal period."
+            );
+
+            Ok(())
+        }
+    }
+}
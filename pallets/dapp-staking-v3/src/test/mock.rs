@@ -32,7 +32,7 @@ use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Permill,
+    BuildStorage, Perbill, Permill,
 };
 use sp_std::cell::RefCell;
 
@@ -195,6 +195,11 @@ impl AccountCheck<AccountId> for DummyAccountCheck {
     }
 }
 
+parameter_types! {
+    pub const LoyaltyMultiplierStep: Perbill = Perbill::from_percent(10);
+    pub const MaxLoyaltyPeriods: u8 = 3;
+}
+
 impl pallet_dapp_staking::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
@@ -208,6 +213,8 @@ impl pallet_dapp_staking::Config for Test {
     type AccountCheck = DummyAccountCheck;
     type TierSlots = StandardTierSlots;
     type EraRewardSpanLength = ConstU32<8>;
+    type MaxRewardSpansPerClaim = ConstU32<4>;
+    type MaxDelegatedClaimsPerBlock = ConstU32<2>;
     type RewardRetentionInPeriods = ConstU32<2>;
     type MaxNumberOfContracts = ConstU32<10>;
     type MaxUnlockingChunks = ConstU32<5>;
@@ -216,6 +223,8 @@ impl pallet_dapp_staking::Config for Test {
     type MaxNumberOfStakedContracts = ConstU32<5>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
+    type LoyaltyMultiplierStep = LoyaltyMultiplierStep;
+    type MaxLoyaltyPeriods = MaxLoyaltyPeriods;
     type WeightInfo = weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -275,6 +284,7 @@ impl ExtBuilder {
                     era: 2,
                     period: 1,
                 },
+                loyalty_stake: 0,
             });
 
             // Init tier params
@@ -20,8 +20,8 @@ use crate::test::mock::*;
 use crate::types::*;
 use crate::{
     pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, DAppTiers,
-    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, NextDAppId,
-    PeriodEnd, PeriodEndInfo, StakerInfo,
+    EraRewards, Event, FreezeReason, HistoryCleanupMarker, IntegratedDApps, Ledger, LoyaltyStreaks,
+    NextDAppId, PeriodEnd, PeriodEndInfo, StakerInfo,
 };
 
 use frame_support::{
@@ -607,6 +607,17 @@ pub(crate) fn assert_stake(
         post_era_info.staked_amount_next_era(stake_subperiod),
         pre_era_info.staked_amount_next_era(stake_subperiod) + amount
     );
+    let pre_loyalty_stake = match pre_staker_info {
+        Some(pre_staker_info) if pre_staker_info.period_number() == stake_period => {
+            expected_loyalty_stake(pre_staker_info)
+        }
+        _ => 0,
+    };
+    assert_eq!(
+        post_era_info.loyalty_stake,
+        pre_era_info.loyalty_stake - pre_loyalty_stake + expected_loyalty_stake(post_staker_info),
+        "Loyalty stake must be updated according to the new staking info."
+    );
 }
 
 /// Unstake some funds from the specified smart contract.
@@ -949,15 +960,18 @@ pub(crate) fn assert_claim_bonus_reward(account: AccountId, smart_contract: &Moc
     let pre_free_balance = <Test as Config>::Currency::free_balance(&account);
 
     let staked_period = pre_staker_info.period_number();
-    let stake_amount = pre_staker_info.staked_amount(Subperiod::Voting);
+    let stake_amount =
+        pre_staker_info.staked_amount(Subperiod::Voting) + expected_loyalty_stake(pre_staker_info);
 
     let period_end_info = pre_snapshot
         .period_end
         .get(&staked_period)
         .expect("Entry must exist, since it's a past period.");
 
-    let reward = Perbill::from_rational(stake_amount, period_end_info.total_vp_stake)
-        * period_end_info.bonus_reward_pool;
+    let reward = Perbill::from_rational(
+        stake_amount,
+        period_end_info.total_vp_stake + period_end_info.total_loyalty_stake,
+    ) * period_end_info.bonus_reward_pool;
 
     // Claim bonus reward & verify event
     assert_ok!(DappStaking::claim_bonus_reward(
@@ -991,6 +1005,11 @@ pub(crate) fn assert_claim_bonus_reward(account: AccountId, smart_contract: &Moc
         !StakerInfo::<Test>::contains_key(&account, smart_contract),
         "Entry must be removed after successful reward claim."
     );
+    assert_eq!(
+        LoyaltyStreaks::<Test>::get(&account, smart_contract),
+        Some((staked_period, pre_staker_info.loyal_periods())),
+        "Loyalty streak must be kept after successful reward claim."
+    );
     assert_eq!(
         pre_snapshot.ledger[&account].contract_stake_count,
         Ledger::<Test>::get(&account).contract_stake_count + 1,
@@ -1122,6 +1141,10 @@ pub(crate) fn assert_unstake_from_unregistered(
         !StakerInfo::<Test>::contains_key(&account, smart_contract),
         "Entry must be deleted since contract is unregistered."
     );
+    assert!(
+        !LoyaltyStreaks::<Test>::contains_key(&account, smart_contract),
+        "Loyalty streak must be deleted since contract is unregistered."
+    );
 
     // 3. verify era info
     // =========================
@@ -1208,6 +1231,10 @@ pub(crate) fn assert_cleanup_expired_entries(account: AccountId) {
             .contains_key(&(account, **contract)));
     });
 
+    // Ensure that loyalty streaks which can no longer be continued have been removed
+    assert!(LoyaltyStreaks::<Test>::iter_prefix(&account)
+        .all(|(_, (loyal_period, _))| loyal_period + 1 >= current_period));
+
     // Ensure that ledger has been correctly updated
     let pre_ledger = pre_snapshot.ledger.get(&account).unwrap();
     let post_ledger = post_snapshot.ledger.get(&account).unwrap();
@@ -1389,6 +1416,11 @@ pub(crate) fn assert_block_bump(pre_snapshot: &MemorySnapshot) {
                 .current_era_info
                 .staked_amount(Subperiod::Voting),
         );
+        assert_eq!(
+            period_end_info.total_loyalty_stake,
+            pre_snapshot.current_era_info.loyalty_stake,
+        );
+        assert!(post_snapshot.current_era_info.loyalty_stake.is_zero());
     }
 
     // 5. Verify history cleanup marker update
@@ -1567,3 +1599,16 @@ pub(crate) fn is_account_ledger_expired(
         _ => false,
     }
 }
+
+/// Expected additional voting subperiod stake of the staking info entry, due to the loyalty multiplier.
+pub(crate) fn expected_loyalty_stake(staking_info: &SingularStakingInfo) -> Balance {
+    let max_loyal_periods: u8 = <Test as Config>::MaxLoyaltyPeriods::get();
+    let extra_periods = staking_info
+        .loyal_periods()
+        .min(max_loyal_periods)
+        .saturating_sub(1);
+
+    <Test as Config>::LoyaltyMultiplierStep::get()
+        * staking_info.staked_amount(Subperiod::Voting)
+        * Balance::from(extra_periods)
+}
@@ -18,24 +18,25 @@
 
 use crate::test::{mock::*, testing_utils::*};
 use crate::{
-    pallet::Config, ActiveProtocolState, ContractStake, DAppId, EraRewards, Error, Event,
-    ForcingType, IntegratedDApps, Ledger, NextDAppId, PeriodNumber, Safeguard, StakerInfo,
-    Subperiod, TierConfig,
+    pallet::Config, ActiveProtocolState, ContractStake, CurrentEraInfo, DAppId, EraRewards, Error,
+    Event, ForcingType, FreezeReason, IntegratedDApps, Ledger, LoyaltyStreaks, NextDAppId,
+    NextTierParams, PeriodNumber, Safeguard, StakerInfo, StaticTierParams, Subperiod, TierConfig,
+    TierThreshold,
 };
 
 use frame_support::{
     assert_noop, assert_ok, assert_storage_noop,
     error::BadOrigin,
     traits::{
-        fungible::Unbalanced as FunUnbalanced, Currency, Get, OnFinalize, OnInitialize,
-        ReservableCurrency,
+        fungible::{InspectFreeze, Unbalanced as FunUnbalanced},
+        Currency, Get, OnFinalize, OnInitialize, ReservableCurrency,
     },
     BoundedVec,
 };
-use sp_runtime::{traits::Zero, FixedU128};
+use sp_runtime::{traits::Zero, FixedU128, Perbill, Permill};
 
 use astar_primitives::{
-    dapp_staking::{CycleConfiguration, EraNumber, SmartContractHandle},
+    dapp_staking::{CycleConfiguration, EraNumber, LocksTransfer, SmartContractHandle},
     Balance, BlockNumber,
 };
 
@@ -1624,6 +1625,148 @@ fn claim_bonus_reward_works() {
     })
 }
 
+#[test]
+fn loyalty_multiplier_increases_bonus_reward_share() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let (loyal_account, new_account) = (2, 3);
+        let lock_amount = 300;
+        assert_lock(loyal_account, lock_amount);
+        assert_lock(new_account, lock_amount);
+
+        // Loyal account stakes in the first period, and claims the bonus reward in the next one
+        let stake_amount = 100;
+        assert_stake(loyal_account, &smart_contract, stake_amount);
+        advance_to_next_period();
+        assert_claim_bonus_reward(loyal_account, &smart_contract);
+        for _ in 0..required_number_of_reward_claims(loyal_account) {
+            assert_claim_staker_rewards(loyal_account);
+        }
+
+        // Both accounts stake the same amount during the voting subperiod, but only one continues the loyalty streak
+        assert_stake(loyal_account, &smart_contract, stake_amount);
+        assert_stake(new_account, &smart_contract, stake_amount);
+        assert_eq!(
+            StakerInfo::<Test>::get(&loyal_account, &smart_contract)
+                .unwrap()
+                .loyal_periods(),
+            2
+        );
+        assert_eq!(
+            StakerInfo::<Test>::get(&new_account, &smart_contract)
+                .unwrap()
+                .loyal_periods(),
+            1
+        );
+        assert!(LoyaltyStreaks::<Test>::get(&loyal_account, &smart_contract).is_none());
+
+        let step: Perbill = <Test as Config>::LoyaltyMultiplierStep::get();
+        assert_eq!(
+            CurrentEraInfo::<Test>::get().loyalty_stake,
+            step * stake_amount
+        );
+
+        // Partial unstake reduces the loyalty stake accordingly
+        let unstake_amount = 10;
+        assert_unstake(loyal_account, &smart_contract, unstake_amount);
+        assert_eq!(
+            CurrentEraInfo::<Test>::get().loyalty_stake,
+            step * (stake_amount - unstake_amount)
+        );
+        assert_stake(loyal_account, &smart_contract, unstake_amount);
+
+        // Advance to the next period, loyal account gets a larger share of the bonus reward pool
+        advance_to_next_period();
+        let loyal_bonus_reward = DappStaking::bonus_reward(&loyal_account, &smart_contract);
+        let new_bonus_reward = DappStaking::bonus_reward(&new_account, &smart_contract);
+        assert!(loyal_bonus_reward > new_bonus_reward);
+
+        assert_claim_bonus_reward(loyal_account, &smart_contract);
+        assert_claim_bonus_reward(new_account, &smart_contract);
+    })
+}
+
+#[test]
+fn loyalty_streak_requires_consecutive_voting_subperiod_stake() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let (account_1, account_2) = (2, 3);
+        let lock_amount = 300;
+        let stake_amount = 100;
+        for account in [account_1, account_2] {
+            assert_lock(account, lock_amount);
+            assert_stake(account, &smart_contract, stake_amount);
+        }
+
+        advance_to_next_period();
+        for account in [account_1, account_2] {
+            assert_claim_bonus_reward(account, &smart_contract);
+            for _ in 0..required_number_of_reward_claims(account) {
+                assert_claim_staker_rewards(account);
+            }
+        }
+
+        // 1st scenario - staking during the build&earn subperiod breaks the streak
+        advance_to_next_subperiod();
+        assert_stake(account_1, &smart_contract, stake_amount);
+        assert!(!StakerInfo::<Test>::get(&account_1, &smart_contract)
+            .unwrap()
+            .is_loyal());
+        assert!(LoyaltyStreaks::<Test>::get(&account_1, &smart_contract).is_none());
+
+        // 2nd scenario - skipping a period breaks the streak
+        advance_to_next_period();
+        assert_stake(account_2, &smart_contract, stake_amount);
+        assert_eq!(
+            StakerInfo::<Test>::get(&account_2, &smart_contract)
+                .unwrap()
+                .loyal_periods(),
+            1
+        );
+        assert!(CurrentEraInfo::<Test>::get().loyalty_stake.is_zero());
+    })
+}
+
+#[test]
+fn loyalty_streak_is_capped() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        let stake_amount = 100;
+        assert_lock(account, lock_amount);
+
+        let max_loyal_periods: u8 = <Test as Config>::MaxLoyaltyPeriods::get();
+        for expected_loyal_periods in 1..=max_loyal_periods + 2 {
+            assert_stake(account, &smart_contract, stake_amount);
+            assert_eq!(
+                StakerInfo::<Test>::get(&account, &smart_contract)
+                    .unwrap()
+                    .loyal_periods(),
+                expected_loyal_periods.min(max_loyal_periods)
+            );
+
+            advance_to_next_period();
+            assert_claim_bonus_reward(account, &smart_contract);
+            for _ in 0..required_number_of_reward_claims(account) {
+                assert_claim_staker_rewards(account);
+            }
+        }
+    })
+}
+
 #[test]
 fn claim_bonus_reward_double_call_fails() {
     ExtBuilder::build().execute_with(|| {
@@ -2115,6 +2258,44 @@ fn cleanup_expired_entries_is_ok() {
     })
 }
 
+#[test]
+fn cleanup_expired_entries_removes_expired_loyalty_streaks() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let account = 2;
+        assert_lock(account, 300);
+        assert_stake(account, &smart_contract, 100);
+
+        // Claim the bonus reward in the next period, the loyalty streak is kept
+        advance_to_next_period();
+        assert_claim_bonus_reward(account, &smart_contract);
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+        assert!(LoyaltyStreaks::<Test>::contains_key(
+            &account,
+            &smart_contract
+        ));
+
+        // Streak can still be continued, so it isn't considered expired
+        assert_noop!(
+            DappStaking::cleanup_expired_entries(RuntimeOrigin::signed(account)),
+            Error::<Test>::NoExpiredEntries
+        );
+
+        // Period in which the streak could have been continued has passed
+        advance_to_next_period();
+        assert_cleanup_expired_entries(account);
+        assert!(!LoyaltyStreaks::<Test>::contains_key(
+            &account,
+            &smart_contract
+        ));
+    })
+}
+
 #[test]
 fn cleanup_expired_entries_fails_with_no_entries() {
     ExtBuilder::build().execute_with(|| {
@@ -2962,3 +3143,1307 @@ fn safeguard_on_by_default() {
         assert!(Safeguard::<Test>::get());
     });
 }
+
+#[test]
+fn pending_staker_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Nothing to claim yet
+        assert!(DappStaking::pending_staker_rewards(&account).is_zero());
+
+        // Advance into the next period, so multiple claim calls are required
+        advance_to_next_period();
+        let pending_rewards = DappStaking::pending_staker_rewards(&account);
+        assert!(!pending_rewards.is_zero());
+
+        let init_balance = Balances::free_balance(&account);
+        for _ in 0..required_number_of_reward_claims(account) {
+            assert_claim_staker_rewards(account);
+        }
+
+        assert_eq!(
+            Balances::free_balance(&account) - init_balance,
+            pending_rewards
+        );
+        assert!(DappStaking::pending_staker_rewards(&account).is_zero());
+    })
+}
+
+#[test]
+fn pending_bonus_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Bonus reward only becomes claimable once the period ends
+        assert!(DappStaking::pending_bonus_rewards(&account).is_empty());
+        advance_to_next_period();
+
+        let pending_rewards = DappStaking::pending_bonus_rewards(&account);
+        assert_eq!(pending_rewards.len(), 1);
+        assert_eq!(pending_rewards[0].0, smart_contract);
+
+        let init_balance = Balances::free_balance(&account);
+        assert_claim_bonus_reward(account, &smart_contract);
+        assert_eq!(
+            Balances::free_balance(&account) - init_balance,
+            pending_rewards[0].1
+        );
+        assert!(DappStaking::pending_bonus_rewards(&account).is_empty());
+    })
+}
+
+#[test]
+fn pending_dapp_rewards_matches_claimed_amount() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance a few eras so we have multiple entries for reward claiming
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let pending_rewards = DappStaking::pending_dapp_rewards(&smart_contract);
+        assert!(!pending_rewards.is_empty());
+        assert!(
+            pending_rewards.windows(2).all(|w| w[0].0 < w[1].0),
+            "Must be sorted by era."
+        );
+
+        // Tier assignment for each pending era must be available
+        for (era, _) in pending_rewards.iter() {
+            assert!(DappStaking::get_dapp_tier_assignment_for_era(*era).is_some());
+        }
+
+        // Claim the first era, and ensure it's no longer reported as pending
+        let (first_era, first_reward) = pending_rewards[0];
+        let init_balance = Balances::free_balance(&dev_account);
+        assert_claim_dapp_reward(account, &smart_contract, first_era);
+        assert_eq!(
+            Balances::free_balance(&dev_account) - init_balance,
+            first_reward
+        );
+        assert_eq!(
+            DappStaking::pending_dapp_rewards(&smart_contract),
+            pending_rewards[1..].to_vec()
+        );
+
+        // Non-existing contract has no pending rewards
+        assert!(
+            DappStaking::pending_dapp_rewards(&MockSmartContract::wasm(2 as AccountId)).is_empty()
+        );
+    })
+}
+
+#[test]
+fn staker_rewards_per_era_and_bonus_reward_are_consistent() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        advance_to_next_period();
+
+        // Per-era staker rewards must be sorted, and add up to the total pending amount
+        let rewards_per_era = DappStaking::staker_rewards_per_era(&account);
+        assert!(!rewards_per_era.is_empty());
+        assert!(rewards_per_era.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(
+            rewards_per_era
+                .iter()
+                .map(|(_, reward)| reward)
+                .sum::<Balance>(),
+            DappStaking::pending_staker_rewards(&account)
+        );
+
+        // Bonus reward for the staked contract must match the claimed amount, and other contracts have none
+        let bonus_reward = DappStaking::bonus_reward(&account, &smart_contract);
+        assert!(!bonus_reward.is_zero());
+        assert!(
+            DappStaking::bonus_reward(&account, &MockSmartContract::wasm(2 as AccountId)).is_zero()
+        );
+
+        let init_balance = Balances::free_balance(&account);
+        assert_claim_bonus_reward(account, &smart_contract);
+        assert_eq!(
+            Balances::free_balance(&account) - init_balance,
+            bonus_reward
+        );
+        assert!(DappStaking::bonus_reward(&account, &smart_contract).is_zero());
+    })
+}
+
+#[test]
+fn claim_staker_rewards_up_to_claims_all_spans() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Advance into the next period, so multiple spans need to be claimed
+        advance_to_next_period();
+        assert!(
+            required_number_of_reward_claims(account) > 1,
+            "Sanity check."
+        );
+        let pending_rewards = DappStaking::pending_staker_rewards(&account);
+        let last_era = DappStaking::staker_rewards_per_era(&account)
+            .last()
+            .map(|(era, _)| *era)
+            .unwrap();
+
+        // Claim everything in a single call
+        let init_balance = Balances::free_balance(&account);
+        assert_ok!(DappStaking::claim_staker_rewards_up_to(
+            RuntimeOrigin::signed(account),
+            EraNumber::MAX
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::BatchReward {
+            account,
+            last_era,
+            amount: pending_rewards,
+        }));
+
+        assert_eq!(
+            Balances::free_balance(&account) - init_balance,
+            pending_rewards
+        );
+        assert!(Ledger::<Test>::get(&account).staked.is_empty());
+        assert!(DappStaking::pending_staker_rewards(&account).is_zero());
+    })
+}
+
+#[test]
+fn claim_staker_rewards_up_to_respects_era_bound() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        let stake_amount = 93;
+        assert_stake(account, &smart_contract, stake_amount);
+
+        // Advance a few eras, so rewards span across multiple reward spans
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 14);
+        let rewards_per_era = DappStaking::staker_rewards_per_era(&account);
+
+        // Claim up to an era in the middle of the second span
+        let up_to_era = rewards_per_era[0].0 + <Test as Config>::EraRewardSpanLength::get();
+        let (claimed, remaining): (Vec<_>, Vec<_>) = rewards_per_era
+            .into_iter()
+            .partition(|(era, _)| *era <= up_to_era);
+        let expected_amount = claimed.iter().map(|(_, reward)| reward).sum::<Balance>();
+
+        let init_balance = Balances::free_balance(&account);
+        assert_ok!(DappStaking::claim_staker_rewards_up_to(
+            RuntimeOrigin::signed(account),
+            up_to_era
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::BatchReward {
+            account,
+            last_era: up_to_era,
+            amount: expected_amount,
+        }));
+        assert_eq!(
+            Balances::free_balance(&account) - init_balance,
+            expected_amount
+        );
+
+        // Only the eras after the bound remain claimable
+        assert_eq!(DappStaking::staker_rewards_per_era(&account), remaining);
+
+        // Nothing more to claim up to the same bound
+        assert_noop!(
+            DappStaking::claim_staker_rewards_up_to(RuntimeOrigin::signed(account), up_to_era),
+            Error::<Test>::NoClaimableRewards,
+        );
+    })
+}
+
+#[test]
+fn set_auto_restake_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Account without a ledger cannot set the preference
+        let account = 2;
+        assert_noop!(
+            DappStaking::set_auto_restake(RuntimeOrigin::signed(account), true),
+            Error::<Test>::AccountNotStaker,
+        );
+
+        // Lock some amount, and enable the automatic restaking
+        assert_lock(account, 300);
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::AutoRestakeSet {
+            account,
+            enabled: true,
+        }));
+        assert!(Ledger::<Test>::get(&account).auto_restake);
+
+        // Disable it again
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            false
+        ));
+        assert!(!Ledger::<Test>::get(&account).auto_restake);
+    })
+}
+
+#[test]
+fn claim_staker_rewards_with_auto_restake_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+
+        // Lock & stake on both contracts, with 1:3 ratio
+        let account = 2;
+        let lock_amount = 1000;
+        assert_lock(account, lock_amount);
+        assert_stake(account, &smart_contract_1, 100);
+        assert_stake(account, &smart_contract_2, 300);
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+
+        // Advance a few eras, but stay within the same reward span & period
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        assert_eq!(
+            required_number_of_reward_claims(account),
+            1,
+            "Sanity check."
+        );
+        let reward = DappStaking::pending_staker_rewards(&account);
+        assert!(!reward.is_zero());
+
+        let current_period = ActiveProtocolState::<Test>::get().period_number();
+        let pre_era_info = CurrentEraInfo::<Test>::get();
+
+        // Claim rewards, and expect them to be locked & restaked
+        assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
+            account
+        )));
+
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.active_locked_amount(), lock_amount + reward);
+        assert_eq!(ledger.staked_amount(current_period), 400 + reward);
+
+        let restaked_1 = Perbill::from_rational(100_u128, 400) * reward;
+        let restaked_2 = reward - restaked_1;
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_1)
+                .unwrap()
+                .total_staked_amount(),
+            100 + restaked_1
+        );
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_2)
+                .unwrap()
+                .total_staked_amount(),
+            300 + restaked_2
+        );
+
+        let post_era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(
+            post_era_info.total_locked,
+            pre_era_info.total_locked + reward
+        );
+        assert_eq!(
+            post_era_info.total_staked_amount_next_era(),
+            pre_era_info.total_staked_amount_next_era() + reward
+        );
+
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Stake {
+            account,
+            smart_contract: smart_contract_2,
+            amount: restaked_2,
+        }));
+    })
+}
+
+#[test]
+fn auto_restake_only_locks_when_staking_not_possible() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let lock_amount = 300;
+        assert_lock(account, lock_amount);
+        assert_stake(account, &smart_contract, 100);
+        assert_ok!(DappStaking::set_auto_restake(
+            RuntimeOrigin::signed(account),
+            true
+        ));
+
+        // Move to the next period, so the stake entry is from the past period
+        advance_to_next_period();
+        let reward = DappStaking::pending_staker_rewards(&account);
+
+        assert_ok!(DappStaking::claim_staker_rewards_up_to(
+            RuntimeOrigin::signed(account),
+            EraNumber::MAX
+        ));
+
+        // Reward is locked, but nothing is staked
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.active_locked_amount(), lock_amount + reward);
+        assert!(ledger.staked.is_empty());
+        assert!(ledger.staked_future.is_none());
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::Locked {
+            account,
+            amount: reward,
+        }));
+    })
+}
+
+#[test]
+fn migrate_to_v7_works() {
+    use crate::migration::v7::{AccountLedgerV6, MigrateToV7};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use parity_scale_codec::Encode;
+
+    ExtBuilder::build().execute_with(|| {
+        StorageVersion::new(6).put::<DappStaking>();
+
+        // Insert a ledger entry using the old layout
+        let account = 2;
+        let old_ledger = AccountLedgerV6::<<Test as Config>::MaxUnlockingChunks> {
+            locked: 300,
+            unlocking: Default::default(),
+            staked: Default::default(),
+            staked_future: None,
+            contract_stake_count: 2,
+        };
+        frame_support::storage::unhashed::put_raw(
+            &Ledger::<Test>::hashed_key_for(&account),
+            &old_ledger.encode(),
+        );
+
+        MigrateToV7::<Test>::on_runtime_upgrade();
+
+        assert_eq!(DappStaking::on_chain_storage_version(), 7);
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.locked, 300);
+        assert_eq!(ledger.contract_stake_count, 2);
+        assert!(!ledger.auto_restake);
+    })
+}
+
+#[test]
+fn move_stake_basic_example_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+
+        // Lock & stake on the first contract during the voting subperiod
+        let account = 2;
+        let amount = 400;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract_1, amount);
+        let ledger_snapshot = Ledger::<Test>::get(&account);
+        let era_info_snapshot = CurrentEraInfo::<Test>::get();
+
+        // Move part of the stake to the second contract
+        let move_amount = 150;
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            smart_contract_1.clone(),
+            smart_contract_2.clone(),
+            move_amount,
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeMoved {
+            account,
+            source_contract: smart_contract_1.clone(),
+            destination_contract: smart_contract_2.clone(),
+            amount: move_amount,
+        }));
+
+        // Staker info of both contracts is updated, and loyalty is kept on both
+        let source_info = StakerInfo::<Test>::get(&account, &smart_contract_1).unwrap();
+        let destination_info = StakerInfo::<Test>::get(&account, &smart_contract_2).unwrap();
+        assert_eq!(source_info.total_staked_amount(), amount - move_amount);
+        assert_eq!(destination_info.total_staked_amount(), move_amount);
+        assert_eq!(
+            destination_info.staked_amount(Subperiod::Voting),
+            move_amount
+        );
+        assert!(source_info.is_loyal());
+        assert!(destination_info.is_loyal());
+
+        // Contract stake is updated
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        let dapp_1_id = IntegratedDApps::<Test>::get(&smart_contract_1).unwrap().id;
+        let dapp_2_id = IntegratedDApps::<Test>::get(&smart_contract_2).unwrap().id;
+        assert_eq!(
+            ContractStake::<Test>::get(&dapp_1_id).total_staked_amount(period),
+            amount - move_amount
+        );
+        assert_eq!(
+            ContractStake::<Test>::get(&dapp_2_id).total_staked_amount(period),
+            move_amount
+        );
+
+        // Total stake of the account remains the same
+        let ledger = Ledger::<Test>::get(&account);
+        assert_eq!(ledger.staked, ledger_snapshot.staked);
+        assert_eq!(ledger.staked_future, ledger_snapshot.staked_future);
+        assert_eq!(ledger.contract_stake_count, 2);
+        assert_eq!(CurrentEraInfo::<Test>::get(), era_info_snapshot);
+    })
+}
+
+#[test]
+fn move_stake_keeps_current_era_stake_active() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+
+        // Stake so some amount is active in the current era, and some only from the next one
+        let account = 2;
+        let voting_amount = 400;
+        let bep_amount = 50;
+        assert_lock(account, voting_amount + bep_amount);
+        assert_stake(account, &smart_contract_1, voting_amount);
+        advance_to_next_era();
+        assert_stake(account, &smart_contract_1, bep_amount);
+
+        // Move more than what was staked in the current era
+        let move_amount = 220;
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            smart_contract_1.clone(),
+            smart_contract_2.clone(),
+            move_amount,
+        ));
+
+        // Amount moved out of the current era of the source contract is active in the current era of the destination contract
+        let current_era = ActiveProtocolState::<Test>::get().era;
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        let current_era_amount = move_amount - bep_amount;
+
+        let dapp_1_id = IntegratedDApps::<Test>::get(&smart_contract_1).unwrap().id;
+        let dapp_2_id = IntegratedDApps::<Test>::get(&smart_contract_2).unwrap().id;
+        let source_contract_stake = ContractStake::<Test>::get(&dapp_1_id);
+        let destination_contract_stake = ContractStake::<Test>::get(&dapp_2_id);
+
+        assert_eq!(
+            source_contract_stake
+                .get(current_era, period)
+                .unwrap()
+                .total(),
+            voting_amount - current_era_amount
+        );
+        assert_eq!(
+            destination_contract_stake
+                .get(current_era, period)
+                .unwrap()
+                .total(),
+            current_era_amount
+        );
+        assert_eq!(
+            source_contract_stake
+                .get(current_era + 1, period)
+                .unwrap()
+                .total(),
+            voting_amount + bep_amount - move_amount
+        );
+        assert_eq!(
+            destination_contract_stake
+                .get(current_era + 1, period)
+                .unwrap()
+                .total(),
+            move_amount
+        );
+    })
+}
+
+#[test]
+fn move_stake_keeps_loyalty_in_build_and_earn_subperiod() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+
+        // Stake during voting subperiod, and then some more during build&earn subperiod
+        let account = 2;
+        let voting_amount = 100;
+        let bep_amount = 50;
+        assert_lock(account, voting_amount + bep_amount);
+        assert_stake(account, &smart_contract_1, voting_amount);
+        advance_to_next_subperiod();
+        assert_stake(account, &smart_contract_1, bep_amount);
+
+        // Move everything to the second contract, loyalty must be preserved
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            smart_contract_1.clone(),
+            smart_contract_2.clone(),
+            voting_amount + bep_amount,
+        ));
+
+        assert!(StakerInfo::<Test>::get(&account, &smart_contract_1).is_none());
+        let destination_info = StakerInfo::<Test>::get(&account, &smart_contract_2).unwrap();
+        assert!(destination_info.is_loyal());
+        assert_eq!(
+            destination_info.staked_amount(Subperiod::Voting),
+            voting_amount
+        );
+        assert_eq!(
+            destination_info.staked_amount(Subperiod::BuildAndEarn),
+            bep_amount
+        );
+        assert_eq!(Ledger::<Test>::get(&account).contract_stake_count, 1);
+    })
+}
+
+#[test]
+fn move_stake_merges_loyalty_streaks() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        let smart_contract_3 = MockSmartContract::wasm(3 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+        assert_register(dev_account, &smart_contract_3);
+
+        // Stay loyal to the first contract for multiple periods, and stake on the second one for the first time
+        let account = 2;
+        let stake_amount = 100;
+        assert_lock(account, 3 * stake_amount);
+
+        let max_loyal_periods: u8 = <Test as Config>::MaxLoyaltyPeriods::get();
+        for _ in 1..max_loyal_periods {
+            assert_stake(account, &smart_contract_1, stake_amount);
+            advance_to_next_period();
+            assert_claim_bonus_reward(account, &smart_contract_1);
+            for _ in 0..required_number_of_reward_claims(account) {
+                assert_claim_staker_rewards(account);
+            }
+        }
+        assert_stake(account, &smart_contract_1, stake_amount);
+        assert_stake(account, &smart_contract_2, stake_amount);
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_1)
+                .unwrap()
+                .loyal_periods(),
+            max_loyal_periods
+        );
+
+        // 1st scenario - moving into a new entry keeps the whole streak
+        let move_amount = 30;
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            smart_contract_1.clone(),
+            smart_contract_3.clone(),
+            move_amount,
+        ));
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_3)
+                .unwrap()
+                .loyal_periods(),
+            max_loyal_periods
+        );
+
+        // 2nd scenario - moving into an entry with a shorter streak keeps the shorter one
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            smart_contract_1.clone(),
+            smart_contract_2.clone(),
+            move_amount,
+        ));
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_2)
+                .unwrap()
+                .loyal_periods(),
+            1
+        );
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_1)
+                .unwrap()
+                .loyal_periods(),
+            max_loyal_periods
+        );
+
+        // Loyalty stake follows the streaks of the entries
+        let step: Perbill = <Test as Config>::LoyaltyMultiplierStep::get();
+        let extra_periods = Balance::from(max_loyal_periods - 1);
+        assert_eq!(
+            CurrentEraInfo::<Test>::get().loyalty_stake,
+            (step * (stake_amount - 2 * move_amount)) * extra_periods
+                + (step * move_amount) * extra_periods
+        );
+    })
+}
+
+#[test]
+fn move_stake_moves_everything_if_below_minimum() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+
+        let account = 2;
+        let amount = 100;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract_1, amount);
+
+        // Remaining amount would be below the minimum, so everything is moved
+        let minimum_stake_amount: Balance = <Test as Config>::MinimumStakeAmount::get();
+        assert_ok!(DappStaking::move_stake(
+            RuntimeOrigin::signed(account),
+            smart_contract_1.clone(),
+            smart_contract_2.clone(),
+            amount - minimum_stake_amount + 1,
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::StakeMoved {
+            account,
+            source_contract: smart_contract_1.clone(),
+            destination_contract: smart_contract_2.clone(),
+            amount,
+        }));
+
+        assert!(StakerInfo::<Test>::get(&account, &smart_contract_1).is_none());
+        assert_eq!(
+            StakerInfo::<Test>::get(&account, &smart_contract_2)
+                .unwrap()
+                .total_staked_amount(),
+            amount
+        );
+        assert_eq!(Ledger::<Test>::get(&account).contract_stake_count, 1);
+    })
+}
+
+#[test]
+fn move_stake_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contracts
+        let dev_account = 1;
+        let smart_contract_1 = MockSmartContract::wasm(1 as AccountId);
+        let smart_contract_2 = MockSmartContract::wasm(2 as AccountId);
+        let smart_contract_3 = MockSmartContract::wasm(3 as AccountId);
+        assert_register(dev_account, &smart_contract_1);
+        assert_register(dev_account, &smart_contract_2);
+
+        let account = 2;
+        let amount = 100;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract_1, amount);
+
+        // Zero amount
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1.clone(),
+                smart_contract_2.clone(),
+                0,
+            ),
+            Error::<Test>::ZeroAmount,
+        );
+
+        // Same contracts
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1.clone(),
+                smart_contract_1.clone(),
+                10,
+            ),
+            Error::<Test>::SameContracts,
+        );
+
+        // Destination contract isn't registered
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1.clone(),
+                smart_contract_3.clone(),
+                10,
+            ),
+            Error::<Test>::ContractNotFound,
+        );
+
+        // No stake on the source contract
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_2.clone(),
+                smart_contract_1.clone(),
+                10,
+            ),
+            Error::<Test>::NoStakingInfo,
+        );
+
+        // Amount is too large
+        assert_noop!(
+            DappStaking::move_stake(
+                RuntimeOrigin::signed(account),
+                smart_contract_1.clone(),
+                smart_contract_2.clone(),
+                amount + 1,
+            ),
+            Error::<Test>::UnstakeAmountTooLarge,
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_for_pays_out_to_staker() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let staker = 2;
+        assert_lock(staker, 300);
+        assert_stake(staker, &smart_contract, 93);
+
+        // Advance a few eras so some rewards can be claimed
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 3);
+        let expected_rewards: Balance = DappStaking::staker_rewards_per_era(&staker)
+            .into_iter()
+            .map(|(_, reward)| reward)
+            .sum();
+        assert!(!expected_rewards.is_zero(), "Sanity check.");
+
+        // Claim on behalf of the staker, by a third party
+        let claimer = 3;
+        let staker_init_balance = Balances::free_balance(&staker);
+        let claimer_init_balance = Balances::free_balance(&claimer);
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(claimer),
+            staker
+        ));
+
+        // Rewards are paid out to the staker, never to the claimer
+        assert_eq!(
+            Balances::free_balance(&staker) - staker_init_balance,
+            expected_rewards
+        );
+        assert_eq!(Balances::free_balance(&claimer), claimer_init_balance);
+        assert!(DappStaking::pending_staker_rewards(&staker).is_zero());
+
+        // Nothing left to claim
+        assert_noop!(
+            DappStaking::claim_staker_rewards_for(RuntimeOrigin::signed(claimer), staker),
+            Error::<Test>::NoClaimableRewards,
+        );
+    })
+}
+
+#[test]
+fn claim_staker_rewards_for_respects_block_limit() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, and prepare more stakers than the block limit allows
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let max_claims: u32 = <Test as Config>::MaxDelegatedClaimsPerBlock::get();
+        let stakers: Vec<AccountId> = (0..=max_claims).map(|x| x as AccountId + 2).collect();
+        for staker in &stakers {
+            assert_lock(*staker, 300);
+            assert_stake(*staker, &smart_contract, 100);
+        }
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+
+        // Failed claim doesn't consume the budget
+        let claimer = 1;
+        assert_noop!(
+            DappStaking::claim_staker_rewards_for(RuntimeOrigin::signed(claimer), dev_account),
+            Error::<Test>::NoClaimableRewards,
+        );
+
+        // Use up the entire budget for this block
+        for staker in stakers.iter().take(max_claims as usize) {
+            assert_ok!(DappStaking::claim_staker_rewards_for(
+                RuntimeOrigin::signed(claimer),
+                *staker
+            ));
+        }
+        let last_staker = *stakers.last().unwrap();
+        assert_noop!(
+            DappStaking::claim_staker_rewards_for(RuntimeOrigin::signed(claimer), last_staker),
+            Error::<Test>::TooManyDelegatedClaims,
+        );
+
+        // Staker can still claim for itself
+        assert_ok!(DappStaking::claim_staker_rewards(RuntimeOrigin::signed(
+            last_staker
+        )));
+
+        // Budget is reset in the following blocks
+        let staker = stakers[0];
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(claimer),
+            staker
+        ));
+    })
+}
+
+#[test]
+fn claim_bonus_reward_for_pays_out_to_staker() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let staker = 2;
+        assert_lock(staker, 300);
+        assert_stake(staker, &smart_contract, 93);
+
+        // Nothing to claim while the period is still ongoing
+        let claimer = 3;
+        assert_noop!(
+            DappStaking::claim_bonus_reward_for(
+                RuntimeOrigin::signed(claimer),
+                staker,
+                smart_contract
+            ),
+            Error::<Test>::NoClaimableRewards,
+        );
+
+        advance_to_next_period();
+        let expected_reward = DappStaking::bonus_reward(&staker, &smart_contract);
+        assert!(!expected_reward.is_zero(), "Sanity check.");
+
+        // Claim on behalf of the staker, by a third party
+        let staker_init_balance = Balances::free_balance(&staker);
+        let claimer_init_balance = Balances::free_balance(&claimer);
+        assert_ok!(DappStaking::claim_bonus_reward_for(
+            RuntimeOrigin::signed(claimer),
+            staker,
+            smart_contract
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::BonusReward {
+            account: staker,
+            smart_contract,
+            period: ActiveProtocolState::<Test>::get().period_number() - 1,
+            amount: expected_reward,
+        }));
+
+        // Reward is paid out to the staker, never to the claimer
+        assert_eq!(
+            Balances::free_balance(&staker) - staker_init_balance,
+            expected_reward
+        );
+        assert_eq!(Balances::free_balance(&claimer), claimer_init_balance);
+        assert!(!StakerInfo::<Test>::contains_key(&staker, &smart_contract));
+        assert!(LoyaltyStreaks::<Test>::contains_key(
+            &staker,
+            &smart_contract
+        ));
+
+        // Nothing left to claim
+        assert_noop!(
+            DappStaking::claim_bonus_reward_for(
+                RuntimeOrigin::signed(claimer),
+                staker,
+                smart_contract
+            ),
+            Error::<Test>::NoClaimableRewards,
+        );
+    })
+}
+
+#[test]
+fn claim_bonus_reward_for_shares_block_limit() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, and prepare more stakers than the block limit allows
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let max_claims: u32 = <Test as Config>::MaxDelegatedClaimsPerBlock::get();
+        let stakers: Vec<AccountId> = (0..=max_claims).map(|x| x as AccountId + 2).collect();
+        for staker in &stakers {
+            assert_lock(*staker, 300);
+            assert_stake(*staker, &smart_contract, 100);
+        }
+        advance_to_next_period();
+
+        // Use up the entire budget for this block, with both staker & bonus reward claims
+        let claimer = 1;
+        let (first_staker, other_stakers) = stakers.split_first().unwrap();
+        assert_ok!(DappStaking::claim_staker_rewards_for(
+            RuntimeOrigin::signed(claimer),
+            *first_staker
+        ));
+        for staker in other_stakers.iter().take(max_claims as usize - 1) {
+            assert_ok!(DappStaking::claim_bonus_reward_for(
+                RuntimeOrigin::signed(claimer),
+                *staker,
+                smart_contract
+            ));
+        }
+        let last_staker = *stakers.last().unwrap();
+        assert_noop!(
+            DappStaking::claim_bonus_reward_for(
+                RuntimeOrigin::signed(claimer),
+                last_staker,
+                smart_contract
+            ),
+            Error::<Test>::TooManyDelegatedClaims,
+        );
+
+        // Staker can still claim for itself
+        assert_claim_bonus_reward(last_staker, &smart_contract);
+
+        // Budget is reset in the following blocks
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+        assert_ok!(DappStaking::claim_bonus_reward_for(
+            RuntimeOrigin::signed(claimer),
+            *first_staker,
+            smart_contract
+        ));
+    })
+}
+
+#[test]
+fn get_dapp_tier_rewards_for_era_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        // Register smart contract, lock&stake some amount
+        let dev_account = 1;
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(dev_account, &smart_contract);
+
+        let account = 2;
+        let amount = 300;
+        assert_lock(account, amount);
+        assert_stake(account, &smart_contract, amount);
+
+        // Advance a few eras so tier rewards for past eras are available
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 2);
+        let protocol_state = ActiveProtocolState::<Test>::get();
+        let past_era = protocol_state.era - 1;
+        let dapp_id = IntegratedDApps::<Test>::get(&smart_contract).unwrap().id;
+
+        let tier_rewards = DappStaking::get_dapp_tier_rewards_for_era(past_era)
+            .expect("Tier rewards for past era must exist.");
+        assert_eq!(tier_rewards.period, protocol_state.period_number());
+        assert!(tier_rewards.dapps.contains_key(&dapp_id));
+        assert!(!tier_rewards.rewards.is_empty());
+        assert_eq!(
+            Some(tier_rewards.dapps.clone().into_inner()),
+            DappStaking::get_dapp_tier_assignment_for_era(past_era)
+        );
+
+        // Once the reward is claimed, dApp is no longer part of the stored assignment
+        assert_claim_dapp_reward(account, &smart_contract, past_era);
+        assert!(!DappStaking::get_dapp_tier_rewards_for_era(past_era)
+            .unwrap()
+            .dapps
+            .contains_key(&dapp_id));
+
+        // Ongoing era has no tier rewards yet
+        assert!(DappStaking::get_dapp_tier_rewards_for_era(protocol_state.era).is_none());
+    })
+}
+
+#[test]
+fn set_next_tier_params_works() {
+    ExtBuilder::build().execute_with(|| {
+        // Prepare new tier params, based on the existing ones
+        let mut tier_params = StaticTierParams::<Test>::get();
+        tier_params.reward_portion = BoundedVec::try_from(vec![
+            Permill::from_percent(50),
+            Permill::from_percent(30),
+            Permill::from_percent(15),
+            Permill::from_percent(5),
+        ])
+        .unwrap();
+
+        // Only manager origin can schedule new params
+        assert_noop!(
+            DappStaking::set_next_tier_params(RuntimeOrigin::signed(1), tier_params.clone()),
+            BadOrigin
+        );
+
+        assert_ok!(DappStaking::set_next_tier_params(
+            RuntimeOrigin::root(),
+            tier_params.clone()
+        ));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::TierParamsScheduled {
+            period: ActiveProtocolState::<Test>::get().period_number() + 1,
+        }));
+        assert_eq!(NextTierParams::<Test>::get(), Some(tier_params));
+    })
+}
+
+#[test]
+fn set_next_tier_params_with_invalid_params_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let valid_tier_params = StaticTierParams::<Test>::get();
+
+        // Reward portion sum exceeds 100%
+        let mut tier_params = valid_tier_params.clone();
+        tier_params.reward_portion[0] = Permill::from_percent(90);
+        assert_noop!(
+            DappStaking::set_next_tier_params(RuntimeOrigin::root(), tier_params),
+            Error::<Test>::InvalidTierParams
+        );
+
+        // Slot distribution sum exceeds 100%
+        let mut tier_params = valid_tier_params.clone();
+        tier_params.slot_distribution[0] = Permill::from_percent(90);
+        assert_noop!(
+            DappStaking::set_next_tier_params(RuntimeOrigin::root(), tier_params),
+            Error::<Test>::InvalidTierParams
+        );
+
+        // Number of entries doesn't match the number of tiers
+        let mut tier_params = valid_tier_params;
+        tier_params.tier_thresholds.pop();
+        assert_noop!(
+            DappStaking::set_next_tier_params(RuntimeOrigin::root(), tier_params),
+            Error::<Test>::InvalidTierParams
+        );
+    })
+}
+
+#[test]
+fn scheduled_tier_params_are_applied_at_next_period() {
+    ExtBuilder::build().execute_with(|| {
+        let init_tier_params = StaticTierParams::<Test>::get();
+
+        // Schedule new tier params
+        let mut tier_params = init_tier_params.clone();
+        tier_params.reward_portion = BoundedVec::try_from(vec![
+            Permill::from_percent(50),
+            Permill::from_percent(30),
+            Permill::from_percent(15),
+            Permill::from_percent(5),
+        ])
+        .unwrap();
+        tier_params.tier_thresholds[3] = TierThreshold::FixedTvlAmount { amount: 17 };
+        assert_ok!(DappStaking::set_next_tier_params(
+            RuntimeOrigin::root(),
+            tier_params.clone()
+        ));
+
+        // Params are not applied during the ongoing period
+        advance_to_next_subperiod();
+        advance_to_era(ActiveProtocolState::<Test>::get().era + 1);
+        assert_eq!(StaticTierParams::<Test>::get(), init_tier_params);
+        assert_eq!(NextTierParams::<Test>::get(), Some(tier_params.clone()));
+
+        // Once the new period starts, params are applied
+        advance_to_next_period();
+        let period = ActiveProtocolState::<Test>::get().period_number();
+        assert_eq!(StaticTierParams::<Test>::get(), tier_params);
+        assert!(NextTierParams::<Test>::get().is_none());
+        assert!(System::events()
+            .iter()
+            .any(|record| record.event
+                == RuntimeEvent::DappStaking(Event::TierParamsApplied { period })));
+
+        let tier_config = TierConfig::<Test>::get();
+        assert_eq!(tier_config.reward_portion, tier_params.reward_portion);
+        assert_eq!(
+            tier_config.tier_thresholds[3],
+            TierThreshold::FixedTvlAmount { amount: 17 }
+        );
+    })
+}
+
+#[test]
+fn migrate_to_v8_works() {
+    use crate::migration::v8::{EraInfoV7, MigrateToV8, PeriodEndInfoV7};
+    use crate::{PeriodEnd, StakeAmount};
+    use frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+
+    ExtBuilder::build().execute_with(|| {
+        StorageVersion::new(7).put::<DappStaking>();
+
+        // Prepare entries in the old format
+        let stake_amount = StakeAmount {
+            voting: 11,
+            build_and_earn: 17,
+            era: 3,
+            period: 2,
+        };
+        let old_era_info = EraInfoV7 {
+            total_locked: 31,
+            unlocking: 7,
+            current_stake_amount: stake_amount,
+            next_stake_amount: stake_amount,
+        };
+        unhashed::put(&CurrentEraInfo::<Test>::hashed_key(), &old_era_info);
+
+        let period = 1;
+        let old_period_end_info = PeriodEndInfoV7 {
+            bonus_reward_pool: 1000,
+            total_vp_stake: 11,
+            final_era: 2,
+        };
+        unhashed::put(
+            &PeriodEnd::<Test>::hashed_key_for(period),
+            &old_period_end_info,
+        );
+
+        MigrateToV8::<Test>::on_runtime_upgrade();
+
+        assert_eq!(DappStaking::on_chain_storage_version(), 8);
+
+        let era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(era_info.total_locked, old_era_info.total_locked);
+        assert_eq!(era_info.unlocking, old_era_info.unlocking);
+        assert_eq!(era_info.current_stake_amount, stake_amount);
+        assert_eq!(era_info.next_stake_amount, stake_amount);
+        assert!(era_info.loyalty_stake.is_zero());
+
+        let period_end_info = PeriodEnd::<Test>::get(period).unwrap();
+        assert_eq!(
+            period_end_info.bonus_reward_pool,
+            old_period_end_info.bonus_reward_pool
+        );
+        assert_eq!(
+            period_end_info.total_vp_stake,
+            old_period_end_info.total_vp_stake
+        );
+        assert_eq!(period_end_info.final_era, old_period_end_info.final_era);
+        assert!(period_end_info.total_loyalty_stake.is_zero());
+
+        // Running it again is a no-op
+        assert_storage_noop!(MigrateToV8::<Test>::on_runtime_upgrade());
+    })
+}
+
+#[test]
+fn transfer_locks_is_ok() {
+    ExtBuilder::build().execute_with(|| {
+        let (from, to) = (2, 3);
+        assert_lock(from, 300);
+        assert_unlock(from, 100);
+        assert_lock(to, 150);
+
+        let from_ledger = Ledger::<Test>::get(&from);
+        let (from_balance, to_balance) =
+            (Balances::total_balance(&from), Balances::total_balance(&to));
+        let pre_era_info = CurrentEraInfo::<Test>::get();
+
+        // Transfer locked & unlocking funds
+        assert_eq!(DappStaking::transfer_locks(&from, &to), Ok(300));
+        System::assert_last_event(RuntimeEvent::DappStaking(Event::LocksTransferred {
+            from,
+            to,
+            amount: 300,
+        }));
+
+        // Locks are removed from the source account
+        assert!(!Ledger::<Test>::contains_key(&from));
+        assert!(Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &from).is_zero());
+        assert_eq!(Balances::total_balance(&from), from_balance - 300);
+
+        // And added to the destination account
+        let to_ledger = Ledger::<Test>::get(&to);
+        assert_eq!(to_ledger.active_locked_amount(), 150 + 200);
+        assert_eq!(to_ledger.unlocking, from_ledger.unlocking);
+        assert_eq!(
+            Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &to),
+            150 + 300
+        );
+        assert_eq!(Balances::total_balance(&to), to_balance + 300);
+
+        // Total locked amount is unchanged
+        assert_eq!(CurrentEraInfo::<Test>::get(), pre_era_info);
+
+        // Nothing to transfer for accounts without locks
+        assert_eq!(DappStaking::transfer_locks(&from, &to), Ok(0));
+    })
+}
+
+#[test]
+fn transfer_locks_with_stake_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (from, to) = (2, 3);
+        assert_lock(from, 300);
+        assert_stake(from, &smart_contract, 100);
+
+        assert_noop!(
+            DappStaking::transfer_locks(&from, &to),
+            Error::<Test>::RemainingStakePreventsLocksTransfer
+        );
+
+        // Fails in maintenance mode too
+        assert_ok!(DappStaking::maintenance_mode(RuntimeOrigin::root(), true));
+        assert_noop!(
+            DappStaking::transfer_locks(&from, &to),
+            Error::<Test>::Disabled
+        );
+    })
+}
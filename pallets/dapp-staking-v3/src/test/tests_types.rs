@@ -16,7 +16,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use astar_primitives::{dapp_staking::StandardTierSlots, Balance};
+use astar_primitives::{
+    dapp_staking::{
+        AccountLedgerInfo, ProtocolStateInfo, StakeAmountInfo, StandardTierSlots, SubperiodInfo,
+        UnlockingChunkInfo,
+    },
+    Balance,
+};
 use frame_support::assert_ok;
 use sp_arithmetic::fixed_point::FixedU128;
 use sp_runtime::Permill;
@@ -149,6 +155,32 @@ fn protocol_state_basic_checks() {
     assert!(protocol_state.is_new_era(next_era_start_2));
 }
 
+#[test]
+fn protocol_state_info_conversion() {
+    let protocol_state = ProtocolState {
+        era: 7,
+        next_era_start: 113,
+        period_info: PeriodInfo {
+            number: 3,
+            subperiod: Subperiod::BuildAndEarn,
+            next_subperiod_start_era: 19,
+        },
+        maintenance: true,
+    };
+
+    assert_eq!(
+        ProtocolStateInfo::from(protocol_state),
+        ProtocolStateInfo {
+            era: 7,
+            next_era_start: 113,
+            period: 3,
+            subperiod: SubperiodInfo::BuildAndEarn,
+            next_subperiod_start_era: 19,
+            maintenance: true,
+        }
+    );
+}
+
 #[test]
 fn dapp_info_basic_checks() {
     let owner = 1;
@@ -185,6 +217,42 @@ fn account_ledger_default() {
     assert!(acc_ledger.active_locked_amount().is_zero());
 }
 
+#[test]
+fn account_ledger_info_conversion() {
+    get_u32_type!(UnlockingDummy, 5);
+    let mut acc_ledger = AccountLedger::<UnlockingDummy>::default();
+    acc_ledger.add_lock_amount(100);
+    assert_ok!(acc_ledger.add_unlocking_chunk(17, 29));
+    acc_ledger.staked_future = Some(StakeAmount {
+        voting: 31,
+        build_and_earn: 37,
+        era: 3,
+        period: 2,
+    });
+    acc_ledger.contract_stake_count = 1;
+    acc_ledger.auto_restake = true;
+
+    assert_eq!(
+        AccountLedgerInfo::from(acc_ledger),
+        AccountLedgerInfo {
+            locked: 100,
+            unlocking: vec![UnlockingChunkInfo {
+                amount: 17,
+                unlock_block: 29,
+            }],
+            staked: StakeAmountInfo::default(),
+            staked_future: Some(StakeAmountInfo {
+                voting: 31,
+                build_and_earn: 37,
+                era: 3,
+                period: 2,
+            }),
+            contract_stake_count: 1,
+            auto_restake: true,
+        }
+    );
+}
+
 #[test]
 fn account_ledger_add_lock_amount_works() {
     get_u32_type!(UnlockingDummy, 5);
@@ -1899,6 +1967,7 @@ fn era_info_migrate_to_next_era_works() {
             era: 3,
             period: 1,
         },
+        loyalty_stake: 7,
     };
 
     // 1st scenario - rollover to next era, no subperiod change
@@ -1908,6 +1977,7 @@ fn era_info_migrate_to_next_era_works() {
 
         assert_eq!(era_info.total_locked, era_info_snapshot.total_locked);
         assert_eq!(era_info.unlocking, era_info_snapshot.unlocking);
+        assert_eq!(era_info.loyalty_stake, era_info_snapshot.loyalty_stake);
         assert_eq!(
             era_info.current_stake_amount,
             era_info_snapshot.next_stake_amount
@@ -1942,6 +2012,7 @@ fn era_info_migrate_to_next_era_works() {
 
         assert_eq!(era_info.total_locked, era_info_snapshot.total_locked);
         assert_eq!(era_info.unlocking, era_info_snapshot.unlocking);
+        assert!(era_info.loyalty_stake.is_zero());
         assert_eq!(
             era_info.current_stake_amount,
             StakeAmount {
@@ -2237,6 +2308,35 @@ fn singular_staking_info_unstake_during_bep_is_ok() {
     assert_eq!(staking_info.previous_staked.era, era_2 - 1);
 }
 
+#[test]
+fn singular_staking_info_continue_loyalty_works() {
+    let max_loyal_periods = 3;
+
+    // New loyal staker continues the streak
+    let mut staking_info = SingularStakingInfo::new(2, Subperiod::Voting);
+    assert_eq!(staking_info.loyal_periods(), 1);
+    staking_info.continue_loyalty(1, max_loyal_periods);
+    assert_eq!(staking_info.loyal_periods(), 2);
+
+    // Streak is capped
+    let mut staking_info = SingularStakingInfo::new(2, Subperiod::Voting);
+    staking_info.continue_loyalty(max_loyal_periods, max_loyal_periods);
+    assert_eq!(staking_info.loyal_periods(), max_loyal_periods);
+
+    // Non-loyal staker cannot continue the streak
+    let mut staking_info = SingularStakingInfo::new(2, Subperiod::BuildAndEarn);
+    staking_info.continue_loyalty(1, max_loyal_periods);
+    assert!(!staking_info.is_loyal());
+
+    // Losing loyalty resets the streak
+    let mut staking_info = SingularStakingInfo::new(2, Subperiod::Voting);
+    staking_info.stake(100, 5, Subperiod::Voting);
+    staking_info.continue_loyalty(1, max_loyal_periods);
+    staking_info.stake(50, 6, Subperiod::BuildAndEarn);
+    let _ = staking_info.unstake(60, 6, Subperiod::BuildAndEarn);
+    assert_eq!(staking_info.loyal_periods(), 0);
+}
+
 #[test]
 fn contract_stake_amount_basic_get_checks_work() {
     // Sanity checks for empty struct
@@ -2478,6 +2578,56 @@ fn contract_stake_amount_stake_is_ok() {
     assert!(contract_stake.staked_future.is_some());
 }
 
+#[test]
+fn contract_stake_amount_stake_current_era_is_ok() {
+    let mut contract_stake = ContractStakeAmount::default();
+
+    let era = 3;
+    let period_info = PeriodInfo {
+        number: 5,
+        subperiod: Subperiod::BuildAndEarn,
+        next_subperiod_start_era: 20,
+    };
+
+    // 1st scenario - nothing staked on the contract yet
+    let amount_1 = 23;
+    contract_stake.stake(amount_1, period_info, era);
+    contract_stake.stake_current_era(amount_1, period_info, era);
+
+    let current_entry = contract_stake.get(era, period_info.number).unwrap();
+    assert_eq!(current_entry.total(), amount_1);
+    assert_eq!(current_entry.for_type(Subperiod::BuildAndEarn), amount_1);
+    assert_eq!(
+        contract_stake
+            .get(era + 1, period_info.number)
+            .unwrap()
+            .total(),
+        amount_1
+    );
+
+    // 2nd scenario - some stake already exists, only part of the amount is active in the current era
+    let next_era = era + 1;
+    let amount_2 = 17;
+    let current_era_amount_2 = 11;
+    contract_stake.stake(amount_2, period_info, next_era);
+    contract_stake.stake_current_era(current_era_amount_2, period_info, next_era);
+
+    assert_eq!(
+        contract_stake
+            .get(next_era, period_info.number)
+            .unwrap()
+            .total(),
+        amount_1 + current_era_amount_2
+    );
+    assert_eq!(
+        contract_stake
+            .get(next_era + 1, period_info.number)
+            .unwrap()
+            .total(),
+        amount_1 + amount_2
+    );
+}
+
 #[test]
 fn contract_stake_amount_basic_unstake_is_ok() {
     let mut contract_stake = ContractStakeAmount::default();
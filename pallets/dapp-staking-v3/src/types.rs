@@ -75,7 +75,10 @@ use sp_runtime::{
 pub use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, vec::Vec};
 
 use astar_primitives::{
-    dapp_staking::{DAppId, EraNumber, PeriodNumber, TierId, TierSlots as TierSlotsFunc},
+    dapp_staking::{
+        AccountLedgerInfo, DAppId, DAppTierRewardsInfo, EraNumber, PeriodNumber, ProtocolStateInfo,
+        StakeAmountInfo, SubperiodInfo, TierId, TierSlots as TierSlotsFunc, UnlockingChunkInfo,
+    },
     Balance, BlockNumber,
 };
 
@@ -114,7 +117,19 @@ pub enum AccountLedgerError {
 }
 
 /// Distinct subperiods in dApp staking protocol.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub enum Subperiod {
     /// Subperiod during which the focus is on voting. No rewards are earned during this subperiod.
     Voting,
@@ -133,7 +148,19 @@ impl Subperiod {
 }
 
 /// Info about the ongoing period.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub struct PeriodInfo {
     /// Period number.
     #[codec(compact)]
@@ -165,6 +192,9 @@ pub struct PeriodEndInfo {
     /// Final era, inclusive, in which the period ended.
     #[codec(compact)]
     pub final_era: EraNumber,
+    /// Additional voting subperiod stake attributed to loyal stakers, due to their loyalty multiplier.
+    #[codec(compact)]
+    pub total_loyalty_stake: Balance,
 }
 
 /// Force types to speed up the next era, and even period.
@@ -177,7 +207,19 @@ pub enum ForcingType {
 }
 
 /// General information & state of the dApp staking protocol.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub struct ProtocolState {
     /// Ongoing era number.
     #[codec(compact)]
@@ -271,7 +313,20 @@ impl<AccountId> DAppInfo<AccountId> {
 }
 
 /// How much was unlocked in some block.
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Default, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    Default,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 pub struct UnlockingChunk {
     /// Amount undergoing the unlocking period.
     #[codec(compact)]
@@ -363,6 +418,8 @@ pub struct AccountLedger<UnlockingLen: Get<u32>> {
     /// Number of contract stake entries in storage.
     #[codec(compact)]
     pub contract_stake_count: u32,
+    /// Whether claimed rewards should be automatically locked & restaked.
+    pub auto_restake: bool,
 }
 
 impl<UnlockingLen> Default for AccountLedger<UnlockingLen>
@@ -376,6 +433,7 @@ where
             staked: StakeAmount::default(),
             staked_future: None,
             contract_stake_count: Zero::zero(),
+            auto_restake: false,
         }
     }
 }
@@ -815,7 +873,20 @@ impl Iterator for EraStakePairIter {
 }
 
 /// Describes stake amount in an particular era/period.
-#[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, Default)]
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Default,
+    Serialize,
+    Deserialize,
+)]
 pub struct StakeAmount {
     /// Amount of staked funds accounting for the voting subperiod.
     #[codec(compact)]
@@ -893,6 +964,9 @@ pub struct EraInfo {
     pub current_stake_amount: StakeAmount,
     /// Stake amount valid from the next era.
     pub next_stake_amount: StakeAmount,
+    /// Additional voting subperiod stake attributed to loyal stakers in the ongoing period, due to their loyalty multiplier.
+    #[codec(compact)]
+    pub loyalty_stake: Balance,
 }
 
 impl EraInfo {
@@ -923,6 +997,12 @@ impl EraInfo {
         self.next_stake_amount.subtract(amount);
     }
 
+    /// Replace the `old` loyalty stake contribution of a staker with the `new` one.
+    pub fn update_loyalty_stake(&mut self, old: Balance, new: Balance) {
+        self.loyalty_stake.saturating_reduce(old);
+        self.loyalty_stake.saturating_accrue(new);
+    }
+
     /// Total staked amount in this era.
     pub fn total_staked_amount(&self) -> Balance {
         self.current_stake_amount.total()
@@ -957,6 +1037,7 @@ impl EraInfo {
                     stake_amount.era.saturating_inc();
                     stake_amount.period.saturating_inc();
                 }
+                self.loyalty_stake = Zero::zero();
             }
             Some(Subperiod::BuildAndEarn) | None => {
                 self.current_stake_amount = self.next_stake_amount;
@@ -975,8 +1056,11 @@ pub struct SingularStakingInfo {
     pub(crate) previous_staked: StakeAmount,
     /// Staked amount
     pub(crate) staked: StakeAmount,
-    /// Indicates whether a staker is a loyal staker or not.
-    pub(crate) loyal_staker: bool,
+    /// Number of consecutive periods, including this one, in which the staker has been loyal to the contract.
+    /// Zero if staker isn't a loyal staker.
+    ///
+    /// Encoding is compatible with the old `loyal_staker: bool` flag.
+    pub(crate) loyal_periods: u8,
 }
 
 impl SingularStakingInfo {
@@ -994,7 +1078,7 @@ impl SingularStakingInfo {
                 ..Default::default()
             },
             // Loyalty staking is only possible if stake is first made during the voting subperiod.
-            loyal_staker: subperiod == Subperiod::Voting,
+            loyal_periods: if subperiod == Subperiod::Voting { 1 } else { 0 },
         }
     }
 
@@ -1007,7 +1091,19 @@ impl SingularStakingInfo {
         Self {
             previous_staked,
             staked,
-            loyal_staker,
+            loyal_periods: loyal_staker.into(),
+        }
+    }
+
+    /// Continues the loyalty streak from the previous period, if staker is loyal in this one.
+    ///
+    /// The number of loyal periods is capped at `max_loyal_periods`.
+    pub fn continue_loyalty(&mut self, previous_loyal_periods: u8, max_loyal_periods: u8) {
+        if self.is_loyal() {
+            self.loyal_periods = previous_loyal_periods
+                .saturating_add(1)
+                .min(max_loyal_periods)
+                .max(1);
         }
     }
 
@@ -1054,11 +1150,13 @@ impl SingularStakingInfo {
         result.push((self.staked.era, unstaked_amount));
 
         // 2. Update loyal staker flag accordingly.
-        self.loyal_staker = self.loyal_staker
-            && match subperiod {
-                Subperiod::Voting => !self.staked.voting.is_zero(),
-                Subperiod::BuildAndEarn => self.staked.voting == staked_snapshot.voting,
-            };
+        let remains_loyal = match subperiod {
+            Subperiod::Voting => !self.staked.voting.is_zero(),
+            Subperiod::BuildAndEarn => self.staked.voting == staked_snapshot.voting,
+        };
+        if !remains_loyal {
+            self.loyal_periods = 0;
+        }
 
         // 3. Determine what was the previous staked amount.
         // This is done by simply comparing where does the _previous era_ fit in the current context.
@@ -1120,6 +1218,63 @@ impl SingularStakingInfo {
         result
     }
 
+    /// Moves the specified amount out of this entry, e.g. so it can be moved over to another contract.
+    ///
+    /// Behaves the same as `unstake`, except that the _loyalty_ flag is never removed.
+    ///
+    /// Returns the moved stake amount, split between the subperiods, alongside
+    /// the `(era, amount)` pairs as returned by `unstake`.
+    pub fn move_out(
+        &mut self,
+        amount: Balance,
+        current_era: EraNumber,
+        subperiod: Subperiod,
+    ) -> (StakeAmount, Vec<(EraNumber, Balance)>) {
+        let loyal_periods = self.loyal_periods;
+        let staked_snapshot = self.staked;
+
+        let era_and_amount_pairs = self.unstake(amount, current_era, subperiod);
+        self.loyal_periods = loyal_periods;
+
+        let moved_amount = StakeAmount {
+            voting: staked_snapshot.voting.saturating_sub(self.staked.voting),
+            build_and_earn: staked_snapshot
+                .build_and_earn
+                .saturating_sub(self.staked.build_and_earn),
+            era: current_era.saturating_add(1),
+            period: staked_snapshot.period,
+        };
+
+        (moved_amount, era_and_amount_pairs)
+    }
+
+    /// Moves the specified amount into this entry, keeping its subperiod composition.
+    ///
+    /// In case voting subperiod stake is being moved, the loyalty streak of the source entry is merged into this one:
+    /// * if the source entry isn't loyal, neither is this entry,
+    /// * if this entry has no voting subperiod stake yet, the longer of the two streaks is kept,
+    /// * otherwise, the shorter of the two streaks is kept.
+    pub fn move_in(
+        &mut self,
+        moved_amount: StakeAmount,
+        source_loyal_periods: u8,
+        current_era: EraNumber,
+    ) {
+        if !moved_amount.voting.is_zero() {
+            self.loyal_periods = if source_loyal_periods == 0 {
+                0
+            } else if self.staked.voting.is_zero() {
+                self.loyal_periods.max(source_loyal_periods)
+            } else {
+                self.loyal_periods.min(source_loyal_periods)
+            };
+        }
+
+        self.stake(moved_amount.voting, current_era, Subperiod::Voting);
+        self.staked
+            .add(moved_amount.build_and_earn, Subperiod::BuildAndEarn);
+    }
+
     /// Total staked on the contract by the user. Both subperiod stakes are included.
     pub fn total_staked_amount(&self) -> Balance {
         self.staked.total()
@@ -1132,7 +1287,12 @@ impl SingularStakingInfo {
 
     /// If `true` staker has staked during voting subperiod and has never reduced their sta
     pub fn is_loyal(&self) -> bool {
-        self.loyal_staker
+        self.loyal_periods > 0
+    }
+
+    /// Number of consecutive periods, including this one, in which the staker has been loyal to the contract.
+    pub fn loyal_periods(&self) -> u8 {
+        self.loyal_periods
     }
 
     /// Period for which this entry is relevant.
@@ -1275,6 +1435,28 @@ impl ContractStakeAmount {
         }
     }
 
+    /// Adds the specified `amount` to the current era entry, for the specified `subperiod`.
+    ///
+    /// Used when stake, which was already active in the current era, is moved over from another contract.
+    /// Must be called after `stake`, so the 'future' entry already accounts for the `amount`.
+    pub fn stake_current_era(
+        &mut self,
+        amount: Balance,
+        period_info: PeriodInfo,
+        current_era: EraNumber,
+    ) {
+        // Nothing is staked on the contract in the ongoing period yet, so a new entry is needed.
+        if self.staked.period != period_info.number {
+            self.staked = StakeAmount {
+                period: period_info.number,
+                ..Default::default()
+            };
+        }
+
+        self.staked.add(amount, period_info.subperiod);
+        self.staked.era = current_era;
+    }
+
     /// Unstake the specified `(era, amount)` pairs from the contract.
     // Important to account for the ongoing specified `subperiod` and `era` in order to align the entries.
     pub fn unstake(
@@ -1820,3 +2002,68 @@ impl CleanupMarker {
             || self.dapp_tiers_index != self.oldest_valid_era
     }
 }
+
+impl From<Subperiod> for SubperiodInfo {
+    fn from(subperiod: Subperiod) -> Self {
+        match subperiod {
+            Subperiod::Voting => SubperiodInfo::Voting,
+            Subperiod::BuildAndEarn => SubperiodInfo::BuildAndEarn,
+        }
+    }
+}
+
+impl From<ProtocolState> for ProtocolStateInfo {
+    fn from(protocol_state: ProtocolState) -> Self {
+        Self {
+            era: protocol_state.era,
+            next_era_start: protocol_state.next_era_start,
+            period: protocol_state.period_info.number,
+            subperiod: protocol_state.period_info.subperiod.into(),
+            next_subperiod_start_era: protocol_state.period_info.next_subperiod_start_era,
+            maintenance: protocol_state.maintenance,
+        }
+    }
+}
+
+impl From<UnlockingChunk> for UnlockingChunkInfo {
+    fn from(chunk: UnlockingChunk) -> Self {
+        Self {
+            amount: chunk.amount,
+            unlock_block: chunk.unlock_block,
+        }
+    }
+}
+
+impl From<StakeAmount> for StakeAmountInfo {
+    fn from(stake: StakeAmount) -> Self {
+        Self {
+            voting: stake.voting,
+            build_and_earn: stake.build_and_earn,
+            era: stake.era,
+            period: stake.period,
+        }
+    }
+}
+
+impl<UnlockingLen: Get<u32>> From<AccountLedger<UnlockingLen>> for AccountLedgerInfo {
+    fn from(ledger: AccountLedger<UnlockingLen>) -> Self {
+        Self {
+            locked: ledger.locked,
+            unlocking: ledger.unlocking.into_iter().map(Into::into).collect(),
+            staked: ledger.staked.into(),
+            staked_future: ledger.staked_future.map(Into::into),
+            contract_stake_count: ledger.contract_stake_count,
+            auto_restake: ledger.auto_restake,
+        }
+    }
+}
+
+impl<MD: Get<u32>, NT: Get<u32>> From<DAppTierRewards<MD, NT>> for DAppTierRewardsInfo {
+    fn from(tier_rewards: DAppTierRewards<MD, NT>) -> Self {
+        Self {
+            dapps: tier_rewards.dapps.into_inner(),
+            rewards: tier_rewards.rewards.into_inner(),
+            period: tier_rewards.period,
+        }
+    }
+}
@@ -73,6 +73,12 @@ pub trait WeightInfo {
 	fn on_initialize_build_and_earn_to_build_and_earn() -> Weight;
 	fn dapp_tier_assignment(x: u32, ) -> Weight;
 	fn on_idle_cleanup() -> Weight;
+	fn set_auto_restake() -> Weight;
+	fn restake_reward(x: u32, ) -> Weight;
+	fn claim_staker_rewards_for_past_period(x: u32, ) -> Weight;
+	fn claim_staker_rewards_for_ongoing_period(x: u32, ) -> Weight;
+	fn transfer_locks() -> Weight;
+	fn claim_bonus_reward_for() -> Weight;
 }
 
 /// Weights for pallet_dapp_staking_v3 using the Substrate node and recommended hardware.
@@ -146,7 +152,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn lock_new_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `138`
@@ -163,7 +169,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn lock_existing_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
@@ -180,7 +186,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
@@ -197,7 +203,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// The range of component `x` is `[0, 16]`.
 	fn claim_unlocked(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -217,7 +223,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn relock_unlocking() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `200`
@@ -233,10 +239,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:1 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -247,8 +255,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `4764`
 		// Minimum execution time: 44_905_000 picoseconds.
 		Weight::from_parts(45_261_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
 	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
@@ -259,7 +267,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -278,7 +286,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: DappStaking EraRewards (r:1 w:0)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
-	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -317,8 +325,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:0 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
-	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	fn claim_bonus_reward() -> Weight {
@@ -328,7 +338,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 41_926_000 picoseconds.
 		Weight::from_parts(42_718_000, 3775)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
 	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
@@ -350,11 +360,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:0 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn unstake_from_unregistered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `318`
@@ -362,10 +374,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 41_858_000 picoseconds.
 		Weight::from_parts(42_476_000, 4764)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: DappStaking StakerInfo (r:17 w:16)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:17 w:16)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -376,16 +390,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn cleanup_expired_entries(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `256 + x * (69 ±0)`
-		//  Estimated: `4764 + x * (2613 ±0)`
+		//  Estimated: `4764 + x * (5150 ±0)`
 		// Minimum execution time: 43_103_000 picoseconds.
 		Weight::from_parts(39_876_215, 4764)
 			// Standard Error: 8_123
 			.saturating_add(Weight::from_parts(5_014_232, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 5150).saturating_mul(x.into()))
 	}
 	fn force() -> Weight {
 		// Proof Size summary in bytes:
@@ -395,7 +409,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(11_735_000, 0)
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	fn on_initialize_voting_to_build_and_earn() -> Weight {
@@ -408,30 +422,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
-	/// Storage: DappStaking StaticTierParams (r:1 w:0)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: DappStaking StaticTierParams (r:1 w:1)
 	/// Proof: DappStaking StaticTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
 	/// Storage: DappStaking TierConfig (r:1 w:1)
 	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:2)
-	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
 	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:1)
 	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking DAppTiers (r:0 w:1)
 	/// Proof: DappStaking DAppTiers (max_values: None, max_size: Some(1583), added: 4058, mode: MaxEncodedLen)
+	/// Storage: DappStaking NextTierParams (r:1 w:1)
+	/// Proof: DappStaking NextTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
 	fn on_initialize_build_and_earn_to_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `839`
 		//  Estimated: `4254`
 		// Minimum execution time: 47_901_000 picoseconds.
 		Weight::from_parts(49_554_000, 4254)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking DAppTiers (r:0 w:1)
@@ -477,6 +493,116 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	fn set_auto_restake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158`
+		//  Estimated: `3775`
+		// Minimum execution time: 14_025_000 picoseconds.
+		Weight::from_parts(14_412_000, 3775)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:17 w:16)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:16 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:16 w:16)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn restake_reward(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215 + x * (112 ±0)`
+		//  Estimated: `3603 + x * (2613 ±0)`
+		// Minimum execution time: 19_437_000 picoseconds.
+		Weight::from_parts(20_184_512, 3603)
+			// Standard Error: 6_482
+			.saturating_add(Weight::from_parts(12_975_346, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking EraRewards (r:1 w:0)
+	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
+	/// Storage: DappStaking PeriodEnd (r:1 w:0)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_staker_rewards_for_past_period(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `545`
+		//  Estimated: `4764`
+		// Minimum execution time: 53_417_000 picoseconds.
+		Weight::from_parts(51_296_412, 4764)
+			// Standard Error: 4_981
+			.saturating_add(Weight::from_parts(3_309_204, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking EraRewards (r:1 w:0)
+	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_staker_rewards_for_ongoing_period(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `523`
+		//  Estimated: `4764`
+		// Minimum execution time: 50_902_000 picoseconds.
+		Weight::from_parts(49_011_268, 4764)
+			// Standard Error: 5_812
+			.saturating_add(Weight::from_parts(3_311_590, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking Ledger (r:2 w:2)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:2 w:2)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:2 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_locks() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `8538`
+		// Minimum execution time: 99_837_000 picoseconds.
+		Weight::from_parts(100_118_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: DappStaking StakerInfo (r:1 w:1)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:0 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: DappStaking PeriodEnd (r:1 w:0)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	fn claim_bonus_reward_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `271`
+		//  Estimated: `3775`
+		// Minimum execution time: 41_926_000 picoseconds.
+		Weight::from_parts(42_718_000, 3775)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -549,7 +675,7 @@ impl WeightInfo for () {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn lock_new_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `138`
@@ -566,7 +692,7 @@ impl WeightInfo for () {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn lock_existing_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
@@ -583,7 +709,7 @@ impl WeightInfo for () {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `158`
@@ -600,7 +726,7 @@ impl WeightInfo for () {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// The range of component `x` is `[0, 16]`.
 	fn claim_unlocked(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -620,7 +746,7 @@ impl WeightInfo for () {
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	fn relock_unlocking() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `200`
@@ -636,10 +762,12 @@ impl WeightInfo for () {
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:1 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -650,8 +778,8 @@ impl WeightInfo for () {
 		//  Estimated: `4764`
 		// Minimum execution time: 44_905_000 picoseconds.
 		Weight::from_parts(45_261_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
 	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
@@ -662,7 +790,7 @@ impl WeightInfo for () {
 	/// Storage: DappStaking ContractStake (r:1 w:1)
 	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -681,7 +809,7 @@ impl WeightInfo for () {
 	/// Storage: DappStaking EraRewards (r:1 w:0)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
-	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
@@ -720,8 +848,10 @@ impl WeightInfo for () {
 	}
 	/// Storage: DappStaking StakerInfo (r:1 w:1)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:0 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:0)
-	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	fn claim_bonus_reward() -> Weight {
@@ -731,7 +861,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 41_926_000 picoseconds.
 		Weight::from_parts(42_718_000, 3775)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: DappStaking IntegratedDApps (r:1 w:0)
 	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
@@ -753,11 +883,13 @@ impl WeightInfo for () {
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
 	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:0)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:0 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	fn unstake_from_unregistered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `318`
@@ -765,10 +897,12 @@ impl WeightInfo for () {
 		// Minimum execution time: 41_858_000 picoseconds.
 		Weight::from_parts(42_476_000, 4764)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: DappStaking StakerInfo (r:17 w:16)
 	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:17 w:16)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
 	/// Storage: DappStaking Ledger (r:1 w:1)
 	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:1)
@@ -779,16 +913,16 @@ impl WeightInfo for () {
 	fn cleanup_expired_entries(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `256 + x * (69 ±0)`
-		//  Estimated: `4764 + x * (2613 ±0)`
+		//  Estimated: `4764 + x * (5150 ±0)`
 		// Minimum execution time: 43_103_000 picoseconds.
 		Weight::from_parts(39_876_215, 4764)
 			// Standard Error: 8_123
 			.saturating_add(Weight::from_parts(5_014_232, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 5150).saturating_mul(x.into()))
 	}
 	fn force() -> Weight {
 		// Proof Size summary in bytes:
@@ -798,7 +932,7 @@ impl WeightInfo for () {
 		Weight::from_parts(11_735_000, 0)
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	fn on_initialize_voting_to_build_and_earn() -> Weight {
@@ -811,30 +945,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
-	/// Storage: DappStaking StaticTierParams (r:1 w:0)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: DappStaking StaticTierParams (r:1 w:1)
 	/// Proof: DappStaking StaticTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
 	/// Storage: DappStaking TierConfig (r:1 w:1)
 	/// Proof: DappStaking TierConfig (max_values: Some(1), max_size: Some(161), added: 656, mode: MaxEncodedLen)
 	/// Storage: DappStaking PeriodEnd (r:1 w:2)
-	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
 	/// Storage: DappStaking HistoryCleanupMarker (r:1 w:1)
 	/// Proof: DappStaking HistoryCleanupMarker (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking DAppTiers (r:0 w:1)
 	/// Proof: DappStaking DAppTiers (max_values: None, max_size: Some(1583), added: 4058, mode: MaxEncodedLen)
+	/// Storage: DappStaking NextTierParams (r:1 w:1)
+	/// Proof: DappStaking NextTierParams (max_values: Some(1), max_size: Some(167), added: 662, mode: MaxEncodedLen)
 	fn on_initialize_build_and_earn_to_voting() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `839`
 		//  Estimated: `4254`
 		// Minimum execution time: 47_901_000 picoseconds.
 		Weight::from_parts(49_554_000, 4254)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
-	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(112), added: 607, mode: MaxEncodedLen)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
 	/// Storage: DappStaking EraRewards (r:1 w:1)
 	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
 	/// Storage: DappStaking DAppTiers (r:0 w:1)
@@ -880,4 +1016,114 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	fn set_auto_restake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158`
+		//  Estimated: `3775`
+		// Minimum execution time: 14_025_000 picoseconds.
+		Weight::from_parts(14_412_000, 3775)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DappStaking CurrentEraInfo (r:1 w:1)
+	/// Proof: DappStaking CurrentEraInfo (max_values: Some(1), max_size: Some(129), added: 624, mode: MaxEncodedLen)
+	/// Storage: DappStaking StakerInfo (r:17 w:16)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking IntegratedDApps (r:16 w:0)
+	/// Proof: DappStaking IntegratedDApps (max_values: Some(65535), max_size: Some(116), added: 2096, mode: MaxEncodedLen)
+	/// Storage: DappStaking ContractStake (r:16 w:16)
+	/// Proof: DappStaking ContractStake (max_values: Some(65535), max_size: Some(91), added: 2071, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn restake_reward(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215 + x * (112 ±0)`
+		//  Estimated: `3603 + x * (2613 ±0)`
+		// Minimum execution time: 19_437_000 picoseconds.
+		Weight::from_parts(20_184_512, 3603)
+			// Standard Error: 6_482
+			.saturating_add(Weight::from_parts(12_975_346, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2613).saturating_mul(x.into()))
+	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking EraRewards (r:1 w:0)
+	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
+	/// Storage: DappStaking PeriodEnd (r:1 w:0)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_staker_rewards_for_past_period(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `545`
+		//  Estimated: `4764`
+		// Minimum execution time: 53_417_000 picoseconds.
+		Weight::from_parts(51_296_412, 4764)
+			// Standard Error: 4_981
+			.saturating_add(Weight::from_parts(3_309_204, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: DappStaking EraRewards (r:1 w:0)
+	/// Proof: DappStaking EraRewards (max_values: None, max_size: Some(789), added: 3264, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:1)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_staker_rewards_for_ongoing_period(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `523`
+		//  Estimated: `4764`
+		// Minimum execution time: 50_902_000 picoseconds.
+		Weight::from_parts(49_011_268, 4764)
+			// Standard Error: 5_812
+			.saturating_add(Weight::from_parts(3_311_590, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: DappStaking Ledger (r:2 w:2)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:2 w:2)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:2 w:0)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_locks() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `8538`
+		// Minimum execution time: 99_837_000 picoseconds.
+		Weight::from_parts(100_118_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: DappStaking StakerInfo (r:1 w:1)
+	/// Proof: DappStaking StakerInfo (max_values: None, max_size: Some(138), added: 2613, mode: MaxEncodedLen)
+	/// Storage: DappStaking LoyaltyStreaks (r:0 w:1)
+	/// Proof: DappStaking LoyaltyStreaks (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: DappStaking PeriodEnd (r:1 w:0)
+	/// Proof: DappStaking PeriodEnd (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: DappStaking Ledger (r:1 w:1)
+	/// Proof: DappStaking Ledger (max_values: None, max_size: Some(310), added: 2785, mode: MaxEncodedLen)
+	fn claim_bonus_reward_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `271`
+		//  Estimated: `3775`
+		// Minimum execution time: 41_926_000 picoseconds.
+		Weight::from_parts(42_718_000, 3775)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
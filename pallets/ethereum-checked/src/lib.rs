@@ -47,12 +47,13 @@ use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 use ethereum_types::{H160, U256};
+use evm::{Capture, Context as EvmContext, Handler};
 use fp_ethereum::{TransactionData, ValidatedTransaction};
 use fp_evm::{
     CallInfo, CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, ExitReason,
     ExitSucceed, TransactionValidationError,
 };
-use pallet_evm::GasWeightMapping;
+use pallet_evm::{runner::stack::Runner as StackRunner, BalanceOf, GasWeightMapping};
 
 use frame_support::{
     dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
@@ -293,11 +294,95 @@ impl<T: Config> Pallet<T> {
     }
 }
 
-impl<T: Config> CheckedEthereumTransact for Pallet<T> {
+impl<T: Config> CheckedEthereumTransact for Pallet<T>
+where
+    BalanceOf<T>: TryFrom<U256> + Into<U256>,
+{
     fn xvm_transact(
         source: H160,
         checked_tx: CheckedEthereumTx,
     ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
         Self::do_transact(source, checked_tx, CheckedEthereumTxKind::Xvm, false)
     }
+
+    fn xvm_static_call(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
+        // Gas limit is capped by the block gas limit of XVM transactions.
+        let gas_limit: u64 = checked_tx
+            .gas_limit
+            .min(U256::from(Self::block_gas_limit(
+                &CheckedEthereumTxKind::Xvm,
+            )))
+            .unique_saturated_into();
+        let (weight_limit, proof_size_base_cost) =
+            match <T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas_limit, true) {
+                weight_limit if weight_limit.proof_size() > 0 => (
+                    Some(weight_limit),
+                    Some(WeightInfoOf::<T>::transact_without_apply().proof_size()),
+                ),
+                _ => (None, None),
+            };
+
+        // The tx is not applied, only the call is executed, in static mode.
+        let precompiles = T::PrecompilesValue::get();
+        let info = StackRunner::<T>::execute(
+            source,
+            U256::zero(),
+            gas_limit,
+            None,
+            None,
+            T::config(),
+            &precompiles,
+            false,
+            weight_limit,
+            proof_size_base_cost,
+            |executor| {
+                let context = EvmContext {
+                    address: checked_tx.target,
+                    caller: source,
+                    apparent_value: U256::zero(),
+                };
+                match Handler::call(
+                    executor,
+                    checked_tx.target,
+                    None,
+                    checked_tx.input.to_vec(),
+                    Some(gas_limit),
+                    true,
+                    context,
+                ) {
+                    Capture::Exit(result) => result,
+                    Capture::Trap(interrupt) => match interrupt {},
+                }
+            },
+        )
+        .map_err(|e| DispatchErrorWithPostInfo {
+            post_info: PostDispatchInfo {
+                actual_weight: Some(e.weight),
+                pays_fee: Pays::Yes,
+            },
+            error: e.error.into(),
+        })?;
+
+        let mut actual_weight = <T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
+            info.used_gas.standard.unique_saturated_into(),
+            true,
+        );
+        if let Some(proof_size_usage) = info
+            .weight_info
+            .and_then(|weight_info| weight_info.proof_size_usage)
+        {
+            *actual_weight.proof_size_mut() = proof_size_usage;
+        }
+
+        Ok((
+            PostDispatchInfo {
+                actual_weight: Some(actual_weight),
+                pays_fee: Pays::Yes,
+            },
+            info,
+        ))
+    }
 }
@@ -197,11 +197,12 @@ impl pallet_ethereum::Config for TestRuntime {
 
 parameter_types! {
     pub TxWeightLimit: Weight = Weight::from_parts(u64::max_value(), 0);
+    pub static XvmTxWeightLimit: Weight = Weight::from_parts(u64::max_value(), 0);
 }
 
 impl pallet_ethereum_checked::Config for TestRuntime {
     type ReservedXcmpWeight = TxWeightLimit;
-    type XvmTxWeightLimit = TxWeightLimit;
+    type XvmTxWeightLimit = XvmTxWeightLimit;
     type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
     type AddressMapper = MockAddressMapper;
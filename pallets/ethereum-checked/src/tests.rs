@@ -23,6 +23,7 @@ use mock::*;
 
 use astar_primitives::ethereum_checked::EthereumTxInput;
 use ethereum::{ReceiptV3 as Receipt, TransactionV2 as Transaction};
+use fp_evm::ExitError;
 pub use fp_rpc::TransactionStatus;
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
@@ -152,3 +153,70 @@ fn no_hash_collision() {
         assert_eq!(tx_hashes.len(), 15);
     });
 }
+
+#[test]
+fn xvm_static_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+        };
+        let retrieve_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `retrieve`
+            input: bounded_input("2e64cec1"),
+            maybe_access_list: None,
+        };
+
+        // Storing in static mode fails, and no tx is applied.
+        let (_, call_info) = EthereumChecked::xvm_static_call(ALICE_H160, store_tx.clone())
+            .expect("failed to static call");
+        assert!(!call_info.exit_reason.is_succeed());
+        assert!(pallet_ethereum::Pending::<TestRuntime>::get().is_empty());
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::zero());
+
+        let (_, call_info) = EthereumChecked::xvm_static_call(ALICE_H160, retrieve_tx.clone())
+            .expect("failed to static call");
+        assert!(call_info.exit_reason.is_succeed());
+        assert_eq!(U256::from_big_endian(&(call_info.value)), U256::zero());
+
+        // Reading in static mode works.
+        assert_ok!(EthereumChecked::xvm_transact(ALICE_H160, store_tx));
+        let (_, call_info) = EthereumChecked::xvm_static_call(ALICE_H160, retrieve_tx)
+            .expect("failed to static call");
+        assert!(call_info.exit_reason.is_succeed());
+        assert_eq!(U256::from_big_endian(&(call_info.value)), 3.into());
+    });
+}
+
+#[test]
+fn xvm_static_call_gas_limit_is_capped() {
+    ExtBuilder::default().build().execute_with(|| {
+        let retrieve_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `retrieve`
+            input: bounded_input("2e64cec1"),
+            maybe_access_list: None,
+        };
+
+        // Gas limit of the tx is enough, but the XVM tx weight limit isn't.
+        XvmTxWeightLimit::set(Weight::from_parts(1_000, 0));
+        let (_, call_info) = EthereumChecked::xvm_static_call(ALICE_H160, retrieve_tx)
+            .expect("failed to static call");
+        assert_eq!(
+            call_info.exit_reason,
+            ExitReason::Error(ExitError::OutOfGas)
+        );
+        assert!(call_info.used_gas.standard <= U256::from(1_000));
+    });
+}
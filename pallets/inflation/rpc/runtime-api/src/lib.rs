@@ -0,0 +1,48 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_inflation::{
+    CycleIssuanceProjection, CyclePayoutProjection, InflationConfiguration, RewardPools,
+    MAX_PROJECTED_CYCLES,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// Inflation Api.
+    ///
+    /// Used to provide information about the inflation, otherwise only available by reimplementing the pallet logic.
+    pub trait InflationApi {
+
+        /// Active inflation configuration.
+        fn inflation_configuration() -> InflationConfiguration;
+
+        /// Reward pools according to the active inflation configuration & the current total value staked.
+        fn reward_pools() -> RewardPools;
+
+        /// Projected max payouts for the remainder of the ongoing cycle, based on the current total value staked.
+        fn cycle_payout_projection() -> CyclePayoutProjection;
+
+        /// Projected max issuance for the next `cycles` cycles, taking into account the decay & the scheduled inflation parameters.
+        ///
+        /// At most `MAX_PROJECTED_CYCLES` cycles are projected.
+        fn issuance_projection(cycles: u32) -> Vec<CycleIssuanceProjection>;
+    }
+}
@@ -0,0 +1,157 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the inflation pallet.
+//!
+//! All of the calls are thin wrappers around the `InflationApi` runtime API,
+//! executed against the best block in case no block hash is provided.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use inflation_runtime_api::{
+    CycleIssuanceProjection, CyclePayoutProjection, InflationApi as InflationRuntimeApi,
+    InflationConfiguration, RewardPools,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Inflation RPC methods.
+#[rpc(client, server)]
+pub trait InflationApi<BlockHash> {
+    /// Active inflation configuration.
+    #[method(name = "inflation_configuration")]
+    fn inflation_configuration(&self, at: Option<BlockHash>) -> RpcResult<InflationConfiguration>;
+
+    /// Reward pools according to the active inflation configuration & the current total value staked.
+    #[method(name = "inflation_rewardPools")]
+    fn reward_pools(&self, at: Option<BlockHash>) -> RpcResult<RewardPools>;
+
+    /// Projected max payouts for the remainder of the ongoing cycle, based on the current total value staked.
+    #[method(name = "inflation_cyclePayoutProjection")]
+    fn cycle_payout_projection(&self, at: Option<BlockHash>) -> RpcResult<CyclePayoutProjection>;
+
+    /// Projected max issuance for the next `cycles` cycles.
+    #[method(name = "inflation_issuanceProjection")]
+    fn issuance_projection(
+        &self,
+        cycles: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<CycleIssuanceProjection>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Provides RPC methods to query inflation state.
+pub struct Inflation<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Inflation<C, Block> {
+    /// Creates a new instance of the inflation RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts a runtime API call error into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query inflation state.",
+        Some(format!("{:?}", err)),
+    ))
+    .into()
+}
+
+impl<C, Block> Inflation<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: InflationRuntimeApi<Block>,
+{
+    /// Executes the provided runtime API call at the specified block, or at the best block if none is provided.
+    fn call_at<R, E: std::fmt::Debug>(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        call: impl FnOnce(&C::Api, <Block as BlockT>::Hash) -> Result<R, E>,
+    ) -> RpcResult<R> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        call(&*api, at_hash).map_err(runtime_error_into_rpc_err)
+    }
+}
+
+impl<C, Block> InflationApiServer<<Block as BlockT>::Hash> for Inflation<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: InflationRuntimeApi<Block>,
+{
+    fn inflation_configuration(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<InflationConfiguration> {
+        self.call_at(at, |api, at_hash| api.inflation_configuration(at_hash))
+    }
+
+    fn reward_pools(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<RewardPools> {
+        self.call_at(at, |api, at_hash| api.reward_pools(at_hash))
+    }
+
+    fn cycle_payout_projection(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<CyclePayoutProjection> {
+        self.call_at(at, |api, at_hash| api.cycle_payout_projection(at_hash))
+    }
+
+    fn issuance_projection(
+        &self,
+        cycles: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<CycleIssuanceProjection>> {
+        self.call_at(at, |api, at_hash| api.issuance_projection(at_hash, cycles))
+    }
+}
@@ -0,0 +1,266 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use jsonrpsee::{core::Error as RpcError, RpcModule};
+use sp_api::{ApiError, ApiRef};
+use sp_blockchain::{BlockStatus, Info};
+use sp_core::H256;
+use sp_runtime::{
+    testing::{Block as RawBlock, ExtrinsicWrapper, Header},
+    Perquintill,
+};
+
+type Block = RawBlock<ExtrinsicWrapper<u64>>;
+
+const BEST_HASH: H256 = H256::repeat_byte(0x01);
+const UNKNOWN_HASH: H256 = H256::repeat_byte(0x02);
+
+/// Client, only aware of the best block.
+struct TestApi;
+
+struct TestRuntimeApi;
+
+impl ProvideRuntimeApi<Block> for TestApi {
+    type Api = TestRuntimeApi;
+
+    fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+        TestRuntimeApi.into()
+    }
+}
+
+impl HeaderBackend<Block> for TestApi {
+    fn header(&self, _hash: H256) -> sp_blockchain::Result<Option<Header>> {
+        Ok(None)
+    }
+
+    fn info(&self) -> Info<Block> {
+        Info {
+            best_hash: BEST_HASH,
+            best_number: 1,
+            genesis_hash: Default::default(),
+            finalized_hash: Default::default(),
+            finalized_number: 0,
+            finalized_state: None,
+            number_leaves: 1,
+            block_gap: None,
+        }
+    }
+
+    fn status(&self, _hash: H256) -> sp_blockchain::Result<BlockStatus> {
+        Ok(BlockStatus::Unknown)
+    }
+
+    fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<u64>> {
+        Ok(None)
+    }
+
+    fn hash(&self, _number: u64) -> sp_blockchain::Result<Option<H256>> {
+        Ok(None)
+    }
+}
+
+fn inflation_configuration() -> InflationConfiguration {
+    InflationConfiguration {
+        recalculation_era: 100,
+        issuance_safety_cap: 1_000_000,
+        collator_reward_per_block: 10,
+        treasury_reward_per_block: 5,
+        dapp_reward_pool_per_era: 1_000,
+        base_staker_reward_pool_per_era: 2_000,
+        adjustable_staker_reward_pool_per_era: 3_000,
+        bonus_reward_pool_per_period: 4_000,
+        ideal_staking_rate: Perquintill::from_percent(50),
+    }
+}
+
+fn reward_pools() -> RewardPools {
+    RewardPools {
+        total_value_staked: 500_000,
+        base_staker_reward_pool_per_era: 2_000,
+        adjustable_staker_reward_pool_per_era: 1_500,
+        dapp_reward_pool_per_era: 1_000,
+        bonus_reward_pool_per_period: 4_000,
+    }
+}
+
+fn cycle_payout_projection() -> CyclePayoutProjection {
+    CyclePayoutProjection {
+        remaining_blocks: 70,
+        remaining_build_and_earn_eras: 6,
+        remaining_periods: 1,
+        collators: 700,
+        treasury: 350,
+        dapps: 6_000,
+        stakers: 21_000,
+        bonus: 4_000,
+    }
+}
+
+/// Projection of the cycle at the specified index, increasing the issuance by 10% each cycle.
+fn issuance_projection(index: u32) -> CycleIssuanceProjection {
+    let total_issuance = (0..index).fold(1_000_000, |issuance, _| issuance * 11 / 10);
+    CycleIssuanceProjection {
+        start_era: 100 + index * 10,
+        max_inflation_rate: Perquintill::from_percent(10),
+        total_issuance,
+        max_emission: total_issuance / 10,
+    }
+}
+
+/// Fails the call if it isn't executed at the best block.
+fn ensure_best_block(at: H256) -> Result<(), ApiError> {
+    if at == BEST_HASH {
+        Ok(())
+    } else {
+        Err(ApiError::UnknownBlock(format!("{:?}", at)))
+    }
+}
+
+sp_api::mock_impl_runtime_apis! {
+    impl InflationRuntimeApi<Block> for TestRuntimeApi {
+        #[advanced]
+        fn inflation_configuration(
+            &self,
+            at: <Block as BlockT>::Hash,
+        ) -> Result<InflationConfiguration, ApiError> {
+            ensure_best_block(at).map(|_| inflation_configuration())
+        }
+
+        #[advanced]
+        fn reward_pools(&self, at: <Block as BlockT>::Hash) -> Result<RewardPools, ApiError> {
+            ensure_best_block(at).map(|_| reward_pools())
+        }
+
+        #[advanced]
+        fn cycle_payout_projection(
+            &self,
+            at: <Block as BlockT>::Hash,
+        ) -> Result<CyclePayoutProjection, ApiError> {
+            ensure_best_block(at).map(|_| cycle_payout_projection())
+        }
+
+        #[advanced]
+        fn issuance_projection(
+            &self,
+            at: <Block as BlockT>::Hash,
+            cycles: u32,
+        ) -> Result<Vec<CycleIssuanceProjection>, ApiError> {
+            ensure_best_block(at).map(|_| (0..cycles).map(issuance_projection).collect())
+        }
+    }
+}
+
+fn inflation_rpc() -> RpcModule<Inflation<TestApi, Block>> {
+    Inflation::new(Arc::new(TestApi)).into_rpc()
+}
+
+/// Asserts that the RPC call failed due to the runtime API call failure.
+fn assert_runtime_error<R: std::fmt::Debug>(result: Result<R, RpcError>) {
+    match result {
+        Err(RpcError::Call(CallError::Custom(error))) => {
+            assert_eq!(error.code(), i32::from(Error::RuntimeError));
+            assert_eq!(error.message(), "Unable to query inflation state.");
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn inflation_configuration_works() {
+    let io = inflation_rpc();
+
+    let config: InflationConfiguration = io
+        .call("inflation_configuration", [None::<H256>])
+        .await
+        .unwrap();
+    assert_eq!(config, inflation_configuration());
+
+    let config: InflationConfiguration = io
+        .call("inflation_configuration", [Some(BEST_HASH)])
+        .await
+        .unwrap();
+    assert_eq!(config, inflation_configuration());
+
+    let result = io
+        .call::<_, InflationConfiguration>("inflation_configuration", [Some(UNKNOWN_HASH)])
+        .await;
+    assert_runtime_error(result);
+}
+
+#[tokio::test]
+async fn reward_pools_works() {
+    let io = inflation_rpc();
+
+    let pools: RewardPools = io
+        .call("inflation_rewardPools", [None::<H256>])
+        .await
+        .unwrap();
+    assert_eq!(pools, reward_pools());
+
+    let result = io
+        .call::<_, RewardPools>("inflation_rewardPools", [Some(UNKNOWN_HASH)])
+        .await;
+    assert_runtime_error(result);
+}
+
+#[tokio::test]
+async fn cycle_payout_projection_works() {
+    let io = inflation_rpc();
+
+    let projection: CyclePayoutProjection = io
+        .call("inflation_cyclePayoutProjection", [None::<H256>])
+        .await
+        .unwrap();
+    assert_eq!(projection, cycle_payout_projection());
+
+    let result = io
+        .call::<_, CyclePayoutProjection>("inflation_cyclePayoutProjection", [Some(UNKNOWN_HASH)])
+        .await;
+    assert_runtime_error(result);
+}
+
+#[tokio::test]
+async fn issuance_projection_works() {
+    let io = inflation_rpc();
+
+    // Number of cycles is passed through to the runtime API
+    let projections: Vec<CycleIssuanceProjection> = io
+        .call("inflation_issuanceProjection", (3, None::<H256>))
+        .await
+        .unwrap();
+    assert_eq!(
+        projections,
+        (0..3).map(issuance_projection).collect::<Vec<_>>()
+    );
+
+    let projections: Vec<CycleIssuanceProjection> = io
+        .call("inflation_issuanceProjection", (0, Some(BEST_HASH)))
+        .await
+        .unwrap();
+    assert!(projections.is_empty());
+
+    let result = io
+        .call::<_, Vec<CycleIssuanceProjection>>(
+            "inflation_issuanceProjection",
+            (3, Some(UNKNOWN_HASH)),
+        )
+        .await;
+    assert_runtime_error(result);
+}
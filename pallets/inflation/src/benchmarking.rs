@@ -41,6 +41,7 @@ fn initial_config<T: Config>() {
         adjustable_stakers_part: Perquintill::from_percent(35),
         bonus_part: Perquintill::from_percent(12),
         ideal_staking_rate: Perquintill::from_percent(50),
+        decay_rate: Perquintill::from_percent(90),
     };
     assert!(params.is_valid());
 
@@ -68,6 +69,16 @@ fn initial_config<T: Config>() {
     T::Currency::make_free_balance_be(&dummy_account, 1_000_000_000_000_000_000_000);
 }
 
+// Fill up the inflation parameters schedule, with the first entry being due at the specified era.
+fn max_scheduled_params<T: Config>(first_era: EraNumber) {
+    let params = InflationParams::<T>::get();
+
+    let scheduled: Vec<_> = (0..T::MaxScheduledParams::get())
+        .map(|idx| (first_era.saturating_add(idx), params))
+        .collect();
+    ScheduledInflationParams::<T>::put(BoundedVec::try_from(scheduled).expect("Within bounds."));
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -88,6 +99,7 @@ mod benchmarks {
     #[benchmark]
     fn force_inflation_recalculation() {
         initial_config::<T>();
+        max_scheduled_params::<T>(123);
 
         #[extrinsic_call]
         _(RawOrigin::Root, 123);
@@ -102,6 +114,7 @@ mod benchmarks {
 
         let init_recalculation_era = ActiveInflationConfig::<T>::get().recalculation_era;
         DoRecalculation::<T>::put(init_recalculation_era);
+        max_scheduled_params::<T>(init_recalculation_era);
 
         #[block]
         {
@@ -65,6 +65,20 @@
 //! When new cycle begins, inflation configuration is recalculated according to the inflation parameters & total issuance at that point in time.
 //! Based on the max inflation rate, rewards for different network actors are calculated.
 //!
+//! ### Decay
+//!
+//! Inflation parameters can declare a per-cycle multiplicative `decay_rate`.
+//! When a new cycle begins, the max inflation rate is multiplied by the decay rate before the recalculation, so each cycle uses a reduced rate.
+//! E.g. with 7% max inflation rate and 90% decay rate, the following cycles will use 6.3%, 5.67%, 5.103%, etc.
+//!
+//! Forced recalculation doesn't start a new cycle, so the decay isn't applied.
+//!
+//! ### Scheduled Parameters
+//!
+//! Governance can schedule new inflation parameters to be applied from some future era.
+//! Scheduled parameters are applied at the first recalculation which happens at or after the specified era,
+//! replacing the existing parameters, including the current (decayed) max inflation rate.
+//!
 //! Some rewards are calculated to be paid out per block, while some are per era or per period.
 //!
 //! ## Rewards
@@ -93,6 +107,18 @@
 //!
 //! This pallet implements `StakingRewardHandler` trait, which is used by the dApp staking protocol to get reward pools & distribute rewards.
 //!
+//! ## Issuance Projection
+//!
+//! `issuance_projection` function can be used to project the max issuance for the upcoming cycles,
+//! taking into account both the decay & the scheduled parameters.
+//!
+//! ## Reward Pools & Payout Projection
+//!
+//! `reward_pools` function provides the reward pools according to the active inflation configuration & the provided total value staked,
+//! while `cycle_payout_projection` projects the max payouts for the remainder of the ongoing cycle.
+//!
+//! All of these are exposed via the `InflationApi` runtime API, so off-chain tooling doesn't need to reimplement the calculations.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -100,9 +126,10 @@ pub use pallet::*;
 
 use astar_primitives::{
     dapp_staking::{
-        CycleConfiguration, EraNumber, Observer as DappStakingObserver, StakingRewardHandler,
+        CycleConfiguration, EraNumber, Observer as DappStakingObserver, PeriodNumber,
+        ProtocolStateInfo, StakingRewardHandler, SubperiodInfo,
     },
-    Balance,
+    Balance, BlockNumber,
 };
 use frame_support::{
     pallet_prelude::*,
@@ -115,8 +142,9 @@ use sp_runtime::{
     traits::{CheckedAdd, Zero},
     Perquintill,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -133,7 +161,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -160,6 +188,10 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// Maximum number of inflation parameter sets which can be scheduled at once.
+        #[pallet::constant]
+        type MaxScheduledParams: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -175,12 +207,22 @@ pub mod pallet {
         ForcedInflationRecalculation { config: InflationConfiguration },
         /// New inflation configuration has been set.
         NewInflationConfiguration { config: InflationConfiguration },
+        /// Inflation parameters have been scheduled to be applied from the specified era.
+        InflationParametersScheduled { era: EraNumber },
+        /// Inflation parameters scheduled for the specified era have been cancelled.
+        ScheduledInflationParametersCancelled { era: EraNumber },
+        /// Inflation parameters scheduled for the specified era have been applied.
+        ScheduledInflationParametersApplied { era: EraNumber },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Sum of all parts must be one whole (100%).
         InvalidInflationParameters,
+        /// Maximum number of scheduled inflation parameter sets has been reached.
+        TooManyScheduledParams,
+        /// There are no inflation parameters scheduled for the specified era.
+        NoScheduledParams,
     }
 
     /// Active inflation configuration parameters.
@@ -193,6 +235,14 @@ pub mod pallet {
     #[pallet::storage]
     pub type InflationParams<T: Config> = StorageValue<_, InflationParameters, ValueQuery>;
 
+    /// Inflation parameters scheduled to be applied in the future, sorted by the era from which they apply.
+    #[pallet::storage]
+    pub type ScheduledInflationParams<T: Config> = StorageValue<
+        _,
+        BoundedVec<(EraNumber, InflationParameters), T::MaxScheduledParams>,
+        ValueQuery,
+    >;
+
     /// Flag indicating whether on the first possible opportunity, recalculation of the inflation config should be done.
     #[pallet::storage]
     #[pallet::whitelist_storage]
@@ -211,11 +261,12 @@ pub mod pallet {
         fn build(&self) {
             assert!(self.params.is_valid());
 
+            InflationParams::<T>::put(self.params);
+
             let starting_era = 1;
             let config = Pallet::<T>::recalculate_inflation(starting_era);
 
             ActiveInflationConfig::<T>::put(config);
-            InflationParams::<T>::put(self.params);
         }
     }
 
@@ -239,7 +290,7 @@ pub mod pallet {
             //
             // This should be done as late as possible, to ensure all operations that modify issuance are done.
             if let Some(next_era) = DoRecalculation::<T>::get() {
-                let config = Self::recalculate_inflation(next_era);
+                let config = Self::recalculate_inflation_for_new_cycle(next_era);
                 ActiveInflationConfig::<T>::put(config.clone());
                 DoRecalculation::<T>::kill();
 
@@ -303,9 +354,82 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Used to schedule new inflation parameters, to be applied at the first recalculation at or after the specified era.
+        /// The parameters must be valid, all parts summing up to one whole (100%), otherwise the call will fail.
+        ///
+        /// If parameters are already scheduled for the same era, they are replaced.
+        ///
+        /// Must be called by `root` origin.
+        #[pallet::call_index(2)]
+        #[pallet::weight(Pallet::<T>::schedule_update_weight())]
+        pub fn schedule_inflation_params(
+            origin: OriginFor<T>,
+            era: EraNumber,
+            params: InflationParameters,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(params.is_valid(), Error::<T>::InvalidInflationParameters);
+
+            ScheduledInflationParams::<T>::try_mutate(|scheduled| {
+                match scheduled.binary_search_by_key(&era, |(scheduled_era, _)| *scheduled_era) {
+                    Ok(idx) => scheduled[idx] = (era, params),
+                    Err(idx) => scheduled
+                        .try_insert(idx, (era, params))
+                        .map_err(|_| Error::<T>::TooManyScheduledParams)?,
+                }
+
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::<T>::InflationParametersScheduled { era });
+
+            Ok(())
+        }
+
+        /// Used to cancel inflation parameters scheduled for the specified era.
+        ///
+        /// Must be called by `root` origin.
+        #[pallet::call_index(3)]
+        #[pallet::weight(Pallet::<T>::schedule_update_weight())]
+        pub fn cancel_scheduled_inflation_params(
+            origin: OriginFor<T>,
+            era: EraNumber,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ScheduledInflationParams::<T>::try_mutate(|scheduled| {
+                let idx = scheduled
+                    .binary_search_by_key(&era, |(scheduled_era, _)| *scheduled_era)
+                    .map_err(|_| Error::<T>::NoScheduledParams)?;
+                scheduled.remove(idx);
+
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::<T>::ScheduledInflationParametersCancelled { era });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Weight of the calls updating the scheduled inflation parameters.
+        ///
+        /// Besides the parameters validation & a single write, which are covered by `force_set_inflation_params`,
+        /// these calls also read the whole schedule.
+        fn schedule_update_weight() -> Weight {
+            let schedule_max_size = BoundedVec::<
+                (EraNumber, InflationParameters),
+                T::MaxScheduledParams,
+            >::max_encoded_len();
+
+            T::WeightInfo::force_set_inflation_params()
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(Weight::from_parts(0, schedule_max_size as u64))
+        }
+
         /// Payout block rewards to the beneficiaries.
         ///
         /// Return the total amount issued.
@@ -323,11 +447,69 @@ pub mod pallet {
 
         /// Recalculates the inflation based on the total issuance & inflation parameters.
         ///
+        /// Any parameters scheduled for `next_era` or earlier are applied first.
+        /// The decay isn't applied, the stored parameters are used as they are.
+        ///
         /// Returns the new inflation configuration.
         pub(crate) fn recalculate_inflation(next_era: EraNumber) -> InflationConfiguration {
-            let params = InflationParams::<T>::get();
+            let params =
+                Self::apply_scheduled_params(next_era).unwrap_or_else(InflationParams::<T>::get);
             let total_issuance = T::Currency::total_issuance();
 
+            Self::calculate_config(&params, total_issuance, next_era)
+        }
+
+        /// Recalculates the inflation for the new cycle, based on the total issuance & inflation parameters.
+        ///
+        /// Any parameters scheduled for `next_era` or earlier are applied first.
+        /// If there are none, the decay is applied to the stored parameters, so the new cycle uses the reduced max inflation rate.
+        ///
+        /// Returns the new inflation configuration.
+        pub(crate) fn recalculate_inflation_for_new_cycle(
+            next_era: EraNumber,
+        ) -> InflationConfiguration {
+            let params = Self::apply_scheduled_params(next_era).unwrap_or_else(|| {
+                let params = InflationParams::<T>::get().decayed();
+                InflationParams::<T>::put(params);
+                params
+            });
+            let total_issuance = T::Currency::total_issuance();
+
+            Self::calculate_config(&params, total_issuance, next_era)
+        }
+
+        /// Applies the latest of the inflation parameters scheduled for `next_era` or earlier, if there are any.
+        /// All the due entries are removed from the schedule.
+        ///
+        /// Returns the applied inflation parameters, if there were any.
+        fn apply_scheduled_params(next_era: EraNumber) -> Option<InflationParameters> {
+            let mut scheduled = ScheduledInflationParams::<T>::get();
+
+            let due_count = scheduled
+                .iter()
+                .take_while(|(era, _)| *era <= next_era)
+                .count();
+
+            match due_count.checked_sub(1).and_then(|idx| scheduled.get(idx)) {
+                Some(&(era, params)) => {
+                    scheduled.retain(|(scheduled_era, _)| *scheduled_era > next_era);
+                    ScheduledInflationParams::<T>::put(scheduled);
+                    InflationParams::<T>::put(params);
+
+                    Self::deposit_event(Event::<T>::ScheduledInflationParametersApplied { era });
+
+                    Some(params)
+                }
+                None => None,
+            }
+        }
+
+        /// Calculates the inflation configuration based on the provided parameters & total issuance.
+        fn calculate_config(
+            params: &InflationParameters,
+            total_issuance: Balance,
+            next_era: EraNumber,
+        ) -> InflationConfiguration {
             // 1. Calculate maximum emission over the period before the next recalculation.
             let max_emission = params.max_inflation_rate * total_issuance;
             let issuance_safety_cap = total_issuance.saturating_add(max_emission);
@@ -389,6 +571,151 @@ pub mod pallet {
             new_inflation_config
         }
 
+        /// Projects the max issuance for the next `cycles` cycles, starting with the one after the ongoing cycle.
+        /// At most `MAX_PROJECTED_CYCLES` cycles are projected.
+        ///
+        /// The projection takes into account both the decay & the scheduled inflation parameters.
+        /// It's an upper bound, since it assumes the max emission of each cycle is issued in full.
+        pub fn issuance_projection(cycles: u32) -> Vec<CycleIssuanceProjection> {
+            let config = ActiveInflationConfig::<T>::get();
+            let scheduled = ScheduledInflationParams::<T>::get();
+            let eras_per_cycle = T::CycleConfiguration::eras_per_cycle();
+
+            let mut params = InflationParams::<T>::get();
+            let mut scheduled = scheduled.iter().peekable();
+            let mut start_era = config.recalculation_era;
+            let mut total_issuance = config.issuance_safety_cap;
+
+            (0..cycles.min(MAX_PROJECTED_CYCLES))
+                .map(|_| {
+                    // Each new cycle uses the decayed parameters, unless new ones are scheduled.
+                    params = params.decayed();
+                    while let Some((_, scheduled_params)) =
+                        scheduled.next_if(|(era, _)| *era <= start_era)
+                    {
+                        params = *scheduled_params;
+                    }
+
+                    let max_emission = params.max_inflation_rate * total_issuance;
+                    let projection = CycleIssuanceProjection {
+                        start_era,
+                        max_inflation_rate: params.max_inflation_rate,
+                        total_issuance,
+                        max_emission,
+                    };
+
+                    start_era = start_era.saturating_add(eras_per_cycle);
+                    total_issuance = total_issuance.saturating_add(max_emission);
+
+                    projection
+                })
+                .collect()
+        }
+
+        /// Reward pools according to the active inflation configuration & the provided total value staked.
+        pub fn reward_pools(total_value_staked: Balance) -> RewardPools {
+            let config = ActiveInflationConfig::<T>::get();
+            let total_issuance = T::Currency::total_issuance();
+
+            // Calculate the adjustable part of the staker reward pool, according to formula:
+            // adjustable_part = max_adjustable_part * min(1, total_staked_percent / ideal_staked_percent)
+            // (These operations are overflow & zero-division safe)
+            let staked_ratio = Perquintill::from_rational(total_value_staked, total_issuance);
+            let adjustment_factor = staked_ratio / config.ideal_staking_rate;
+
+            RewardPools {
+                total_value_staked,
+                base_staker_reward_pool_per_era: config.base_staker_reward_pool_per_era,
+                adjustable_staker_reward_pool_per_era: adjustment_factor
+                    * config.adjustable_staker_reward_pool_per_era,
+                dapp_reward_pool_per_era: config.dapp_reward_pool_per_era,
+                bonus_reward_pool_per_period: config.bonus_reward_pool_per_period,
+            }
+        }
+
+        /// Projects the max payouts for the remainder of the ongoing cycle, including the ongoing era.
+        ///
+        /// The cycle ends at the `recalculation_era` of the active inflation configuration, which isn't necessarily aligned
+        /// with the start of a period, so the ongoing era & subperiod are taken from the provided dApp staking protocol state.
+        /// Each period started before the recalculation era begins with a voting subperiod.
+        /// Staker rewards are projected based on the provided total value staked.
+        pub fn cycle_payout_projection(
+            protocol_state: ProtocolStateInfo,
+            blocks_until_next_era: BlockNumber,
+            total_value_staked: Balance,
+        ) -> CyclePayoutProjection {
+            let config = ActiveInflationConfig::<T>::get();
+            let remaining_eras = config.recalculation_era.saturating_sub(protocol_state.era);
+            if remaining_eras.is_zero() {
+                return Default::default();
+            }
+
+            // 1. Count the remaining eras of the ongoing period, after the ongoing era.
+            let eras_per_build_and_earn_subperiod =
+                T::CycleConfiguration::eras_per_build_and_earn_subperiod();
+            let (ongoing_build_and_earn_eras, ongoing_period_eras) = match protocol_state.subperiod
+            {
+                SubperiodInfo::Voting => (0, eras_per_build_and_earn_subperiod),
+                SubperiodInfo::BuildAndEarn => (
+                    1,
+                    protocol_state
+                        .next_subperiod_start_era
+                        .saturating_sub(protocol_state.era)
+                        .saturating_sub(1),
+                ),
+            };
+            let later_eras = remaining_eras.saturating_sub(1);
+            let later_ongoing_period_eras = later_eras.min(ongoing_period_eras);
+
+            // 2. Count the eras of the periods started before the recalculation era, each starting with a voting era.
+            let later_periods_eras = later_eras.saturating_sub(later_ongoing_period_eras);
+            let eras_per_period = T::CycleConfiguration::eras_per_period().max(1);
+            let later_voting_eras = later_periods_eras
+                .saturating_add(eras_per_period - 1)
+                .saturating_div(eras_per_period);
+            let later_build_and_earn_eras = later_ongoing_period_eras
+                .saturating_add(later_periods_eras.saturating_sub(later_voting_eras));
+
+            let remaining_build_and_earn_eras =
+                later_build_and_earn_eras.saturating_add(ongoing_build_and_earn_eras);
+            let remaining_periods = later_voting_eras.saturating_add(1);
+
+            let blocks_per_era = T::CycleConfiguration::blocks_per_era();
+            let remaining_blocks = blocks_until_next_era
+                .saturating_add(later_build_and_earn_eras.saturating_mul(blocks_per_era))
+                .saturating_add(
+                    later_voting_eras
+                        .saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod())
+                        .saturating_mul(blocks_per_era),
+                );
+
+            // 3. Calculate the payouts.
+            let pools = Self::reward_pools(total_value_staked);
+            let staker_reward_pool_per_era = pools
+                .base_staker_reward_pool_per_era
+                .saturating_add(pools.adjustable_staker_reward_pool_per_era);
+
+            CyclePayoutProjection {
+                remaining_blocks,
+                remaining_build_and_earn_eras,
+                remaining_periods,
+                collators: config
+                    .collator_reward_per_block
+                    .saturating_mul(remaining_blocks.into()),
+                treasury: config
+                    .treasury_reward_per_block
+                    .saturating_mul(remaining_blocks.into()),
+                dapps: pools
+                    .dapp_reward_pool_per_era
+                    .saturating_mul(remaining_build_and_earn_eras.into()),
+                stakers: staker_reward_pool_per_era
+                    .saturating_mul(remaining_build_and_earn_eras.into()),
+                bonus: pools
+                    .bonus_reward_pool_per_period
+                    .saturating_mul(remaining_periods.into()),
+            }
+        }
+
         /// Check if payout cap limit would be reached after payout.
         fn is_payout_cap_limit_exceeded(payout: Balance) -> bool {
             let config = ActiveInflationConfig::<T>::get();
@@ -428,21 +755,13 @@ pub mod pallet {
 
     impl<T: Config> StakingRewardHandler<T::AccountId> for Pallet<T> {
         fn staker_and_dapp_reward_pools(total_value_staked: Balance) -> (Balance, Balance) {
-            let config = ActiveInflationConfig::<T>::get();
-            let total_issuance = T::Currency::total_issuance();
+            let pools = Self::reward_pools(total_value_staked);
 
-            // First calculate the adjustable part of the staker reward pool, according to formula:
-            // adjustable_part = max_adjustable_part * min(1, total_staked_percent / ideal_staked_percent)
-            // (These operations are overflow & zero-division safe)
-            let staked_ratio = Perquintill::from_rational(total_value_staked, total_issuance);
-            let adjustment_factor = staked_ratio / config.ideal_staking_rate;
-
-            let adjustable_part = adjustment_factor * config.adjustable_staker_reward_pool_per_era;
-            let staker_reward_pool = config
+            let staker_reward_pool = pools
                 .base_staker_reward_pool_per_era
-                .saturating_add(adjustable_part);
+                .saturating_add(pools.adjustable_staker_reward_pool_per_era);
 
-            (staker_reward_pool, config.dapp_reward_pool_per_era)
+            (staker_reward_pool, pools.dapp_reward_pool_per_era)
         }
 
         fn bonus_reward_pool() -> Balance {
@@ -463,6 +782,9 @@ pub mod pallet {
     }
 }
 
+/// Maximum number of cycles which can be projected by `issuance_projection`.
+pub const MAX_PROJECTED_CYCLES: u32 = 100;
+
 /// Configuration of the inflation.
 /// Contains information about rewards, when inflation is recalculated, etc.
 #[derive(Encode, Decode, MaxEncodedLen, Default, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
@@ -570,6 +892,10 @@ pub struct InflationParameters {
     /// Used to derive exact amount of adjustable staker rewards.
     #[codec(compact)]
     pub ideal_staking_rate: Perquintill,
+    /// Per-cycle multiplicative decay of the max inflation rate.
+    /// After each recalculation, max inflation rate is multiplied by this value. `100%` means there's no decay.
+    #[codec(compact)]
+    pub decay_rate: Perquintill,
 }
 
 impl InflationParameters {
@@ -595,6 +921,14 @@ impl InflationParameters {
             })
             == Some(Perquintill::one())
     }
+
+    /// Parameters to be used in the next cycle, with the decay applied to the max inflation rate.
+    pub fn decayed(&self) -> Self {
+        Self {
+            max_inflation_rate: self.max_inflation_rate * self.decay_rate,
+            ..*self
+        }
+    }
 }
 
 // Default inflation parameters, just to make sure genesis builder is happy
@@ -609,10 +943,82 @@ impl Default for InflationParameters {
             adjustable_stakers_part: Perquintill::from_percent(35),
             bonus_part: Perquintill::from_percent(12),
             ideal_staking_rate: Perquintill::from_percent(50),
+            decay_rate: Perquintill::one(),
         }
     }
 }
 
+/// Projected issuance for a single cycle.
+#[derive(Encode, Decode, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CycleIssuanceProjection {
+    /// Era at which the cycle starts.
+    #[codec(compact)]
+    pub start_era: EraNumber,
+    /// Max inflation rate used for the cycle.
+    #[codec(compact)]
+    pub max_inflation_rate: Perquintill,
+    /// Total issuance at the start of the cycle, assuming all the previous max emissions were issued in full.
+    #[codec(compact)]
+    pub total_issuance: Balance,
+    /// Max emission during the cycle.
+    #[codec(compact)]
+    pub max_emission: Balance,
+}
+
+/// Reward pools, based on the active inflation configuration & the total value staked.
+#[derive(Encode, Decode, Default, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardPools {
+    /// Total value staked, used to derive the adjustable staker reward pool.
+    #[codec(compact)]
+    pub total_value_staked: Balance,
+    /// Base staker reward pool per era.
+    #[codec(compact)]
+    pub base_staker_reward_pool_per_era: Balance,
+    /// Adjustable staker reward pool per era, according to the total value staked.
+    #[codec(compact)]
+    pub adjustable_staker_reward_pool_per_era: Balance,
+    /// dApp reward pool per era.
+    #[codec(compact)]
+    pub dapp_reward_pool_per_era: Balance,
+    /// Bonus reward pool per period.
+    #[codec(compact)]
+    pub bonus_reward_pool_per_period: Balance,
+}
+
+/// Projected max payouts for the remainder of the ongoing cycle.
+///
+/// dApp & staker payouts are upper bounds, since the reward pools aren't necessarily distributed in full.
+#[derive(Encode, Decode, Default, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CyclePayoutProjection {
+    /// Number of blocks remaining until the end of the cycle.
+    #[codec(compact)]
+    pub remaining_blocks: BlockNumber,
+    /// Number of build&earn eras remaining in the cycle, including the ongoing one.
+    #[codec(compact)]
+    pub remaining_build_and_earn_eras: EraNumber,
+    /// Number of periods remaining in the cycle, including the ongoing one.
+    #[codec(compact)]
+    pub remaining_periods: PeriodNumber,
+    /// Collator rewards paid out until the end of the cycle.
+    #[codec(compact)]
+    pub collators: Balance,
+    /// Treasury rewards paid out until the end of the cycle.
+    #[codec(compact)]
+    pub treasury: Balance,
+    /// dApp rewards for the remaining build&earn eras.
+    #[codec(compact)]
+    pub dapps: Balance,
+    /// Staker rewards for the remaining build&earn eras, based on the total value staked.
+    #[codec(compact)]
+    pub stakers: Balance,
+    /// Bonus rewards for the remaining periods.
+    #[codec(compact)]
+    pub bonus: Balance,
+}
+
 /// Defines functions used to payout the beneficiaries of block rewards
 pub trait PayoutPerBlock<Imbalance> {
     /// Payout reward to the treasury.
@@ -623,14 +1029,13 @@ pub trait PayoutPerBlock<Imbalance> {
 }
 
 /// `OnRuntimeUpgrade` logic for integrating this pallet into the live network.
-#[cfg(feature = "try-runtime")]
-use sp_std::vec::Vec;
 pub struct PalletInflationInitConfig<T, P>(PhantomData<(T, P, Weight)>);
 impl<T: Config, P: Get<(InflationParameters, EraNumber, Weight)>> OnRuntimeUpgrade
     for PalletInflationInitConfig<T, P>
 {
     fn on_runtime_upgrade() -> Weight {
-        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+        // Storage version `1` is the first version which contains the inflation parameters & configuration.
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
             return T::DbWeight::get().reads(1);
         }
 
@@ -0,0 +1,107 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Migration from storage version `1` to `2`.
+///
+/// Introduces the `decay_rate` into the `InflationParameters`.
+/// Existing parameters are translated without any decay, so the inflation remains unchanged.
+pub mod v2 {
+    use super::*;
+
+    /// `InflationParameters` layout used up to storage version `1`.
+    #[derive(Encode, Decode, Clone)]
+    pub struct InflationParametersV1 {
+        #[codec(compact)]
+        pub max_inflation_rate: Perquintill,
+        #[codec(compact)]
+        pub treasury_part: Perquintill,
+        #[codec(compact)]
+        pub collators_part: Perquintill,
+        #[codec(compact)]
+        pub dapps_part: Perquintill,
+        #[codec(compact)]
+        pub base_stakers_part: Perquintill,
+        #[codec(compact)]
+        pub adjustable_stakers_part: Perquintill,
+        #[codec(compact)]
+        pub bonus_part: Perquintill,
+        #[codec(compact)]
+        pub ideal_staking_rate: Perquintill,
+    }
+
+    /// Translates the `InflationParams` into the new layout, with no decay.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() >= 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let _ = InflationParams::<T>::translate::<InflationParametersV1, _>(|maybe_old| {
+                maybe_old.map(|old| InflationParameters {
+                    max_inflation_rate: old.max_inflation_rate,
+                    treasury_part: old.treasury_part,
+                    collators_part: old.collators_part,
+                    dapps_part: old.dapps_part,
+                    base_stakers_part: old.base_stakers_part,
+                    adjustable_stakers_part: old.adjustable_stakers_part,
+                    bonus_part: old.bonus_part,
+                    ideal_staking_rate: old.ideal_staking_rate,
+                    decay_rate: Perquintill::one(),
+                })
+            })
+            .map_err(|_| {
+                log::error!("Failed to translate inflation parameters.");
+            });
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            log::info!("Inflation pallet migrated to v2.");
+
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "Expected storage version 1."
+            );
+
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "Expected storage version 2."
+            );
+
+            let params = InflationParams::<T>::get();
+            ensure!(params.is_valid(), "Inflation parameters must be valid.");
+            ensure!(
+                params.decay_rate == Perquintill::one(),
+                "Inflation parameters must not decay."
+            );
+
+            Ok(())
+        }
+    }
+}
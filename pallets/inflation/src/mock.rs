@@ -48,6 +48,7 @@ pub const INIT_PARAMS: InflationParameters = InflationParameters {
     adjustable_stakers_part: Perquintill::from_percent(35),
     bonus_part: Perquintill::from_percent(12),
     ideal_staking_rate: Perquintill::from_percent(50),
+    decay_rate: Perquintill::one(),
 };
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
@@ -139,6 +140,7 @@ impl pallet_inflation::Config for Test {
     type PayoutPerBlock = DummyPayoutPerBlock;
     type CycleConfiguration = DummyCycleConfiguration;
     type RuntimeEvent = RuntimeEvent;
+    type MaxScheduledParams = ConstU32<4>;
     type WeightInfo = ();
 }
 
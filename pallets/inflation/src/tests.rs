@@ -467,3 +467,524 @@ fn test_genesis_build() {
         assert!(ActiveInflationConfig::<Test>::get().recalculation_era > 0);
     })
 }
+
+#[test]
+fn inflation_decay_is_applied_on_recalculation() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut params = INIT_PARAMS;
+        params.decay_rate = Perquintill::from_percent(90);
+        InflationParams::<Test>::put(params);
+
+        // New cycle uses the decayed max inflation rate
+        let total_issuance = Balances::total_issuance();
+        let config = Inflation::recalculate_inflation_for_new_cycle(100);
+        let decayed_params = InflationParams::<Test>::get();
+        assert_eq!(
+            decayed_params.max_inflation_rate,
+            params.max_inflation_rate * Perquintill::from_percent(90)
+        );
+        assert_eq!(decayed_params, params.decayed());
+        assert_eq!(
+            config.issuance_safety_cap,
+            total_issuance + decayed_params.max_inflation_rate * total_issuance
+        );
+
+        // The cycle after it decays the max inflation rate once more
+        let config = Inflation::recalculate_inflation_for_new_cycle(config.recalculation_era);
+        assert_eq!(
+            config.issuance_safety_cap,
+            total_issuance + decayed_params.decayed().max_inflation_rate * total_issuance
+        );
+        assert_eq!(InflationParams::<Test>::get(), decayed_params.decayed());
+    })
+}
+
+#[test]
+fn inflation_without_decay_remains_unchanged() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_eq!(INIT_PARAMS.decay_rate, Perquintill::one(), "Sanity check.");
+
+        let config = Inflation::recalculate_inflation_for_new_cycle(100);
+        assert_eq!(InflationParams::<Test>::get(), INIT_PARAMS);
+
+        let _ = Inflation::recalculate_inflation_for_new_cycle(config.recalculation_era);
+        assert_eq!(InflationParams::<Test>::get(), INIT_PARAMS);
+    })
+}
+
+#[test]
+fn force_inflation_recalculation_does_not_apply_decay() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut params = INIT_PARAMS;
+        params.decay_rate = Perquintill::from_percent(90);
+        InflationParams::<Test>::put(params);
+
+        // Forced recalculation doesn't start a new cycle, so the max inflation rate remains the same
+        let total_issuance = Balances::total_issuance();
+        for _ in 0..2 {
+            assert_ok!(Inflation::force_inflation_recalculation(
+                RuntimeOrigin::root(),
+                100,
+            ));
+            assert_eq!(
+                ActiveInflationConfig::<Test>::get().issuance_safety_cap,
+                total_issuance + params.max_inflation_rate * total_issuance
+            );
+            assert_eq!(InflationParams::<Test>::get(), params);
+        }
+    })
+}
+
+#[test]
+fn schedule_inflation_params_work() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut params_1 = INIT_PARAMS;
+        params_1.max_inflation_rate = Perquintill::from_percent(5);
+        let mut params_2 = INIT_PARAMS;
+        params_2.decay_rate = Perquintill::from_percent(95);
+
+        // Schedule out of order, ensure entries are sorted by era
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            200,
+            params_2
+        ));
+        System::assert_last_event(Event::InflationParametersScheduled { era: 200 }.into());
+
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            100,
+            params_1
+        ));
+        System::assert_last_event(Event::InflationParametersScheduled { era: 100 }.into());
+
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(100, params_1), (200, params_2)]
+        );
+
+        // Scheduling for the same era replaces the existing entry
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            200,
+            params_1
+        ));
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(100, params_1), (200, params_1)]
+        );
+
+        // Active parameters remain unchanged until the recalculation
+        assert_eq!(InflationParams::<Test>::get(), INIT_PARAMS);
+    })
+}
+
+#[test]
+fn schedule_inflation_params_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Invalid params cannot be scheduled
+        let mut invalid_params = INIT_PARAMS;
+        invalid_params.bonus_part = Zero::zero();
+        assert_noop!(
+            Inflation::schedule_inflation_params(RuntimeOrigin::root(), 100, invalid_params),
+            Error::<Test>::InvalidInflationParameters
+        );
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::schedule_inflation_params(RuntimeOrigin::signed(1), 100, INIT_PARAMS),
+            BadOrigin
+        );
+
+        // Schedule the max number of entries, next one must fail
+        let max_scheduled = <Test as Config>::MaxScheduledParams::get();
+        for era in 0..max_scheduled {
+            assert_ok!(Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                100 + era,
+                INIT_PARAMS
+            ));
+        }
+        assert_noop!(
+            Inflation::schedule_inflation_params(RuntimeOrigin::root(), 1000, INIT_PARAMS),
+            Error::<Test>::TooManyScheduledParams
+        );
+
+        // Replacing an existing entry is still possible
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            100,
+            INIT_PARAMS
+        ));
+    })
+}
+
+#[test]
+fn cancel_scheduled_inflation_params_work() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            100,
+            INIT_PARAMS
+        ));
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            200,
+            INIT_PARAMS
+        ));
+
+        assert_ok!(Inflation::cancel_scheduled_inflation_params(
+            RuntimeOrigin::root(),
+            100
+        ));
+        System::assert_last_event(Event::ScheduledInflationParametersCancelled { era: 100 }.into());
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(200, INIT_PARAMS)]
+        );
+    })
+}
+
+#[test]
+fn cancel_scheduled_inflation_params_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            100,
+            INIT_PARAMS
+        ));
+
+        assert_noop!(
+            Inflation::cancel_scheduled_inflation_params(RuntimeOrigin::root(), 101),
+            Error::<Test>::NoScheduledParams
+        );
+        assert_noop!(
+            Inflation::cancel_scheduled_inflation_params(RuntimeOrigin::signed(1), 100),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn scheduled_inflation_params_are_applied_on_recalculation() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut params_1 = INIT_PARAMS;
+        params_1.max_inflation_rate = Perquintill::from_percent(5);
+        let mut params_2 = INIT_PARAMS;
+        params_2.max_inflation_rate = Perquintill::from_percent(4);
+        params_2.decay_rate = Perquintill::from_percent(80);
+        let mut params_3 = INIT_PARAMS;
+        params_3.max_inflation_rate = Perquintill::from_percent(3);
+
+        for (era, params) in [(90, params_1), (100, params_2), (200, params_3)] {
+            assert_ok!(Inflation::schedule_inflation_params(
+                RuntimeOrigin::root(),
+                era,
+                params
+            ));
+        }
+
+        // Recalculation before any entry is due doesn't change the schedule
+        let _ = Inflation::recalculate_inflation_for_new_cycle(50);
+        assert_eq!(InflationParams::<Test>::get(), INIT_PARAMS);
+        assert_eq!(ScheduledInflationParams::<Test>::get().len(), 3);
+
+        // Latest of the due entries is applied, all due entries are removed
+        let total_issuance = Balances::total_issuance();
+        let config = Inflation::recalculate_inflation_for_new_cycle(110);
+        System::assert_has_event(Event::ScheduledInflationParametersApplied { era: 100 }.into());
+
+        // Scheduled parameters are used as they are, without the decay
+        assert_eq!(
+            config.issuance_safety_cap,
+            total_issuance + params_2.max_inflation_rate * total_issuance
+        );
+        assert_eq!(InflationParams::<Test>::get(), params_2);
+        assert_eq!(
+            ScheduledInflationParams::<Test>::get().into_inner(),
+            vec![(200, params_3)]
+        );
+    })
+}
+
+#[test]
+fn issuance_projection_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let mut params = INIT_PARAMS;
+        params.decay_rate = Perquintill::from_percent(90);
+        InflationParams::<Test>::put(params);
+
+        let config = ActiveInflationConfig::<Test>::get();
+        let eras_per_cycle = <Test as Config>::CycleConfiguration::eras_per_cycle();
+
+        // Schedule new params for the start of the third projected cycle
+        let mut scheduled_params = INIT_PARAMS;
+        scheduled_params.max_inflation_rate = Perquintill::from_percent(10);
+        let scheduled_era = config.recalculation_era + 2 * eras_per_cycle;
+        assert_ok!(Inflation::schedule_inflation_params(
+            RuntimeOrigin::root(),
+            scheduled_era,
+            scheduled_params
+        ));
+
+        let projection = Inflation::issuance_projection(4);
+        assert_eq!(projection.len(), 4);
+
+        // Verify each cycle is projected as expected
+        let expected_rates = [
+            params.decayed().max_inflation_rate,
+            params.decayed().decayed().max_inflation_rate,
+            scheduled_params.max_inflation_rate,
+            scheduled_params.max_inflation_rate,
+        ];
+        let mut total_issuance = config.issuance_safety_cap;
+        for (idx, (cycle, rate)) in projection.iter().zip(expected_rates).enumerate() {
+            let max_emission = rate * total_issuance;
+            assert_eq!(
+                *cycle,
+                CycleIssuanceProjection {
+                    start_era: config.recalculation_era + idx as u32 * eras_per_cycle,
+                    max_inflation_rate: rate,
+                    total_issuance,
+                    max_emission,
+                }
+            );
+            total_issuance += max_emission;
+        }
+
+        // Projection has no effect on the storage
+        assert_eq!(InflationParams::<Test>::get(), params);
+        assert_eq!(ScheduledInflationParams::<Test>::get().len(), 1);
+
+        // Number of projected cycles is limited
+        assert_eq!(
+            Inflation::issuance_projection(u32::MAX).len() as u32,
+            MAX_PROJECTED_CYCLES
+        );
+    })
+}
+
+#[test]
+fn reward_pools_are_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let total_issuance = Balances::total_issuance();
+        let config = ActiveInflationConfig::<Test>::get();
+
+        // Below the ideal staking rate, only part of the adjustable pool is used
+        let test_rate = config.ideal_staking_rate - Perquintill::from_percent(11);
+        let total_value_staked = test_rate * total_issuance;
+        let pools = Inflation::reward_pools(total_value_staked);
+
+        assert_eq!(
+            pools,
+            RewardPools {
+                total_value_staked,
+                base_staker_reward_pool_per_era: config.base_staker_reward_pool_per_era,
+                adjustable_staker_reward_pool_per_era: test_rate / config.ideal_staking_rate
+                    * config.adjustable_staker_reward_pool_per_era,
+                dapp_reward_pool_per_era: config.dapp_reward_pool_per_era,
+                bonus_reward_pool_per_period: config.bonus_reward_pool_per_period,
+            }
+        );
+
+        // Must be aligned with the pools used by dApp staking
+        let (staker_pool, dapp_pool) = Inflation::staker_and_dapp_reward_pools(total_value_staked);
+        assert_eq!(
+            staker_pool,
+            pools.base_staker_reward_pool_per_era + pools.adjustable_staker_reward_pool_per_era
+        );
+        assert_eq!(dapp_pool, pools.dapp_reward_pool_per_era);
+    })
+}
+
+/// dApp staking protocol state in the specified era & subperiod.
+fn protocol_state(
+    era: EraNumber,
+    subperiod: SubperiodInfo,
+    next_subperiod_start_era: EraNumber,
+) -> ProtocolStateInfo {
+    ProtocolStateInfo {
+        era,
+        next_era_start: 0,
+        period: 1,
+        subperiod,
+        next_subperiod_start_era,
+        maintenance: false,
+    }
+}
+
+#[test]
+fn cycle_payout_projection_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        type CycleConfig = <Test as Config>::CycleConfiguration;
+
+        let total_issuance = Balances::total_issuance();
+        let config = ActiveInflationConfig::<Test>::get();
+        let total_value_staked = config.ideal_staking_rate * total_issuance;
+        let pools = Inflation::reward_pools(total_value_staked);
+        let staker_pool =
+            pools.base_staker_reward_pool_per_era + pools.adjustable_staker_reward_pool_per_era;
+
+        let cycle_start_era = config.recalculation_era - CycleConfig::eras_per_cycle();
+        let blocks_per_era = CycleConfig::blocks_per_era();
+        let voting_blocks = CycleConfig::eras_per_voting_subperiod() * blocks_per_era;
+        let eras_per_build_and_earn = CycleConfig::eras_per_build_and_earn_subperiod();
+
+        // 1st scenario - start of the cycle, the whole cycle remains
+        let projection = Inflation::cycle_payout_projection(
+            protocol_state(cycle_start_era, SubperiodInfo::Voting, cycle_start_era + 1),
+            voting_blocks,
+            total_value_staked,
+        );
+        let build_and_earn_eras = CycleConfig::build_and_earn_eras_per_cycle();
+        let blocks = CycleConfig::blocks_per_cycle();
+        let periods = CycleConfig::periods_per_cycle();
+        assert_eq!(
+            projection,
+            CyclePayoutProjection {
+                remaining_blocks: blocks,
+                remaining_build_and_earn_eras: build_and_earn_eras,
+                remaining_periods: periods,
+                collators: config.collator_reward_per_block * Balance::from(blocks),
+                treasury: config.treasury_reward_per_block * Balance::from(blocks),
+                dapps: pools.dapp_reward_pool_per_era * Balance::from(build_and_earn_eras),
+                stakers: staker_pool * Balance::from(build_and_earn_eras),
+                bonus: pools.bonus_reward_pool_per_period * Balance::from(periods),
+            }
+        );
+
+        // 2nd scenario - in the middle of the first build&earn era
+        let blocks_until_next_era = 3;
+        let projection = Inflation::cycle_payout_projection(
+            protocol_state(
+                cycle_start_era + 1,
+                SubperiodInfo::BuildAndEarn,
+                cycle_start_era + 1 + eras_per_build_and_earn,
+            ),
+            blocks_until_next_era,
+            total_value_staked,
+        );
+        let blocks = CycleConfig::blocks_per_cycle() - voting_blocks - blocks_per_era
+            + blocks_until_next_era;
+        assert_eq!(projection.remaining_blocks, blocks);
+        assert_eq!(
+            projection.remaining_build_and_earn_eras,
+            build_and_earn_eras
+        );
+        assert_eq!(projection.remaining_periods, periods);
+        assert_eq!(
+            projection.collators,
+            config.collator_reward_per_block * Balance::from(blocks)
+        );
+
+        // 3rd scenario - voting subperiod of the second period
+        let second_period_start_era = cycle_start_era + CycleConfig::eras_per_period();
+        let projection = Inflation::cycle_payout_projection(
+            protocol_state(
+                second_period_start_era,
+                SubperiodInfo::Voting,
+                second_period_start_era + 1,
+            ),
+            voting_blocks,
+            total_value_staked,
+        );
+        let build_and_earn_eras = build_and_earn_eras - eras_per_build_and_earn;
+        assert_eq!(
+            projection.remaining_blocks,
+            CycleConfig::blocks_per_cycle() / periods * (periods - 1)
+        );
+        assert_eq!(
+            projection.remaining_build_and_earn_eras,
+            build_and_earn_eras
+        );
+        assert_eq!(projection.remaining_periods, periods - 1);
+        assert_eq!(
+            projection.stakers,
+            staker_pool * Balance::from(build_and_earn_eras)
+        );
+
+        // 4th scenario - last era of the cycle
+        let projection = Inflation::cycle_payout_projection(
+            protocol_state(
+                config.recalculation_era - 1,
+                SubperiodInfo::BuildAndEarn,
+                config.recalculation_era,
+            ),
+            blocks_until_next_era,
+            total_value_staked,
+        );
+        assert_eq!(projection.remaining_blocks, blocks_until_next_era);
+        assert_eq!(projection.remaining_build_and_earn_eras, 1);
+        assert_eq!(projection.remaining_periods, 1);
+        assert_eq!(projection.dapps, pools.dapp_reward_pool_per_era);
+
+        // 5th scenario - recalculation is due, nothing remains
+        assert_eq!(
+            Inflation::cycle_payout_projection(
+                protocol_state(
+                    config.recalculation_era,
+                    SubperiodInfo::Voting,
+                    config.recalculation_era + 1,
+                ),
+                blocks_until_next_era,
+                total_value_staked
+            ),
+            Default::default()
+        );
+    })
+}
+
+#[test]
+fn cycle_payout_projection_with_cycle_not_aligned_to_periods_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        type CycleConfig = <Test as Config>::CycleConfiguration;
+
+        // Cycle started in the second to last build&earn era of a period,
+        // so the last period of the cycle ends before its build&earn subperiod does.
+        let config = ActiveInflationConfig::<Test>::get();
+        let cycle_start_era = config.recalculation_era - CycleConfig::eras_per_cycle();
+        let blocks_until_next_era = 3;
+        let total_value_staked = 0;
+        let projection = Inflation::cycle_payout_projection(
+            protocol_state(
+                cycle_start_era,
+                SubperiodInfo::BuildAndEarn,
+                cycle_start_era + 2,
+            ),
+            blocks_until_next_era,
+            total_value_staked,
+        );
+
+        // Ongoing & one more build&earn era of the ongoing period, followed by the full periods.
+        // The last period has a single build&earn era less than the full one.
+        let periods = CycleConfig::periods_per_cycle();
+        let build_and_earn_eras = 2
+            + (periods - 1) * CycleConfig::eras_per_build_and_earn_subperiod()
+            + CycleConfig::eras_per_build_and_earn_subperiod()
+            - 2;
+        let blocks_per_era = CycleConfig::blocks_per_era();
+        let blocks = blocks_until_next_era
+            + (build_and_earn_eras - 1) * blocks_per_era
+            + periods * CycleConfig::eras_per_voting_subperiod() * blocks_per_era;
+        assert_eq!(
+            build_and_earn_eras + periods,
+            CycleConfig::eras_per_cycle(),
+            "Sanity check."
+        );
+
+        let pools = Inflation::reward_pools(total_value_staked);
+        assert_eq!(
+            projection,
+            CyclePayoutProjection {
+                remaining_blocks: blocks,
+                remaining_build_and_earn_eras: build_and_earn_eras,
+                remaining_periods: periods + 1,
+                collators: config.collator_reward_per_block * Balance::from(blocks),
+                treasury: config.treasury_reward_per_block * Balance::from(blocks),
+                dapps: pools.dapp_reward_pool_per_era * Balance::from(build_and_earn_eras),
+                stakers: pools.base_staker_reward_pool_per_era * Balance::from(build_and_earn_eras),
+                bonus: pools.bonus_reward_pool_per_period * Balance::from(periods + 1),
+            }
+        );
+    })
+}
@@ -37,15 +37,16 @@ mod benchmarks {
                 .try_push(value)
                 .expect("Must succeed since we are iterating to the limit");
         }
-        CurrentBlockValues::<T>::put(result);
+        let currency_id = <T as Config>::NativeCurrencyId::get();
+        CurrentBlockValues::<T>::insert(currency_id, result);
 
         #[block]
         {
-            Pallet::<T>::process_block_aggregated_values();
+            Pallet::<T>::process_block_aggregated_values(currency_id);
         }
 
         assert!(
-            CurrentBlockValues::<T>::get().is_empty(),
+            CurrentBlockValues::<T>::get(currency_id).is_empty(),
             "Should have been cleaned up."
         );
     }
@@ -53,7 +54,8 @@ mod benchmarks {
     #[benchmark]
     fn process_intermediate_aggregated_values() {
         // 1. Fill up the current aggregator and make it trigger on the current block end
-        IntermediateValueAggregator::<T>::mutate(|a| {
+        let currency_id = <T as Config>::NativeCurrencyId::get();
+        IntermediateValueAggregator::<T>::mutate(currency_id, |a| {
             a.limit_block = frame_system::Pallet::<T>::block_number().saturated_into();
 
             a.total = CurrencyAmount::from_rational(1234, 10);
@@ -62,27 +64,38 @@ mod benchmarks {
 
         // 2. Fill up the circular buffer with some values
         let buffer_length = <T as Config>::CircularBufferLength::get();
-        ValuesCircularBuffer::<T>::mutate(|b| {
+        ValuesCircularBuffer::<T>::mutate(currency_id, |b| {
             for x in 1..=buffer_length {
                 b.add(CurrencyAmount::from_rational(x as u128 + 3, 10));
             }
         });
         assert_eq!(
-            ValuesCircularBuffer::<T>::get().buffer.len(),
+            ValuesCircularBuffer::<T>::get(currency_id).buffer.len(),
             buffer_length as usize,
             "Sanity check."
         );
 
-        // 3. Prepare local variables
-        let buffer_snapshot = ValuesCircularBuffer::<T>::get();
+        // 3. Reach the submitter quorum, and trip the circuit breaker so it gets reset
+        let submitters: Vec<T::AccountId> = (0..<T as Config>::MinSubmitters::get())
+            .map(|idx| account("submitter", idx, 0))
+            .collect();
+        AggregationSubmitters::<T>::insert(
+            currency_id,
+            BoundedVec::try_from(submitters).expect("Must fit since it's exactly the limit."),
+        );
+        FrozenPrice::<T>::insert(currency_id, CurrencyAmount::from_rational(5, 10));
+
+        // 4. Prepare local variables
+        let buffer_snapshot = ValuesCircularBuffer::<T>::get(currency_id);
         let current_block = frame_system::Pallet::<T>::block_number();
 
         #[block]
         {
-            Pallet::<T>::process_intermediate_aggregated_values(current_block);
+            Pallet::<T>::process_intermediate_aggregated_values(currency_id, current_block);
         }
 
-        assert!(ValuesCircularBuffer::<T>::get() != buffer_snapshot);
+        assert!(ValuesCircularBuffer::<T>::get(currency_id) != buffer_snapshot);
+        assert!(!FrozenPrice::<T>::contains_key(currency_id));
     }
 
     impl_benchmark_test_suite!(
@@ -22,14 +22,20 @@
 //!
 //! Purpose of this pallet is to aggregate price data over some time, and then calculate the moving average.
 //!
+//! Aggregation is done independently for each of the tracked currencies, native currency being one of them.
+//!
 //! ## Solution
 //!
 //! The overall solution is broken down into several steps that occur over the course of various time periods.
 //!
 //! ### Block Aggregation
 //!
-//! During each block, the native currency price data is accumulated. This is done 'outside' the pallet, and it's only expected
-//! that 'something' will push this data to the price aggregator pallet. The pallet itself doesn't care about the source of the data, nor who submitted it.
+//! During each block, the tracked currencies price data is accumulated. This is done 'outside' the pallet, and it's only expected
+//! that 'something' will push this data to the price aggregator pallet. The pallet itself doesn't care about the source of the data,
+//! it only keeps track of how many distinct submitters provided the data during the aggregation period.
+//!
+//! Submitted values which deviate from the current moving average by more than the configured bound are rejected as outliers.
+//! In case moving average isn't available yet, or the circuit breaker is tripped (see below), all values are accepted.
 //!
 //! At the end of each block, accumulated data is processed according to the specified algorithm (e.g. can be average, median, or something else).
 //! In case processing was successful, the result is stored in the intermediate value aggregator.
@@ -38,13 +44,38 @@
 //! ### Intermediate Value Aggregation
 //!
 //! After a predetermined amount of time (blocks) has passed, the average value is calculated from the intermediate value aggregator.
-//! In case it's a valid value (non-zero), it's pushed into the circular buffer used to calculate the moving average.
+//! In case it's a valid value (non-zero), and the minimum submitter quorum has been reached during the aggregation period,
+//! it's pushed into the circular buffer used to calculate the moving average.
 //! In case of an error, the value is simply ignored.
 //!
+//! ### Stale Feed Circuit Breaker
+//!
+//! In case no valid data has been received for a currency during the configured number of blocks, the feed is considered stale.
+//! The circuit breaker is tripped, and the moving average at that moment is frozen, i.e. it's provided as the average price
+//! until the feed recovers. Feed is considered recovered once a new valid value is pushed into the circular buffer.
+//!
+//! While the circuit breaker is tripped, deviation bounds aren't enforced, so a persistent market move can't lock the feed forever.
+//!
 //! ### Moving Average Calculation
 //!
-//! The moving average is calculated from the circular buffer, and is used to provide the 'average' price of the native currency, over some time period.
+//! The moving average is calculated from the circular buffer, and is used to provide the 'average' price of the currency, over some time period.
 //! It's important to note that the moving average is not a 'real-time' value, but rather a 'lagging' indicator.
+//!
+//! The calculation method is configurable, and can be one of:
+//! * simple moving average - each value in the buffer has the same weight
+//! * time-weighted moving average - each value is weighted by the duration of its aggregation period
+//! * exponential moving average - more recent values are given more weight, according to the smoothing factor
+//!
+//! Compared to the simple moving average, the exponential moving average reacts faster to the price changes,
+//! which also means that, with a high enough smoothing factor, short-lived price spikes affect it more.
+//!
+//! ## Interface
+//!
+//! The native currency moving average is provided via the `PriceProvider` trait,
+//! while the moving average of any tracked currency is provided via the `MultiCurrencyPriceProvider` trait.
+//!
+//! The native currency moving average is also provided via the `FalliblePriceProvider` trait,
+//! which doesn't provide any price in case the circuit breaker is tripped.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -53,17 +84,22 @@ use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_arithmetic::{
     fixed_point::FixedU128,
-    traits::{CheckedAdd, SaturatedConversion, Saturating, Zero},
+    traits::{CheckedAdd, One, SaturatedConversion, Saturating, Zero},
+    FixedPointNumber, Perbill,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use orml_traits::OnNewData;
 
 use astar_primitives::{
-    oracle::{CurrencyAmount, CurrencyId, PriceProvider},
+    oracle::{
+        CurrencyAmount, CurrencyId, FalliblePriceProvider, MultiCurrencyPriceProvider,
+        PriceProvider,
+    },
     BlockNumber,
 };
 
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -124,6 +160,42 @@ impl ProcessBlockValues for MedianBlockValue {
     }
 }
 
+/// Trait for calculating the moving average from the values stored in the circular buffer.
+///
+/// This can be anything from simple moving average, to time-weighted or exponential moving average.
+pub trait CalculateMovingAverage {
+    /// Calculate the moving average of the values stored in the circular buffer.
+    ///
+    /// In case buffer is empty, zero is returned.
+    fn calculate<L: Get<u32>>(buffer: &CircularBuffer<L>) -> CurrencyAmount;
+}
+
+/// Used to calculate the simple moving average, where each value has the same weight.
+pub struct SimpleMovingAverage;
+impl CalculateMovingAverage for SimpleMovingAverage {
+    fn calculate<L: Get<u32>>(buffer: &CircularBuffer<L>) -> CurrencyAmount {
+        buffer.average()
+    }
+}
+
+/// Used to calculate the time-weighted moving average, where each value is weighted by the number of blocks it was valid for.
+pub struct TimeWeightedMovingAverage;
+impl CalculateMovingAverage for TimeWeightedMovingAverage {
+    fn calculate<L: Get<u32>>(buffer: &CircularBuffer<L>) -> CurrencyAmount {
+        buffer.time_weighted_average()
+    }
+}
+
+/// Used to calculate the exponential moving average, with the configurable smoothing factor.
+///
+/// The higher the smoothing factor, the more weight is given to the more recent values.
+pub struct ExponentialMovingAverage<S>(PhantomData<S>);
+impl<S: Get<Perbill>> CalculateMovingAverage for ExponentialMovingAverage<S> {
+    fn calculate<L: Get<u32>>(buffer: &CircularBuffer<L>) -> CurrencyAmount {
+        buffer.exponential_average(S::get())
+    }
+}
+
 /// Used to aggregate the accumulated values over some time period.
 ///
 /// To avoid having a large memory footprint, values are summed up into a single accumulator.
@@ -183,6 +255,8 @@ impl ValueAggregator {
 ///
 /// Inserts values sequentially into the buffer, until the buffer has been filled out.
 /// After that, the oldest value is always overwritten with the new value.
+///
+/// Each value is accompanied by the duration of its aggregation period, expressed in the number of blocks.
 #[derive(
     Encode,
     Decode,
@@ -201,11 +275,42 @@ pub struct CircularBuffer<L: Get<u32>> {
     /// Next index to write to.
     #[codec(compact)]
     pub(crate) head: u32,
+    /// Number of blocks each value was valid for, at the matching indices.
+    pub(crate) durations: BoundedVec<BlockNumber, L>,
 }
 
 impl<L: Get<u32>> CircularBuffer<L> {
+    /// Number of values currently stored in the circular buffer.
+    pub fn len(&self) -> u32 {
+        self.buffer.len() as u32
+    }
+
+    /// `true` if no values are stored in the circular buffer, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Most recently added value, if any.
+    pub fn latest(&self) -> Option<CurrencyAmount> {
+        let latest_idx = if self.head.is_zero() {
+            self.buffer.len().checked_sub(1)?
+        } else {
+            self.head.saturating_sub(1) as usize
+        };
+
+        self.buffer.get(latest_idx).copied()
+    }
+
     /// Adds a new value to the circular buffer, possibly overriding the oldest value if capacity is filled.
+    ///
+    /// Value is considered to have been aggregated during a single block.
     pub fn add(&mut self, value: CurrencyAmount) {
+        self.add_with_duration(value, 1)
+    }
+
+    /// Adds a new value, valid for the specified number of blocks, to the circular buffer,
+    /// possibly overriding the oldest value if capacity is filled.
+    pub fn add_with_duration(&mut self, value: CurrencyAmount, duration: BlockNumber) {
         // This can never happen, parameters must ensure that.
         // But we still check it and log an error if it does.
         if self.head >= L::get() || self.head as usize > self.buffer.len() {
@@ -227,9 +332,74 @@ impl<L: Get<u32>> CircularBuffer<L> {
             // Vec is not full yet, so we can just push the value
             let _ignorable = self.buffer.try_push(value);
         }
+
+        if self.durations.len() > self.head as usize {
+            self.durations[self.head as usize] = duration;
+        } else {
+            let _ignorable = self.durations.try_push(duration);
+        }
+
         self.head = self.head.saturating_add(1) % L::get();
     }
 
+    /// Iterator over the values & their durations, from the oldest to the most recent one.
+    fn chronological_entries(&self) -> impl Iterator<Item = (CurrencyAmount, BlockNumber)> + '_ {
+        // Until the buffer is filled out, the oldest value is at the start.
+        let oldest_idx = if self.buffer.len() as u32 >= L::get() {
+            self.head as usize
+        } else {
+            0
+        };
+
+        self.buffer
+            .iter()
+            .zip(self.durations.iter().chain(sp_std::iter::repeat(&1)))
+            .map(|(value, duration)| (*value, *duration))
+            .cycle()
+            .skip(oldest_idx)
+            .take(self.buffer.len())
+    }
+
+    /// Returns the time-weighted average of the accumulated values.
+    ///
+    /// Each value is weighted by the duration of its aggregation period.
+    pub fn time_weighted_average(&self) -> CurrencyAmount {
+        let (weighted_sum, total_duration) = self.chronological_entries().fold(
+            (0_u128, 0_u128),
+            |(weighted_sum, total_duration), (value, duration)| {
+                (
+                    weighted_sum.saturating_add(value.into_inner().saturating_mul(duration.into())),
+                    total_duration.saturating_add(duration.into()),
+                )
+            },
+        );
+
+        if total_duration.is_zero() {
+            return self.average();
+        }
+
+        CurrencyAmount::from_inner(weighted_sum / total_duration)
+    }
+
+    /// Returns the exponential moving average of the accumulated values, using the specified smoothing factor.
+    ///
+    /// Calculation starts from the oldest value, with each following value being weighted by the smoothing factor.
+    pub fn exponential_average(&self, smoothing: Perbill) -> CurrencyAmount {
+        let smoothing: CurrencyAmount = smoothing.into();
+        let remainder = CurrencyAmount::one().saturating_sub(smoothing);
+
+        let mut entries = self.chronological_entries();
+        let Some((first_value, _)) = entries.next() else {
+            return CurrencyAmount::zero();
+        };
+
+        entries.fold(first_value, |average, (value, _)| {
+            value
+                .saturating_mul(smoothing)
+                .saturating_add(average.saturating_mul(remainder))
+        })
+    }
+
     /// Returns the average of the accumulated values.
     pub fn average(&self) -> CurrencyAmount {
         if self.buffer.is_empty() {
@@ -255,7 +425,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -276,88 +446,199 @@ pub mod pallet {
         /// Native currency ID that this pallet is supposed to track.
         type NativeCurrencyId: Get<CurrencyId>;
 
+        /// All the currencies this pallet is supposed to track, native currency included.
+        /// Values of any other currency are ignored.
+        ///
+        /// Aggregation is done for each of them in every block, so the list should be kept short.
+        type TrackedCurrencies: Get<Vec<CurrencyId>>;
+
         /// Maximum length of the circular buffer used to calculate the moving average.
         #[pallet::constant]
         type CircularBufferLength: Get<u32>;
 
+        /// Used to calculate the moving average from the values stored in the circular buffer.
+        type MovingAverage: CalculateMovingAverage;
+
         /// Duration of aggregation period expressed in the number of blocks.
         /// During this time, currency values are aggregated, and are then used to calculate the average value.
         #[pallet::constant]
         type AggregationDuration: Get<BlockNumberFor<Self>>;
 
+        /// Maximum allowed deviation of a submitted value from the current moving average.
+        /// Values outside of these bounds are rejected as outliers.
+        ///
+        /// Zero disables the outlier rejection.
+        #[pallet::constant]
+        type MaxDeviation: Get<Perbill>;
+
+        /// Minimum number of distinct submitters required during an aggregation period,
+        /// for the aggregated value to be pushed into the circular buffer.
+        ///
+        /// Zero disables the quorum requirement.
+        #[pallet::constant]
+        type MinSubmitters: Get<u32>;
+
+        /// Number of blocks without any valid data after which the feed is considered stale,
+        /// and the circuit breaker is tripped.
+        #[pallet::constant]
+        type StaleFeedThreshold: Get<BlockNumberFor<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
+        /// Initial circular buffer of the native currency.
         pub circular_buffer: BoundedVec<CurrencyAmount, T::CircularBufferLength>,
     }
 
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            ValuesCircularBuffer::<T>::put(CircularBuffer::<T::CircularBufferLength> {
-                buffer: self.circular_buffer.clone(),
-                head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
-            });
+            let aggregation_duration: BlockNumber = T::AggregationDuration::get().saturated_into();
+            ValuesCircularBuffer::<T>::insert(
+                T::NativeCurrencyId::get(),
+                CircularBuffer::<T::CircularBufferLength> {
+                    buffer: self.circular_buffer.clone(),
+                    head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
+                    durations: BoundedVec::truncate_from(
+                        sp_std::vec![aggregation_duration; self.circular_buffer.len()],
+                    ),
+                },
+            );
 
-            IntermediateValueAggregator::<T>::mutate(|aggregator| {
-                aggregator.limit_block = T::AggregationDuration::get().saturated_into();
-            });
+            for currency_id in T::TrackedCurrencies::get() {
+                IntermediateValueAggregator::<T>::mutate(currency_id, |aggregator| {
+                    aggregator.limit_block = T::AggregationDuration::get().saturated_into();
+                });
+            }
         }
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// New average native currency value has been calculated and pushed into the moving average buffer.
-        AverageAggregatedValue { value: CurrencyAmount },
+        /// New average currency value has been calculated and pushed into the moving average buffer.
+        AverageAggregatedValue {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+        },
+        /// Submitted value deviates too much from the moving average, and has been rejected.
+        OutlierRejected {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+        },
+        /// Minimum submitter quorum hasn't been reached during the aggregation period,
+        /// so the aggregated value hasn't been pushed into the moving average buffer.
+        QuorumNotReached {
+            currency_id: CurrencyId,
+            submitters: u32,
+        },
+        /// No valid data has been received for too long, the circuit breaker has been tripped.
+        StaleFeedDetected {
+            currency_id: CurrencyId,
+            frozen_price: CurrencyAmount,
+        },
+        /// Valid data is being received again, the circuit breaker has been reset.
+        FeedRecovered { currency_id: CurrencyId },
     }
 
-    /// Storage for the accumulated native currency price in the current block.
+    /// Storage for the accumulated currency prices in the current block.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type CurrentBlockValues<T: Config> =
-        StorageValue<_, BoundedVec<CurrencyAmount, T::MaxValuesPerBlock>, ValueQuery>;
+    pub type CurrentBlockValues<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        BoundedVec<CurrencyAmount, T::MaxValuesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Used to store the aggregated processed block values during some time period, per currency.
+    #[pallet::storage]
+    pub type IntermediateValueAggregator<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, ValueAggregator, ValueQuery>;
 
-    /// Used to store the aggregated processed block values during some time period.
+    /// Used to store aggregated intermediate values for some time period, per currency.
+    #[pallet::storage]
+    pub type ValuesCircularBuffer<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        CircularBuffer<T::CircularBufferLength>,
+        ValueQuery,
+    >;
+
+    /// Distinct submitters of the valid values during the ongoing aggregation period, per currency.
+    ///
+    /// Only tracked until the minimum quorum is reached.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type IntermediateValueAggregator<T: Config> = StorageValue<_, ValueAggregator, ValueQuery>;
+    pub type AggregationSubmitters<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        BoundedVec<T::AccountId, T::MinSubmitters>,
+        ValueQuery,
+    >;
+
+    /// Block in which the last valid value was pushed into the circular buffer, per currency.
+    ///
+    /// Values which don't meet the quorum, or which average to zero, don't refresh it.
+    #[pallet::storage]
+    pub type LastValidValueBlock<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, BlockNumber, OptionQuery>;
 
-    /// Used to store aggregated intermediate values for some time period.
+    /// Price frozen by the tripped circuit breaker, per currency.
+    /// While present, it's provided as the average price.
     #[pallet::storage]
-    pub type ValuesCircularBuffer<T: Config> =
-        StorageValue<_, CircularBuffer<T::CircularBufferLength>, ValueQuery>;
+    pub type FrozenPrice<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, CurrencyAmount, OptionQuery>;
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-            // Need to account for the reads and writes of:
-            // - CurrentBlockValues
-            // - IntermediateValueAggregator
-            //
-            // Also need to account for the weight of processing block accumulated values.
-            let mut total_weight = T::DbWeight::get()
-                .reads_writes(2, 2)
-                .saturating_add(T::WeightInfo::process_block_aggregated_values());
-
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                total_weight
-                    .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+            let mut total_weight = Weight::zero();
+
+            for currency_id in T::TrackedCurrencies::get() {
+                // Need to account for the reads and writes of:
+                // - CurrentBlockValues
+                // - IntermediateValueAggregator
+                // - LastValidValueBlock
+                // - FrozenPrice
+                // - ValuesCircularBuffer
+                //
+                // Also need to account for the weight of processing block accumulated values.
+                total_weight.saturating_accrue(
+                    T::DbWeight::get()
+                        .reads_writes(5, 4)
+                        .saturating_add(T::WeightInfo::process_block_aggregated_values()),
+                );
+
+                if IntermediateValueAggregator::<T>::get(currency_id).limit_block
+                    <= now.saturated_into()
+                {
+                    total_weight
+                        .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+                }
             }
 
             total_weight
         }
 
         fn on_finalize(now: BlockNumberFor<T>) {
-            // 1. Process the accumulated native currency values in the current block.
-            Self::process_block_aggregated_values();
+            for currency_id in T::TrackedCurrencies::get() {
+                // 1. Process the accumulated currency values in the current block.
+                Self::process_block_aggregated_values(currency_id);
+
+                // 2. Check if we need to push the average aggregated value to the storage.
+                if IntermediateValueAggregator::<T>::get(currency_id).limit_block
+                    <= now.saturated_into()
+                {
+                    Self::process_intermediate_aggregated_values(currency_id, now);
+                }
 
-            // 2. Check if we need to push the average aggregated value to the storage.
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                Self::process_intermediate_aggregated_values(now);
+                // 3. Check whether the feed has gone stale.
+                Self::check_stale_feed(currency_id, now);
             }
         }
 
@@ -365,48 +646,56 @@ pub mod pallet {
             assert!(T::MaxValuesPerBlock::get() > 0);
             assert!(T::CircularBufferLength::get() > 0);
             assert!(!T::AggregationDuration::get().is_zero());
+            assert!(!T::StaleFeedThreshold::get().is_zero());
+
+            let tracked_currencies = T::TrackedCurrencies::get();
+            assert!(tracked_currencies.contains(&T::NativeCurrencyId::get()));
+            for (idx, currency_id) in tracked_currencies.iter().enumerate() {
+                assert!(!tracked_currencies[idx + 1..].contains(currency_id));
+            }
         }
     }
 
     impl<T: Config> Pallet<T> {
-        /// Used to process the native currency values accumulated in the current block.
+        /// Used to process the currency values accumulated in the current block.
         ///
         /// Guarantees that the accumulated values are cleared after processing.
         /// In case of an error during processing, intermediate aggregated value is not updated.
-        pub(crate) fn process_block_aggregated_values() {
+        pub(crate) fn process_block_aggregated_values(currency_id: CurrencyId) {
             // 1. Take the accumulated block values, clearing the existing storage.
-            let accumulated_values = CurrentBlockValues::<T>::take();
+            let accumulated_values = CurrentBlockValues::<T>::take(currency_id);
 
             // 2. Attempt to process accumulated block values.
-            let processed_value = match T::ProcessBlockValues::process(
-                accumulated_values.as_slice(),
-            ) {
-                Ok(value) => value,
-                Err(message) => {
-                    log::trace!(
-                        target: LOG_TARGET,
-                        "Failed to process the accumulated native currency values in the current block. \
-                        Reason: {:?}",
-                        message
-                    );
-
-                    // Nothing to do if we have no valid value to store.
-                    return;
-                }
-            };
+            let processed_value =
+                match T::ProcessBlockValues::process(accumulated_values.as_slice()) {
+                    Ok(value) => value,
+                    Err(message) => {
+                        log::trace!(
+                            target: LOG_TARGET,
+                            "Failed to process the accumulated {:?} values in the current block. \
+                            Reason: {:?}",
+                            currency_id,
+                            message
+                        );
+
+                        // Nothing to do if we have no valid value to store.
+                        return;
+                    }
+                };
 
             // 3. Attempt to store the processed value.
             // This operation is practically infallible, but we check the results for the additional safety.
-            let intermediate_value = IntermediateValueAggregator::<T>::get();
+            let intermediate_value = IntermediateValueAggregator::<T>::get(currency_id);
             match intermediate_value.try_add(processed_value) {
                 Ok(new_aggregator) => {
-                    IntermediateValueAggregator::<T>::put(new_aggregator);
+                    IntermediateValueAggregator::<T>::insert(currency_id, new_aggregator);
                 }
                 Err(message) => {
                     log::error!(
                         target: LOG_TARGET,
-                        "Failed to add the processed native currency value to the intermediate storage. \
+                        "Failed to add the processed {:?} value to the intermediate storage. \
                         Reason: {:?}",
+                        currency_id,
                         message
                     );
                 }
@@ -414,50 +703,191 @@ pub mod pallet {
         }
 
         /// Used to process the intermediate aggregated values, and push them to the moving average storage.
-        pub(crate) fn process_intermediate_aggregated_values(now: BlockNumberFor<T>) {
-            // 1. Get the average value from the intermediate aggregator.
-            let average_value = IntermediateValueAggregator::<T>::get().average();
+        pub(crate) fn process_intermediate_aggregated_values(
+            currency_id: CurrencyId,
+            now: BlockNumberFor<T>,
+        ) {
+            // 1. Get the average value from the intermediate aggregator, and the submitters of the aggregated values.
+            let average_value = IntermediateValueAggregator::<T>::get(currency_id).average();
+            let submitters = AggregationSubmitters::<T>::take(currency_id).len() as u32;
 
             // 2. Reset the aggregator back to zero, and set the new limit block.
-            IntermediateValueAggregator::<T>::put(ValueAggregator::new(
-                now.saturating_add(T::AggregationDuration::get())
-                    .saturated_into(),
-            ));
+            IntermediateValueAggregator::<T>::insert(
+                currency_id,
+                ValueAggregator::new(
+                    now.saturating_add(T::AggregationDuration::get())
+                        .saturated_into(),
+                ),
+            );
 
             // 3. In case aggregated value equals 0, it means something has gone wrong since it's extremely unlikely
             // that price goes to absolute zero. The much more likely case is that there's a problem with the oracle data feed.
             if average_value.is_zero() {
                 log::error!(
                     target: LOG_TARGET,
-                    "The average aggregated price equals zero, which most likely means that oracle data feed is faulty. \
-                    Not pushing the 'zero' value to the moving average storage."
+                    "The average aggregated {:?} price equals zero, which most likely means that oracle data feed is faulty. \
+                    Not pushing the 'zero' value to the moving average storage.",
+                    currency_id
+                );
+                return;
+            }
+
+            // 4. Values provided by too few submitters can't be trusted.
+            if submitters < T::MinSubmitters::get() {
+                log::error!(
+                    target: LOG_TARGET,
+                    "Minimum submitter quorum for {:?} hasn't been reached, only {:?} submitter(s) provided values. \
+                    Not pushing the aggregated value to the moving average storage.",
+                    currency_id,
+                    submitters
                 );
+                Self::deposit_event(Event::QuorumNotReached {
+                    currency_id,
+                    submitters,
+                });
                 return;
             }
 
-            // 4. Push the 'valid' average aggregated value to the circular buffer.
-            ValuesCircularBuffer::<T>::mutate(|buffer| buffer.add(average_value));
+            // 5. Push the 'valid' average aggregated value to the circular buffer.
+            // Its duration covers all the blocks since the last valid value, including the skipped aggregation periods.
+            let now: BlockNumber = now.saturated_into();
+            let duration = LastValidValueBlock::<T>::get(currency_id)
+                .map(|last_valid_block| now.saturating_sub(last_valid_block))
+                .filter(|duration| !duration.is_zero())
+                .unwrap_or_else(|| T::AggregationDuration::get().saturated_into());
+
+            ValuesCircularBuffer::<T>::mutate(currency_id, |buffer| {
+                buffer.add_with_duration(average_value, duration)
+            });
+            LastValidValueBlock::<T>::insert(currency_id, now);
             Self::deposit_event(Event::AverageAggregatedValue {
+                currency_id,
                 value: average_value,
             });
+
+            // 6. Reset the circuit breaker, if it was tripped.
+            if FrozenPrice::<T>::take(currency_id).is_some() {
+                Self::deposit_event(Event::FeedRecovered { currency_id });
+            }
+        }
+
+        /// Used to trip the circuit breaker in case no valid value has been processed for too long.
+        pub(crate) fn check_stale_feed(currency_id: CurrencyId, now: BlockNumberFor<T>) {
+            let now: BlockNumber = now.saturated_into();
+
+            // There's no price to freeze until the first valid value is pushed into the circular buffer.
+            if ValuesCircularBuffer::<T>::get(currency_id).is_empty() {
+                return;
+            }
+
+            // Start tracking the feed in case it isn't being tracked yet.
+            let Some(last_valid_block) = LastValidValueBlock::<T>::get(currency_id) else {
+                LastValidValueBlock::<T>::insert(currency_id, now);
+                return;
+            };
+
+            let threshold: BlockNumber = T::StaleFeedThreshold::get().saturated_into();
+            if now.saturating_sub(last_valid_block) < threshold
+                || FrozenPrice::<T>::contains_key(currency_id)
+            {
+                return;
+            }
+
+            let frozen_price = Self::moving_average(currency_id);
+            log::error!(
+                target: LOG_TARGET,
+                "No valid {:?} value has been received since block {:?}, freezing the price at {:?}.",
+                currency_id,
+                last_valid_block,
+                frozen_price
+            );
+
+            FrozenPrice::<T>::insert(currency_id, frozen_price);
+            Self::deposit_event(Event::StaleFeedDetected {
+                currency_id,
+                frozen_price,
+            });
+        }
+
+        /// `true` if the value is within the allowed deviation bounds of the current moving average, `false` otherwise.
+        ///
+        /// Bounds aren't enforced if disabled, if the moving average isn't available, or if the circuit breaker is tripped.
+        pub(crate) fn is_within_deviation_bounds(
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+        ) -> bool {
+            let max_deviation = T::MaxDeviation::get();
+            if max_deviation.is_zero() || FrozenPrice::<T>::contains_key(currency_id) {
+                return true;
+            }
+
+            let average = Self::moving_average(currency_id);
+            if average.is_zero() {
+                return true;
+            }
+
+            let allowed_deviation = average.saturating_mul(max_deviation.into());
+            let deviation = if value > average {
+                value.saturating_sub(average)
+            } else {
+                average.saturating_sub(value)
+            };
+
+            deviation <= allowed_deviation
+        }
+
+        /// Current average price of the specified currency.
+        ///
+        /// Frozen price is returned in case the circuit breaker is tripped.
+        pub(crate) fn current_average_price(currency_id: CurrencyId) -> CurrencyAmount {
+            FrozenPrice::<T>::get(currency_id).unwrap_or_else(|| Self::moving_average(currency_id))
+        }
+
+        /// Moving average of the specified currency, calculated from its circular buffer.
+        pub(crate) fn moving_average(currency_id: CurrencyId) -> CurrencyAmount {
+            T::MovingAverage::calculate(&ValuesCircularBuffer::<T>::get(currency_id))
         }
     }
 
     // Make this pallet an 'observer' ('listener') of the new oracle data feed.
     impl<T: Config> OnNewData<T::AccountId, CurrencyId, CurrencyAmount> for Pallet<T> {
         fn on_new_data(who: &T::AccountId, key: &CurrencyId, value: &CurrencyAmount) {
-            // Ignore any currency that is not native currency.
-            if T::NativeCurrencyId::get() != *key {
+            // Ignore any currency that is not tracked.
+            if !T::TrackedCurrencies::get().contains(key) {
                 return;
             }
 
-            CurrentBlockValues::<T>::mutate(|v| match v.try_push(*value) {
-                Ok(()) => {}
+            // Reject the outliers.
+            if !Self::is_within_deviation_bounds(*key, *value) {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Rejecting {:?} value {:?} submitted by {:?} since it deviates too much from the moving average.",
+                    key,
+                    value,
+                    who
+                );
+                Self::deposit_event(Event::OutlierRejected {
+                    currency_id: *key,
+                    value: *value,
+                });
+                return;
+            }
+
+            CurrentBlockValues::<T>::mutate(key, |v| match v.try_push(*value) {
+                Ok(()) => {
+                    // Keep track of the distinct submitters, until the quorum is reached.
+                    AggregationSubmitters::<T>::mutate(key, |submitters| {
+                        if !submitters.contains(who) {
+                            let _ignorable = submitters.try_push(who.clone());
+                        }
+                    });
+                }
                 Err(_) => {
                     log::error!(
                     target: LOG_TARGET,
-                        "Failed to push native currency value into the ongoing block due to exceeded capacity. \
+                        "Failed to push {:?} value into the ongoing block due to exceeded capacity. \
                         Value was submitted by: {:?}",
+                        key,
                         who
                     );
                 }
@@ -467,10 +897,37 @@ pub mod pallet {
 
     // Make this pallet a `price provider` for the native currency.
     //
-    // For this particular implementation, a simple moving average is used to calculate the average price.
+    // The average price is calculated using the configured moving average method.
     impl<T: Config> PriceProvider for Pallet<T> {
         fn average_price() -> FixedU128 {
-            ValuesCircularBuffer::<T>::get().average()
+            Self::current_average_price(T::NativeCurrencyId::get())
+        }
+    }
+
+    // Make this pallet a `fallible price provider` for the native currency.
+    //
+    // Price isn't provided in case the circuit breaker is tripped, or if the moving average isn't available yet.
+    impl<T: Config> FalliblePriceProvider for Pallet<T> {
+        fn try_average_price() -> Option<CurrencyAmount> {
+            let native_currency_id = T::NativeCurrencyId::get();
+            if FrozenPrice::<T>::contains_key(native_currency_id) {
+                return None;
+            }
+
+            let average = Self::moving_average(native_currency_id);
+            (!average.is_zero()).then_some(average)
+        }
+    }
+
+    // Make this pallet a `price provider` for all the tracked currencies.
+    impl<T: Config> MultiCurrencyPriceProvider for Pallet<T> {
+        fn average_price_of(currency_id: CurrencyId) -> Option<CurrencyAmount> {
+            if !T::TrackedCurrencies::get().contains(&currency_id) {
+                return None;
+            }
+
+            let average = Self::current_average_price(currency_id);
+            (!average.is_zero()).then_some(average)
         }
     }
 }
@@ -486,16 +943,21 @@ impl<T: Config, P: Get<CurrencyAmount>> OnRuntimeUpgrade for PriceAggregatorInit
         // 1. Prepare price aggregator storage.
         let now = frame_system::Pallet::<T>::block_number();
         let limit_block = now.saturating_add(T::AggregationDuration::get().saturated_into());
-        IntermediateValueAggregator::<T>::put(ValueAggregator::new(limit_block.saturated_into()));
+        let native_currency_id = T::NativeCurrencyId::get();
+        IntermediateValueAggregator::<T>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block.saturated_into()),
+        );
 
         // 2. Put the initial value into the circular buffer so it's not empty.
-        use sp_arithmetic::FixedPointNumber;
         let init_price = P::get().max(FixedU128::from_rational(1, FixedU128::DIV.into()));
         log::info!(
             "Pushing initial price value into moving average buffer: {}",
             init_price
         );
-        ValuesCircularBuffer::<T>::mutate(|buffer| buffer.add(init_price));
+        ValuesCircularBuffer::<T>::mutate(native_currency_id, |buffer| {
+            buffer.add_with_duration(init_price, T::AggregationDuration::get().saturated_into())
+        });
 
         // 3. Set the initial storage version.
         STORAGE_VERSION.put::<Pallet<T>>();
@@ -0,0 +1,246 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Migration from storage version `1` to `2`.
+///
+/// Moves the single-currency storage values under the native currency key of the new per-currency storage maps.
+pub mod v2 {
+    use super::*;
+
+    pub(crate) mod v1 {
+        use super::*;
+
+        #[frame_support::storage_alias]
+        pub type CurrentBlockValues<T: Config> = StorageValue<
+            Pallet<T>,
+            BoundedVec<CurrencyAmount, <T as Config>::MaxValuesPerBlock>,
+            ValueQuery,
+        >;
+
+        #[frame_support::storage_alias]
+        pub type IntermediateValueAggregator<T: Config> =
+            StorageValue<Pallet<T>, ValueAggregator, ValueQuery>;
+
+        #[frame_support::storage_alias]
+        pub type ValuesCircularBuffer<T: Config> = StorageValue<
+            Pallet<T>,
+            v3::v2::CircularBuffer<<T as Config>::CircularBufferLength>,
+            ValueQuery,
+        >;
+    }
+
+    /// Moves the existing native currency storage values into the per-currency storage maps.
+    ///
+    /// Other tracked currencies get their aggregators aligned with the native one. Their circuit breaker stays inactive
+    /// until the first valid value is pushed, since there's no price to freeze until then.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let native_currency_id = T::NativeCurrencyId::get();
+
+            // Old values must be taken out first since their keys are prefixes of the new map keys.
+            let block_values = v1::CurrentBlockValues::<T>::take();
+            let aggregator = v1::IntermediateValueAggregator::<T>::take();
+            let circular_buffer = v1::ValuesCircularBuffer::<T>::take();
+
+            let limit_block = aggregator.limit_block;
+            CurrentBlockValues::<T>::insert(native_currency_id, block_values);
+            IntermediateValueAggregator::<T>::insert(native_currency_id, aggregator);
+            v3::v2::ValuesCircularBuffer::<T>::insert(native_currency_id, circular_buffer);
+            LastValidValueBlock::<T>::insert(
+                native_currency_id,
+                frame_system::Pallet::<T>::block_number().saturated_into::<BlockNumber>(),
+            );
+
+            let mut other_currencies = 0_u64;
+            for currency_id in T::TrackedCurrencies::get() {
+                if currency_id == native_currency_id
+                    || IntermediateValueAggregator::<T>::contains_key(currency_id)
+                {
+                    continue;
+                }
+
+                IntermediateValueAggregator::<T>::insert(
+                    currency_id,
+                    ValueAggregator::new(limit_block),
+                );
+                other_currencies.saturating_inc();
+            }
+
+            StorageVersion::new(2).put::<Pallet<T>>();
+            log::info!(target: LOG_TARGET, "Price aggregator pallet migrated to v2.");
+
+            T::DbWeight::get().reads_writes(
+                4_u64.saturating_add(other_currencies),
+                8_u64.saturating_add(other_currencies),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "Expected storage version 1."
+            );
+
+            Ok(v1::ValuesCircularBuffer::<T>::get().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "Expected storage version 2."
+            );
+
+            let old_buffer =
+                v3::v2::CircularBuffer::<T::CircularBufferLength>::decode(&mut state.as_slice())
+                    .map_err(|_| "Failed to decode the old circular buffer.")?;
+            ensure!(
+                v3::v2::ValuesCircularBuffer::<T>::get(T::NativeCurrencyId::get()) == old_buffer,
+                "Native currency circular buffer must be preserved."
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Migration from storage version `2` to `3`.
+///
+/// Adds the aggregation period durations to the existing circular buffers.
+pub mod v3 {
+    use super::*;
+
+    pub(crate) mod v2 {
+        use super::*;
+
+        /// Circular buffer without the aggregation period durations.
+        #[derive(
+            Encode,
+            Decode,
+            MaxEncodedLen,
+            RuntimeDebugNoBound,
+            PartialEqNoBound,
+            EqNoBound,
+            CloneNoBound,
+            TypeInfo,
+            DefaultNoBound,
+        )]
+        #[scale_info(skip_type_params(L))]
+        pub struct CircularBuffer<L: Get<u32>> {
+            pub(crate) buffer: BoundedVec<CurrencyAmount, L>,
+            #[codec(compact)]
+            pub(crate) head: u32,
+        }
+
+        #[frame_support::storage_alias]
+        pub type ValuesCircularBuffer<T: Config> = StorageMap<
+            Pallet<T>,
+            Twox64Concat,
+            CurrencyId,
+            CircularBuffer<<T as Config>::CircularBufferLength>,
+            ValueQuery,
+        >;
+    }
+
+    /// Translates the existing circular buffers into the new layout.
+    ///
+    /// Since all the existing values were aggregated over the same period, each of them is assigned the current aggregation duration.
+    pub struct MigrateToV3<T>(PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let aggregation_duration: BlockNumber = T::AggregationDuration::get().saturated_into();
+            let mut translated = 0_u64;
+            ValuesCircularBuffer::<T>::translate::<v2::CircularBuffer<T::CircularBufferLength>, _>(
+                |_, old_buffer| {
+                    translated.saturating_inc();
+
+                    let durations = BoundedVec::truncate_from(sp_std::vec![
+                        aggregation_duration;
+                        old_buffer.buffer.len()
+                    ]);
+                    Some(CircularBuffer {
+                        buffer: old_buffer.buffer,
+                        head: old_buffer.head,
+                        durations,
+                    })
+                },
+            );
+
+            StorageVersion::new(3).put::<Pallet<T>>();
+            log::info!(
+                target: LOG_TARGET,
+                "Price aggregator pallet migrated to v3, {} circular buffer(s) translated.",
+                translated
+            );
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "Expected storage version 2."
+            );
+
+            let old_buffers: Vec<_> = v2::ValuesCircularBuffer::<T>::iter().collect();
+            Ok(old_buffers.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "Expected storage version 3."
+            );
+
+            let old_buffers =
+                Vec::<(CurrencyId, v2::CircularBuffer<T::CircularBufferLength>)>::decode(
+                    &mut state.as_slice(),
+                )
+                .map_err(|_| "Failed to decode the old circular buffers.")?;
+
+            for (currency_id, old_buffer) in old_buffers {
+                let new_buffer = ValuesCircularBuffer::<T>::get(currency_id);
+                ensure!(
+                    new_buffer.buffer == old_buffer.buffer && new_buffer.head == old_buffer.head,
+                    "Circular buffer values must be preserved."
+                );
+                ensure!(
+                    new_buffer.durations.len() == new_buffer.buffer.len(),
+                    "Each value must have its duration."
+                );
+            }
+
+            Ok(())
+        }
+    }
+}
@@ -17,7 +17,8 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor, IntermediateValueAggregator,
+    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor,
+    IntermediateValueAggregator, SimpleMovingAverage,
 };
 
 use frame_support::{
@@ -29,7 +30,7 @@ use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, Perbill,
 };
 
 use astar_primitives::{oracle::CurrencyId, Balance, BlockNumber};
@@ -88,7 +89,12 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
+    pub TrackedCurrencies: Vec<CurrencyId> = vec![CurrencyId::ASTR, CurrencyId::DOT];
     pub const AggregationDuration: BlockNumberFor<Test> = 16;
+    pub const StaleFeedThreshold: BlockNumberFor<Test> = 64;
+    // Outlier rejection is disabled by default, tests which need it enable it explicitly.
+    pub static MaxDeviation: Perbill = Perbill::zero();
+    pub static MinSubmitters: u32 = 1;
 }
 
 impl pallet_price_aggregator::Config for Test {
@@ -97,8 +103,13 @@ impl pallet_price_aggregator::Config for Test {
     type MaxValuesPerBlock = ConstU32<4>;
     type ProcessBlockValues = AverageBlockValue;
     type NativeCurrencyId = NativeCurrencyId;
+    type TrackedCurrencies = TrackedCurrencies;
     type CircularBufferLength = ConstU32<7>;
+    type MovingAverage = SimpleMovingAverage;
     type AggregationDuration = AggregationDuration;
+    type MaxDeviation = MaxDeviation;
+    type MinSubmitters = MinSubmitters;
+    type StaleFeedThreshold = StaleFeedThreshold;
     type WeightInfo = ();
 }
 
@@ -119,11 +130,13 @@ impl ExtBuilder {
 
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| {
-            // 1. Set the initial limit block for the intermediate value aggregator
-            IntermediateValueAggregator::<Test>::mutate(|v| {
-                v.limit_block =
-                    <Test as pallet_price_aggregator::Config>::AggregationDuration::get() + 1
-            });
+            // 1. Set the initial limit block for the intermediate value aggregators
+            for currency_id in TrackedCurrencies::get() {
+                IntermediateValueAggregator::<Test>::mutate(currency_id, |v| {
+                    v.limit_block =
+                        <Test as pallet_price_aggregator::Config>::AggregationDuration::get() + 1
+                });
+            }
 
             // 2. Init block setting
             let init_block_number = 1;
@@ -18,21 +18,24 @@
 
 use crate::mock::*;
 use crate::{
-    pallet::Config, AverageBlockValue, CircularBuffer, CurrentBlockValues, Event,
-    IntermediateValueAggregator, MedianBlockValue, ProcessBlockValues, ValueAggregator,
-    ValuesCircularBuffer,
+    migration, pallet::Config, AggregationSubmitters, AverageBlockValue, CalculateMovingAverage,
+    CircularBuffer, CurrentBlockValues, Event, ExponentialMovingAverage, FrozenPrice,
+    IntermediateValueAggregator, LastValidValueBlock, MedianBlockValue, ProcessBlockValues,
+    SimpleMovingAverage, TimeWeightedMovingAverage, ValueAggregator, ValuesCircularBuffer,
 };
 
-use astar_primitives::oracle::{CurrencyAmount, CurrencyId};
+use astar_primitives::oracle::{
+    CurrencyAmount, CurrencyId, FalliblePriceProvider, MultiCurrencyPriceProvider, PriceProvider,
+};
 
 use orml_traits::OnNewData;
 
 use frame_support::{
     assert_storage_noop,
-    traits::{Get, Hooks},
+    traits::{Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
     BoundedVec,
 };
-use sp_runtime::{traits::Zero, Saturating};
+use sp_runtime::{traits::Zero, Perbill, Saturating};
 
 pub use num_traits::Bounded;
 
@@ -217,6 +220,7 @@ fn circular_buffer_basic_checks() {
     let mut circular_buffer = CircularBuffer::<BufferSize>::default();
     assert!(circular_buffer.buffer.is_empty());
     assert!(circular_buffer.head.is_zero());
+    assert!(circular_buffer.latest().is_none());
 
     // 2. Add a value, verify state is as expected
     let amount_1 = CurrencyAmount::from_rational(19, 10);
@@ -224,7 +228,9 @@ fn circular_buffer_basic_checks() {
     circular_buffer.add(amount_1);
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert_eq!(circular_buffer.head, 1);
+    assert_eq!(circular_buffer.durations.clone().into_inner(), vec![1]);
     assert_eq!(circular_buffer.average(), amount_1);
+    assert_eq!(circular_buffer.latest(), Some(amount_1));
 
     // 3. Add another value, verify state is as expected
     let amount_2 = CurrencyAmount::from_rational(7, 10);
@@ -236,6 +242,7 @@ fn circular_buffer_basic_checks() {
         circular_buffer.average(),
         CurrencyAmount::from_rational(13, 10)
     );
+    assert_eq!(circular_buffer.latest(), Some(amount_2));
 
     // 4. Fill up the buffer, verify state is as expected
     let amount_3 = CurrencyAmount::from_rational(27, 10);
@@ -245,6 +252,7 @@ fn circular_buffer_basic_checks() {
     }
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert!(circular_buffer.head.is_zero());
+    assert_eq!(circular_buffer.latest(), Some(amount_3));
 
     // 5. Add another value, verify 0-th element is replaced
     let amount_4 = CurrencyAmount::from_rational(9, 10);
@@ -252,6 +260,7 @@ fn circular_buffer_basic_checks() {
     expected_buffer[0] = amount_4;
     assert_eq!(circular_buffer.buffer.clone().into_inner(), expected_buffer);
     assert_eq!(circular_buffer.head, 1);
+    assert_eq!(circular_buffer.latest(), Some(amount_4));
 
     // 6. Repeat the cycle few more times, expect it works as expected
     for x in 0..BUFFER_SIZE * 5 {
@@ -265,6 +274,7 @@ fn circular_buffer_basic_checks() {
         circular_buffer.add(amount);
         assert_eq!(circular_buffer.buffer[init_head as usize], amount);
         assert_eq!(circular_buffer.head, (init_head + 1) % BUFFER_SIZE);
+        assert_eq!(circular_buffer.latest(), Some(amount));
     }
 }
 
@@ -284,6 +294,7 @@ fn circular_buffer_inconsistency_safeguard_checks() {
     let mut inconsistent_buffer = CircularBuffer::<BufferSize> {
         buffer: Default::default(),
         head: 1,
+        durations: Default::default(),
     };
 
     inconsistent_buffer.add(amount);
@@ -296,6 +307,7 @@ fn circular_buffer_inconsistency_safeguard_checks() {
     let mut inconsistent_buffer = CircularBuffer::<BufferSize> {
         buffer: init_buffer.clone(),
         head: BUFFER_SIZE,
+        durations: Default::default(),
     };
 
     inconsistent_buffer.add(amount);
@@ -303,44 +315,223 @@ fn circular_buffer_inconsistency_safeguard_checks() {
     assert_eq!(inconsistent_buffer.head, BUFFER_SIZE);
 }
 
+#[test]
+fn circular_buffer_moving_average_checks() {
+    // 0. Buffer size prep
+    const BUFFER_SIZE: u32 = 4;
+    struct BufferSize;
+    impl Get<u32> for BufferSize {
+        fn get() -> u32 {
+            BUFFER_SIZE
+        }
+    }
+    struct Smoothing;
+    impl Get<Perbill> for Smoothing {
+        fn get() -> Perbill {
+            Perbill::from_percent(50)
+        }
+    }
+    type Ema = ExponentialMovingAverage<Smoothing>;
+
+    // 1. Empty buffer
+    let mut circular_buffer = CircularBuffer::<BufferSize>::default();
+    assert!(SimpleMovingAverage::calculate(&circular_buffer).is_zero());
+    assert!(TimeWeightedMovingAverage::calculate(&circular_buffer).is_zero());
+    assert!(Ema::calculate(&circular_buffer).is_zero());
+
+    // 2. Single value, all methods return it
+    let amount_1 = CurrencyAmount::from_rational(2, 1);
+    circular_buffer.add_with_duration(amount_1, 3);
+    assert_eq!(SimpleMovingAverage::calculate(&circular_buffer), amount_1);
+    assert_eq!(
+        TimeWeightedMovingAverage::calculate(&circular_buffer),
+        amount_1
+    );
+    assert_eq!(Ema::calculate(&circular_buffer), amount_1);
+
+    // 3. Add value with a different duration, verify each method
+    let amount_2 = CurrencyAmount::from_rational(6, 1);
+    circular_buffer.add_with_duration(amount_2, 1);
+    assert_eq!(circular_buffer.durations.clone().into_inner(), vec![3, 1]);
+    assert_eq!(
+        SimpleMovingAverage::calculate(&circular_buffer),
+        CurrencyAmount::from_rational(4, 1)
+    );
+    // (2 * 3 + 6 * 1) / 4 = 3
+    assert_eq!(
+        TimeWeightedMovingAverage::calculate(&circular_buffer),
+        CurrencyAmount::from_rational(3, 1)
+    );
+    // 0.5 * 6 + 0.5 * 2 = 4
+    assert_eq!(
+        Ema::calculate(&circular_buffer),
+        CurrencyAmount::from_rational(4, 1)
+    );
+
+    // 4. Fill up the buffer & overwrite the oldest value, verify EMA follows the chronological order
+    let amount_3 = CurrencyAmount::from_rational(4, 1);
+    for _ in 0..BUFFER_SIZE - 1 {
+        circular_buffer.add_with_duration(amount_3, 3);
+    }
+    assert_eq!(circular_buffer.head, 1);
+    assert_eq!(
+        circular_buffer.buffer.clone().into_inner(),
+        vec![amount_3, amount_2, amount_3, amount_3]
+    );
+    assert_eq!(
+        circular_buffer.durations.clone().into_inner(),
+        vec![3, 1, 3, 3]
+    );
+
+    // (6 * 1 + 4 * 9) / 10 = 4.2
+    assert_eq!(
+        TimeWeightedMovingAverage::calculate(&circular_buffer),
+        CurrencyAmount::from_rational(42, 10)
+    );
+    // Oldest value is `6`, followed by three `4` values: 6 -> 5 -> 4.5 -> 4.25
+    assert_eq!(
+        Ema::calculate(&circular_buffer),
+        CurrencyAmount::from_rational(425, 100)
+    );
+}
+
+#[test]
+fn moving_average_methods_under_price_spike() {
+    // 0. Buffer size prep
+    const BUFFER_SIZE: u32 = 7;
+    struct BufferSize;
+    impl Get<u32> for BufferSize {
+        fn get() -> u32 {
+            BUFFER_SIZE
+        }
+    }
+    struct FastSmoothing;
+    impl Get<Perbill> for FastSmoothing {
+        fn get() -> Perbill {
+            Perbill::from_percent(50)
+        }
+    }
+    struct SlowSmoothing;
+    impl Get<Perbill> for SlowSmoothing {
+        fn get() -> Perbill {
+            Perbill::from_percent(10)
+        }
+    }
+    type FastEma = ExponentialMovingAverage<FastSmoothing>;
+    type SlowEma = ExponentialMovingAverage<SlowSmoothing>;
+
+    let duration = 16;
+    let stable_amount = CurrencyAmount::from_rational(1, 1);
+    let spike_amount = CurrencyAmount::from_rational(5, 1);
+
+    // 1. Stable price, all methods agree
+    let mut circular_buffer = CircularBuffer::<BufferSize>::default();
+    for _ in 0..BUFFER_SIZE - 1 {
+        circular_buffer.add_with_duration(stable_amount, duration);
+    }
+    assert_eq!(
+        SimpleMovingAverage::calculate(&circular_buffer),
+        stable_amount
+    );
+    assert_eq!(
+        TimeWeightedMovingAverage::calculate(&circular_buffer),
+        stable_amount
+    );
+    assert_eq!(FastEma::calculate(&circular_buffer), stable_amount);
+    assert_eq!(SlowEma::calculate(&circular_buffer), stable_amount);
+
+    // 2. Price spike, aggregated over the full duration
+    let mut spiked_buffer = circular_buffer.clone();
+    spiked_buffer.add_with_duration(spike_amount, duration);
+
+    let sma = SimpleMovingAverage::calculate(&spiked_buffer);
+    assert!(sma > stable_amount);
+    // With equal durations, time-weighted average is the same as the simple one (up to rounding)
+    let twap = TimeWeightedMovingAverage::calculate(&spiked_buffer);
+    assert!(twap.max(sma).saturating_sub(twap.min(sma)) <= CurrencyAmount::from_inner(1));
+    // Fast EMA reacts more to the spike than SMA, slow EMA reacts less
+    // 0.5 * 5 + 0.5 * 1 = 3
+    assert_eq!(
+        FastEma::calculate(&spiked_buffer),
+        CurrencyAmount::from_rational(3, 1)
+    );
+    assert!(FastEma::calculate(&spiked_buffer) > sma);
+    // 0.1 * 5 + 0.9 * 1 = 1.4
+    assert_eq!(
+        SlowEma::calculate(&spiked_buffer),
+        CurrencyAmount::from_rational(14, 10)
+    );
+    assert!(SlowEma::calculate(&spiked_buffer) < sma);
+
+    // 3. Price spike aggregated over a short period (e.g. due to the missing values) barely affects the time-weighted average
+    let mut short_spiked_buffer = circular_buffer.clone();
+    short_spiked_buffer.add_with_duration(spike_amount, 1);
+    assert_eq!(SimpleMovingAverage::calculate(&short_spiked_buffer), sma);
+    let short_twap = TimeWeightedMovingAverage::calculate(&short_spiked_buffer);
+    assert!(short_twap > stable_amount && short_twap < sma);
+
+    // 4. Price returns back to normal, EMA decays while SMA remains the same until spike leaves the buffer
+    spiked_buffer.add_with_duration(stable_amount, duration);
+    assert_eq!(SimpleMovingAverage::calculate(&spiked_buffer), sma);
+    assert_eq!(
+        FastEma::calculate(&spiked_buffer),
+        CurrencyAmount::from_rational(2, 1)
+    );
+
+    // 5. Once the spike leaves the buffer, all methods agree again
+    for _ in 0..BUFFER_SIZE - 1 {
+        spiked_buffer.add_with_duration(stable_amount, duration);
+    }
+    assert_eq!(
+        SimpleMovingAverage::calculate(&spiked_buffer),
+        stable_amount
+    );
+    assert_eq!(
+        TimeWeightedMovingAverage::calculate(&spiked_buffer),
+        stable_amount
+    );
+    assert_eq!(FastEma::calculate(&spiked_buffer), stable_amount);
+    assert_eq!(SlowEma::calculate(&spiked_buffer), stable_amount);
+}
+
 #[test]
 fn on_new_data_works_as_expected() {
     ExtBuilder::build().execute_with(|| {
         // 0. Initial sanity check
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(native_currency_id).is_empty(),
             "Init state must be empty."
         );
 
         // 1. Inform pallet of a new piece of data, verify state is as expected
         let dummy_account_1 = 123;
-        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
         let amount_1 = CurrencyAmount::from_rational(15, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_1);
         assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
             vec![amount_1],
         );
 
-        // 2. Try to add non-native currency, verify no state change
-        let non_native_currency_id = CurrencyId::SDN;
+        // 2. Try to add untracked currency, verify no state change
+        let untracked_currency_id = CurrencyId::SDN;
         assert!(
-            non_native_currency_id != native_currency_id,
+            !TrackedCurrencies::get().contains(&untracked_currency_id),
             "Sanity check."
         );
 
-        let non_native_amount = CurrencyAmount::from_rational(7, 10);
+        let untracked_amount = CurrencyAmount::from_rational(7, 10);
         assert_storage_noop!(PriceAggregator::on_new_data(
             &dummy_account_1,
-            &non_native_currency_id,
-            &non_native_amount
+            &untracked_currency_id,
+            &untracked_amount
         ));
 
         // 3. Add additional amount, verify state is as expected
         let amount_2 = CurrencyAmount::from_rational(3, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_2);
         assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
             vec![amount_1, amount_2],
         );
 
@@ -355,7 +546,10 @@ fn on_new_data_works_as_expected() {
         }
 
         assert_eq!(result.len(), limit as usize, "Sanity check.");
-        assert_eq!(CurrentBlockValues::<Test>::get().into_inner(), result);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            result
+        );
 
         // 5. Try to add one more value, overflowing the buffer, verify no state change
         assert_storage_noop!(PriceAggregator::on_new_data(
@@ -382,17 +576,19 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_finalize(block_number_1);
 
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(native_currency_id).is_empty(),
             "Buffer must be empty after the finalization."
         );
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(intermediate_value_aggregator.count, 1);
 
         let average_amount_1 = CurrencyAmount::from_rational(15, 10);
         assert_eq!(intermediate_value_aggregator.total, average_amount_1);
 
         // 3. Move to the next block, but for this one no new data is added
-        let intermediate_value_snapshot = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_snapshot =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
 
         let block_number_2 = block_number_1 + 1;
         System::set_block_number(block_number_2);
@@ -401,7 +597,7 @@ fn on_finalize_updates_aggregated_data() {
         // No new data is added, everything must still work without breaking
         PriceAggregator::on_finalize(block_number_2);
         assert_eq!(
-            IntermediateValueAggregator::<Test>::get(),
+            IntermediateValueAggregator::<Test>::get(native_currency_id),
             intermediate_value_snapshot,
             "No new data was added, so the state must remain the same."
         );
@@ -416,7 +612,8 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_3);
         PriceAggregator::on_finalize(block_number_3);
 
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(
             intermediate_value_aggregator.count, 2,
             "Count must be 2 since we added only 2 new values."
@@ -438,7 +635,7 @@ fn on_finalize_updates_circular_buffer() {
         // 1. Advance just until limit block is reached, checking appropriate storage items along the way
         let mut total = CurrencyAmount::zero();
         let current_block = System::block_number();
-        let limit_block = IntermediateValueAggregator::<Test>::get().limit_block;
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
 
         for block in current_block..limit_block {
             // Add new data
@@ -449,12 +646,14 @@ fn on_finalize_updates_circular_buffer() {
             // Finalize the block
             PriceAggregator::on_finalize(block);
             assert_eq!(
-                IntermediateValueAggregator::<Test>::get().total,
+                IntermediateValueAggregator::<Test>::get(native_currency_id).total,
                 total,
                 "Check total is updated as expected."
             );
             assert!(
-                ValuesCircularBuffer::<Test>::get().buffer.is_empty(),
+                ValuesCircularBuffer::<Test>::get(native_currency_id)
+                    .buffer
+                    .is_empty(),
                 "Circular buffer is expected to remain empty until limit block is reached."
             );
 
@@ -472,7 +671,8 @@ fn on_finalize_updates_circular_buffer() {
         PriceAggregator::on_finalize(current_block);
 
         // Check that value aggregator is reset & new block limit is correct
-        let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+        let reset_intermediate_aggregator =
+            IntermediateValueAggregator::<Test>::get(native_currency_id);
         assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
         assert_eq!(reset_intermediate_aggregator.count, 0);
         assert_eq!(
@@ -481,17 +681,22 @@ fn on_finalize_updates_circular_buffer() {
         );
 
         // Check that circular buffer was updated as expected
-        let circular_buffer = ValuesCircularBuffer::<Test>::get();
+        let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
         let expected_average = total * CurrencyAmount::from_rational(1, limit_block as u128 - 1);
         assert_eq!(
             circular_buffer.buffer.clone().into_inner(),
             vec![expected_average]
         );
         assert_eq!(circular_buffer.head, 1);
+        assert_eq!(
+            circular_buffer.durations.clone().into_inner(),
+            vec![<Test as Config>::AggregationDuration::get()]
+        );
 
         // Verify deposited event
         System::assert_last_event(RuntimeEvent::PriceAggregator(
             Event::AverageAggregatedValue {
+                currency_id: native_currency_id,
                 value: expected_average,
             },
         ));
@@ -504,6 +709,7 @@ fn circular_buffer_really_is_circular() {
         // 0. Init data
         let aggregation_duration = <Test as Config>::AggregationDuration::get();
         let circular_buffer_length: u32 = <Test as Config>::CircularBufferLength::get();
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
 
         fn advance_to_block(block: u32) {
             let dummy_account = 456;
@@ -526,16 +732,18 @@ fn circular_buffer_really_is_circular() {
         // 1. Fill up the circular buffer
         for x in 0..circular_buffer_length {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
             assert_eq!(circular_buffer.buffer.len(), x as usize + 1);
             assert_eq!(circular_buffer.head, (x + 1) % circular_buffer_length);
 
             // Check that intermediate aggregator is reset & limit block is updated
-            let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let reset_intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
             assert_eq!(reset_intermediate_aggregator.count, 0);
             assert_eq!(
@@ -547,11 +755,12 @@ fn circular_buffer_really_is_circular() {
         // 2. Continue adding the data, verify circular buffer is updated as expected
         for x in 0..circular_buffer_length * 3 {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(native_currency_id);
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
             assert_eq!(
                 circular_buffer.buffer.len(),
                 circular_buffer_length as usize
@@ -560,3 +769,566 @@ fn circular_buffer_really_is_circular() {
         }
     })
 }
+
+#[test]
+fn tracked_currencies_are_aggregated_independently() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 789;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let other_currency_id = CurrencyId::DOT;
+        assert!(
+            TrackedCurrencies::get().contains(&other_currency_id),
+            "Sanity check."
+        );
+
+        // 1. Submit different values for the tracked currencies
+        let native_amount = CurrencyAmount::from_rational(5, 100);
+        let other_amount = CurrencyAmount::from_rational(6_000, 1_000);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &native_amount);
+        PriceAggregator::on_new_data(&dummy_account, &other_currency_id, &other_amount);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![native_amount]
+        );
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(other_currency_id).into_inner(),
+            vec![other_amount]
+        );
+
+        // 2. Finalize the block, verify each aggregator was updated with its own values
+        PriceAggregator::on_finalize(System::block_number());
+        assert_eq!(
+            IntermediateValueAggregator::<Test>::get(native_currency_id).total,
+            native_amount
+        );
+        assert_eq!(
+            IntermediateValueAggregator::<Test>::get(other_currency_id).total,
+            other_amount
+        );
+
+        // 3. Advance until the limit block, verify each circular buffer was updated with its own average
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        for block in System::block_number() + 1..=limit_block {
+            System::set_block_number(block);
+            PriceAggregator::on_initialize(block);
+            PriceAggregator::on_finalize(block);
+        }
+
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(native_currency_id)
+                .buffer
+                .into_inner(),
+            vec![native_amount]
+        );
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(other_currency_id)
+                .buffer
+                .into_inner(),
+            vec![other_amount]
+        );
+        System::assert_has_event(RuntimeEvent::PriceAggregator(
+            Event::AverageAggregatedValue {
+                currency_id: other_currency_id,
+                value: other_amount,
+            },
+        ));
+    })
+}
+
+#[test]
+fn multi_currency_price_provider_works() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let other_currency_id = CurrencyId::DOT;
+        let untracked_currency_id = CurrencyId::SDN;
+
+        // 1. No prices are available initially
+        assert!(PriceAggregator::average_price_of(native_currency_id).is_none());
+        assert!(PriceAggregator::average_price_of(other_currency_id).is_none());
+
+        // 2. Fill up the circular buffers, verify the averages are provided
+        let native_amount = CurrencyAmount::from_rational(7, 100);
+        let other_amount = CurrencyAmount::from_rational(51, 10);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |b| b.add(native_amount));
+        ValuesCircularBuffer::<Test>::mutate(other_currency_id, |b| b.add(other_amount));
+        ValuesCircularBuffer::<Test>::mutate(untracked_currency_id, |b| b.add(other_amount));
+
+        assert_eq!(
+            PriceAggregator::average_price_of(native_currency_id),
+            Some(native_amount)
+        );
+        assert_eq!(PriceAggregator::average_price(), native_amount);
+        assert_eq!(
+            PriceAggregator::average_price_of(other_currency_id),
+            Some(other_amount)
+        );
+
+        // 3. Untracked currency price is never provided
+        assert!(PriceAggregator::average_price_of(untracked_currency_id).is_none());
+    })
+}
+
+#[test]
+fn fallible_price_provider_works() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+
+        // 1. No price is available while the buffer is empty
+        assert!(PriceAggregator::try_average_price().is_none());
+
+        // 2. Price is available once the buffer has some values
+        let amount = CurrencyAmount::from_rational(7, 10);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |b| b.add(amount));
+        assert_eq!(PriceAggregator::try_average_price(), Some(amount));
+
+        // 3. No price is available while the circuit breaker is tripped
+        FrozenPrice::<Test>::insert(native_currency_id, amount);
+        assert!(PriceAggregator::try_average_price().is_none());
+        assert_eq!(PriceAggregator::average_price(), amount);
+    })
+}
+
+#[test]
+fn migration_to_v2_works() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let other_currency_id = CurrencyId::DOT;
+
+        // 1. Prepare the old storage layout
+        StorageVersion::new(1).put::<PriceAggregator>();
+        IntermediateValueAggregator::<Test>::remove(native_currency_id);
+        IntermediateValueAggregator::<Test>::remove(other_currency_id);
+        let block_values = BoundedVec::try_from(vec![CurrencyAmount::from_rational(3, 10)])
+            .expect("Must work since size is within the bound.");
+        let aggregator = ValueAggregator {
+            total: CurrencyAmount::from_rational(17, 10),
+            count: 2,
+            limit_block: 21,
+        };
+        let circular_buffer =
+            migration::v3::v2::CircularBuffer::<<Test as Config>::CircularBufferLength> {
+                buffer: BoundedVec::try_from(vec![CurrencyAmount::from_rational(11, 10)])
+                    .expect("Must work since size is within the bound."),
+                head: 1,
+            };
+
+        migration::v2::v1::CurrentBlockValues::<Test>::put(block_values.clone());
+        migration::v2::v1::IntermediateValueAggregator::<Test>::put(aggregator);
+        migration::v2::v1::ValuesCircularBuffer::<Test>::put(circular_buffer.clone());
+
+        // 2. Run the migration, verify the values were moved under the native currency
+        migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(PriceAggregator::on_chain_storage_version(), 2);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id),
+            block_values
+        );
+        assert_eq!(
+            IntermediateValueAggregator::<Test>::get(native_currency_id),
+            aggregator
+        );
+        assert_eq!(
+            migration::v3::v2::ValuesCircularBuffer::<Test>::get(native_currency_id),
+            circular_buffer
+        );
+        assert!(!migration::v2::v1::ValuesCircularBuffer::<Test>::exists());
+        assert_eq!(
+            LastValidValueBlock::<Test>::get(native_currency_id),
+            Some(System::block_number())
+        );
+
+        // Other tracked currencies are aligned with the native one, but aren't tracked by the circuit breaker yet
+        assert_eq!(
+            IntermediateValueAggregator::<Test>::get(other_currency_id),
+            ValueAggregator::new(aggregator.limit_block)
+        );
+        assert!(!LastValidValueBlock::<Test>::contains_key(
+            other_currency_id
+        ));
+
+        // 3. Running the migration again does nothing
+        assert_storage_noop!(migration::v2::MigrateToV2::<Test>::on_runtime_upgrade());
+    })
+}
+
+#[test]
+fn migration_to_v3_works() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let other_currency_id = CurrencyId::DOT;
+        let aggregation_duration = <Test as Config>::AggregationDuration::get();
+
+        // 1. Prepare the old storage layout
+        StorageVersion::new(2).put::<PriceAggregator>();
+        ValuesCircularBuffer::<Test>::remove(native_currency_id);
+
+        let native_amounts = vec![
+            CurrencyAmount::from_rational(11, 10),
+            CurrencyAmount::from_rational(13, 10),
+        ];
+        let native_buffer =
+            migration::v3::v2::CircularBuffer::<<Test as Config>::CircularBufferLength> {
+                buffer: BoundedVec::try_from(native_amounts.clone())
+                    .expect("Must work since size is within the bound."),
+                head: 2,
+            };
+        let other_buffer =
+            migration::v3::v2::CircularBuffer::<<Test as Config>::CircularBufferLength> {
+                buffer: BoundedVec::try_from(vec![CurrencyAmount::from_rational(7, 1)])
+                    .expect("Must work since size is within the bound."),
+                head: 1,
+            };
+        migration::v3::v2::ValuesCircularBuffer::<Test>::insert(native_currency_id, native_buffer);
+        migration::v3::v2::ValuesCircularBuffer::<Test>::insert(other_currency_id, other_buffer);
+
+        // 2. Run the migration, verify each value got the aggregation duration assigned
+        migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(PriceAggregator::on_chain_storage_version(), 3);
+
+        let native_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
+        assert_eq!(native_buffer.buffer.clone().into_inner(), native_amounts);
+        assert_eq!(native_buffer.head, 2);
+        assert_eq!(
+            native_buffer.durations.into_inner(),
+            vec![aggregation_duration; 2]
+        );
+
+        let other_buffer = ValuesCircularBuffer::<Test>::get(other_currency_id);
+        assert_eq!(
+            other_buffer.buffer.into_inner(),
+            vec![CurrencyAmount::from_rational(7, 1)]
+        );
+        assert_eq!(other_buffer.head, 1);
+        assert_eq!(
+            other_buffer.durations.into_inner(),
+            vec![aggregation_duration]
+        );
+
+        // 3. Running the migration again does nothing
+        assert_storage_noop!(migration::v3::MigrateToV3::<Test>::on_runtime_upgrade());
+    })
+}
+
+/// Finalizes all the blocks up to, but excluding, the specified block.
+fn run_to_block(block: u32) {
+    for current_block in System::block_number()..block {
+        PriceAggregator::on_finalize(current_block);
+
+        let new_block = current_block + 1;
+        System::set_block_number(new_block);
+        PriceAggregator::on_initialize(new_block);
+    }
+}
+
+#[test]
+fn outlier_values_are_rejected() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 123;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        MaxDeviation::set(Perbill::from_percent(20));
+
+        // 1. Moving average isn't available yet, so any value is accepted
+        let outlier_amount = CurrencyAmount::from_rational(100, 1);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &outlier_amount);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![outlier_amount]
+        );
+        CurrentBlockValues::<Test>::remove(native_currency_id);
+
+        // 2. Prepare the moving average
+        let average = CurrencyAmount::from_rational(1, 1);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |b| b.add(average));
+
+        // 3. Values within the bounds are accepted
+        let amount_1 = CurrencyAmount::from_rational(11, 10);
+        let amount_2 = CurrencyAmount::from_rational(8, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_1);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_2);
+
+        // 4. Values outside of the bounds are rejected
+        for amount in [
+            CurrencyAmount::from_rational(13, 10),
+            CurrencyAmount::from_rational(7, 10),
+        ] {
+            PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+            System::assert_last_event(RuntimeEvent::PriceAggregator(Event::OutlierRejected {
+                currency_id: native_currency_id,
+                value: amount,
+            }));
+        }
+
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![amount_1, amount_2]
+        );
+
+        // 5. Bounds are tracked per currency, so the other currency isn't affected
+        let other_currency_id = CurrencyId::DOT;
+        PriceAggregator::on_new_data(&dummy_account, &other_currency_id, &outlier_amount);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(other_currency_id).into_inner(),
+            vec![outlier_amount]
+        );
+    })
+}
+
+#[test]
+fn minimum_submitter_quorum_is_enforced() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        MinSubmitters::set(2);
+
+        // 1. Single submitter provides multiple values during the aggregation period
+        let dummy_account_1 = 123;
+        let amount = CurrencyAmount::from_rational(15, 10);
+        PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount);
+        PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount);
+        assert_eq!(
+            AggregationSubmitters::<Test>::get(native_currency_id).into_inner(),
+            vec![dummy_account_1]
+        );
+
+        // 2. Quorum isn't reached at the end of the aggregation period, no value is pushed
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(limit_block + 1);
+
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::QuorumNotReached {
+            currency_id: native_currency_id,
+            submitters: 1,
+        }));
+        assert!(ValuesCircularBuffer::<Test>::get(native_currency_id)
+            .buffer
+            .is_empty());
+        assert!(AggregationSubmitters::<Test>::get(native_currency_id).is_empty());
+
+        // 3. Two distinct submitters provide values, quorum is reached and value is pushed
+        let dummy_account_2 = 456;
+        PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount);
+        PriceAggregator::on_new_data(&dummy_account_2, &native_currency_id, &amount);
+
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(limit_block + 1);
+
+        System::assert_has_event(RuntimeEvent::PriceAggregator(
+            Event::AverageAggregatedValue {
+                currency_id: native_currency_id,
+                value: amount,
+            },
+        ));
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(native_currency_id)
+                .buffer
+                .into_inner(),
+            vec![amount]
+        );
+    })
+}
+
+#[test]
+fn skipped_aggregation_periods_extend_value_duration() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let aggregation_duration = <Test as Config>::AggregationDuration::get();
+        MinSubmitters::set(2);
+
+        let dummy_account_1 = 123;
+        let dummy_account_2 = 456;
+
+        // 1. Valid value is pushed at the end of the first aggregation period
+        let amount_1 = CurrencyAmount::from_rational(1, 1);
+        PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_1);
+        PriceAggregator::on_new_data(&dummy_account_2, &native_currency_id, &amount_1);
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(limit_block + 1);
+
+        // 2. Next period is skipped since there are no values, the one after since the quorum isn't reached
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(limit_block + 1);
+
+        PriceAggregator::on_new_data(
+            &dummy_account_1,
+            &native_currency_id,
+            &CurrencyAmount::from_rational(5, 1),
+        );
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(limit_block + 1);
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::QuorumNotReached {
+            currency_id: native_currency_id,
+            submitters: 1,
+        }));
+
+        // 3. Next valid value covers all the blocks since the previous valid value
+        let amount_2 = CurrencyAmount::from_rational(3, 1);
+        PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_2);
+        PriceAggregator::on_new_data(&dummy_account_2, &native_currency_id, &amount_2);
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(limit_block + 1);
+
+        let circular_buffer = ValuesCircularBuffer::<Test>::get(native_currency_id);
+        assert_eq!(
+            circular_buffer.buffer.clone().into_inner(),
+            vec![amount_1, amount_2]
+        );
+        assert_eq!(
+            circular_buffer.durations.clone().into_inner(),
+            vec![aggregation_duration, 3 * aggregation_duration]
+        );
+
+        // 4. Time-weighted average accounts for the longer duration of the second value
+        assert_eq!(
+            SimpleMovingAverage::calculate(&circular_buffer),
+            CurrencyAmount::from_rational(2, 1)
+        );
+        assert_eq!(
+            TimeWeightedMovingAverage::calculate(&circular_buffer),
+            CurrencyAmount::from_rational(5, 2)
+        );
+    })
+}
+
+#[test]
+fn stale_feed_trips_circuit_breaker() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 123;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let stale_feed_threshold = <Test as Config>::StaleFeedThreshold::get();
+
+        // 1. Prepare the moving average, and submit a single valid value which gets pushed into the buffer
+        let init_amount = CurrencyAmount::from_rational(1, 1);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |b| b.add(init_amount));
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &init_amount);
+
+        let last_valid_block =
+            IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(last_valid_block + 1);
+        assert_eq!(
+            LastValidValueBlock::<Test>::get(native_currency_id),
+            Some(last_valid_block)
+        );
+
+        // 2. Advance until just before the threshold is reached, no circuit breaker yet
+        run_to_block(last_valid_block + stale_feed_threshold);
+        assert!(!FrozenPrice::<Test>::contains_key(native_currency_id));
+
+        // 3. Reach the threshold, circuit breaker is tripped
+        run_to_block(last_valid_block + stale_feed_threshold + 1);
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::StaleFeedDetected {
+            currency_id: native_currency_id,
+            frozen_price: init_amount,
+        }));
+        assert_eq!(
+            FrozenPrice::<Test>::get(native_currency_id),
+            Some(init_amount)
+        );
+
+        // 4. Price remains frozen, even if the moving average changes
+        let new_amount = CurrencyAmount::from_rational(3, 1);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |b| b.add(new_amount));
+        assert_eq!(PriceAggregator::average_price(), init_amount);
+        assert_eq!(
+            PriceAggregator::average_price_of(native_currency_id),
+            Some(init_amount)
+        );
+
+        // 5. Deviation bounds aren't enforced while the circuit breaker is tripped
+        MaxDeviation::set(Perbill::from_percent(10));
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &new_amount);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(native_currency_id).into_inner(),
+            vec![new_amount]
+        );
+
+        // 6. Once the new value is pushed into the moving average buffer, the feed is recovered
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        run_to_block(limit_block + 1);
+
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::FeedRecovered {
+            currency_id: native_currency_id,
+        }));
+        assert!(!FrozenPrice::<Test>::contains_key(native_currency_id));
+        assert_eq!(
+            PriceAggregator::average_price(),
+            ValuesCircularBuffer::<Test>::get(native_currency_id).average()
+        );
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(native_currency_id)
+                .buffer
+                .into_inner(),
+            vec![init_amount, init_amount, new_amount, new_amount]
+        );
+    })
+}
+
+#[test]
+fn stale_feed_trips_circuit_breaker_if_quorum_is_never_reached() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 123;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        let stale_feed_threshold = <Test as Config>::StaleFeedThreshold::get();
+        MinSubmitters::set(2);
+
+        let amount = CurrencyAmount::from_rational(1, 1);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |b| b.add(amount));
+
+        // 1. Single submitter keeps providing values in each block, but the quorum is never reached
+        let tracking_start_block = System::block_number();
+        for block in tracking_start_block..tracking_start_block + stale_feed_threshold {
+            PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+            run_to_block(block + 1);
+            assert!(!FrozenPrice::<Test>::contains_key(native_currency_id));
+        }
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::QuorumNotReached {
+            currency_id: native_currency_id,
+            submitters: 1,
+        }));
+        assert_eq!(
+            LastValidValueBlock::<Test>::get(native_currency_id),
+            Some(tracking_start_block)
+        );
+
+        // 2. Threshold is reached, circuit breaker is tripped regardless of the submitted values
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+        run_to_block(tracking_start_block + stale_feed_threshold + 1);
+
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::StaleFeedDetected {
+            currency_id: native_currency_id,
+            frozen_price: amount,
+        }));
+        assert_eq!(FrozenPrice::<Test>::get(native_currency_id), Some(amount));
+    })
+}
+
+#[test]
+fn stale_feed_circuit_breaker_waits_for_first_valid_value() {
+    ExtBuilder::build().execute_with(|| {
+        let dummy_account = 123;
+        let currency_id = CurrencyId::DOT;
+        let stale_feed_threshold = <Test as Config>::StaleFeedThreshold::get();
+
+        // 1. Tracked currency without any values isn't frozen, no matter how long it goes without a feed
+        run_to_block(System::block_number() + 2 * stale_feed_threshold);
+        assert!(!FrozenPrice::<Test>::contains_key(currency_id));
+        assert!(!LastValidValueBlock::<Test>::contains_key(currency_id));
+
+        // 2. First valid value starts the stale feed tracking
+        let amount = CurrencyAmount::from_rational(7, 1);
+        PriceAggregator::on_new_data(&dummy_account, &currency_id, &amount);
+        let last_valid_block = IntermediateValueAggregator::<Test>::get(currency_id).limit_block;
+        run_to_block(last_valid_block + 1);
+        assert_eq!(
+            LastValidValueBlock::<Test>::get(currency_id),
+            Some(last_valid_block)
+        );
+
+        // 3. Once the threshold is reached, circuit breaker is tripped
+        run_to_block(last_valid_block + stale_feed_threshold + 1);
+        System::assert_has_event(RuntimeEvent::PriceAggregator(Event::StaleFeedDetected {
+            currency_id,
+            frozen_price: amount,
+        }));
+        assert_eq!(FrozenPrice::<Test>::get(currency_id), Some(amount));
+    })
+}
@@ -63,16 +63,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 3_417_000 picoseconds.
 		Weight::from_parts(3_520_000, 0)
 	}
+	/// Storage: `PriceAggregator::AggregationSubmitters` (r:1 w:1)
+	/// Proof: `PriceAggregator::AggregationSubmitters` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:1 w:1)
-	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(172), added: 2647, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::FrozenPrice` (r:1 w:1)
+	/// Proof: `PriceAggregator::FrozenPrice` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn process_intermediate_aggregated_values() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `102`
-		//  Estimated: `1602`
-		// Minimum execution time: 9_093_000 picoseconds.
-		Weight::from_parts(9_297_000, 1602)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `319`
+		//  Estimated: `3637`
+		// Minimum execution time: 14_352_000 picoseconds.
+		Weight::from_parts(14_744_000, 3637)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -85,15 +89,19 @@ impl WeightInfo for () {
 		// Minimum execution time: 3_417_000 picoseconds.
 		Weight::from_parts(3_520_000, 0)
 	}
+	/// Storage: `PriceAggregator::AggregationSubmitters` (r:1 w:1)
+	/// Proof: `PriceAggregator::AggregationSubmitters` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:1 w:1)
-	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(172), added: 2647, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::FrozenPrice` (r:1 w:1)
+	/// Proof: `PriceAggregator::FrozenPrice` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	fn process_intermediate_aggregated_values() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `102`
-		//  Estimated: `1602`
-		// Minimum execution time: 9_093_000 picoseconds.
-		Weight::from_parts(9_297_000, 1602)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `319`
+		//  Estimated: `3637`
+		// Minimum execution time: 14_352_000 picoseconds.
+		Weight::from_parts(14_744_000, 3637)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
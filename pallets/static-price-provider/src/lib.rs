@@ -27,6 +27,15 @@
 //! Only the root can set the price.
 //!
 //! Network maintainers must ensure to update the price at appropriate times so that inflation & dApp Staking rewards are calculated correctly.
+//!
+//! ## Hybrid Price Provider
+//!
+//! `HybridPriceProvider` combines the oracle based price provider with this pallet.
+//! In case oracle price is available, it's used, but clamped to the price bounds set by the root (if any).
+//! In case oracle price isn't available (e.g. data feed is stale), the static price is used instead.
+//! Until the static price is set by the root, the last known oracle price is used as the fallback.
+//!
+//! This ensures that a broken oracle price never gets propagated further, e.g. into the dApp Staking tier slots calculation.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -36,20 +45,22 @@ pub use pallet::*;
 use sp_arithmetic::{fixed_point::FixedU128, traits::Zero, FixedPointNumber};
 use sp_std::marker::PhantomData;
 
-use astar_primitives::oracle::PriceProvider;
+use astar_primitives::oracle::{FalliblePriceProvider, PriceProvider};
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+const LOG_TARGET: &str = "static-price-provider";
+
 #[frame_support::pallet]
 pub mod pallet {
 
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -66,12 +77,16 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// New static native currency price has been set.
         PriceSet { price: FixedU128 },
+        /// New native currency price bounds have been set.
+        PriceBoundsSet { bounds: Option<PriceBounds> },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Zero is invalid value for the price (hopefully).
         ZeroPrice,
+        /// Minimum price must be non-zero, and must not exceed the maximum price.
+        InvalidPriceBounds,
     }
 
     /// Default value handler for active price.
@@ -88,6 +103,10 @@ pub mod pallet {
     #[pallet::whitelist_storage]
     pub type ActivePrice<T: Config> = StorageValue<_, FixedU128, ValueQuery, DefaultActivePrice>;
 
+    /// Bounds within which the oracle native currency price is considered valid.
+    #[pallet::storage]
+    pub type ActivePriceBounds<T: Config> = StorageValue<_, PriceBounds, OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Privileged action used to set the active native currency price.
@@ -105,6 +124,30 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Privileged action used to set the native currency price bounds, used by the `HybridPriceProvider`.
+        ///
+        /// In case `None` is provided, existing bounds are removed.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn force_set_price_bounds(
+            origin: OriginFor<T>,
+            bounds: Option<PriceBounds>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match bounds {
+                Some(bounds) => {
+                    ensure!(bounds.is_valid(), Error::<T>::InvalidPriceBounds);
+                    ActivePriceBounds::<T>::put(bounds);
+                }
+                None => ActivePriceBounds::<T>::kill(),
+            }
+
+            Self::deposit_event(Event::<T>::PriceBoundsSet { bounds });
+
+            Ok(().into())
+        }
     }
 
     impl<T: Config> PriceProvider for Pallet<T> {
@@ -114,6 +157,72 @@ pub mod pallet {
     }
 }
 
+/// Minimum & maximum native currency price.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub struct PriceBounds {
+    /// Minimum price, inclusive.
+    pub min: FixedU128,
+    /// Maximum price, inclusive.
+    pub max: FixedU128,
+}
+
+impl PriceBounds {
+    /// `true` if bounds are valid, `false` otherwise.
+    pub fn is_valid(&self) -> bool {
+        !self.min.is_zero() && self.min <= self.max
+    }
+
+    /// Clamps the price to the bounds.
+    pub fn clamp(&self, price: FixedU128) -> FixedU128 {
+        price.max(self.min).min(self.max)
+    }
+}
+
+/// Native currency price provider which primarily relies on the oracle price,
+/// and falls back to the static price in case oracle price isn't available.
+///
+/// Oracle price is clamped to the price bounds, if they are set.
+/// In case the static price hasn't been set by the root, the last known oracle price is used as the fallback.
+pub struct HybridPriceProvider<T, O>(PhantomData<(T, O)>);
+impl<T: Config, O: FalliblePriceProvider + PriceProvider> PriceProvider
+    for HybridPriceProvider<T, O>
+{
+    fn average_price() -> FixedU128 {
+        let Some(oracle_price) = O::try_average_price() else {
+            if !ActivePrice::<T>::exists() {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Oracle price isn't available and the static price isn't set, falling back to the last oracle price."
+                );
+                return O::average_price();
+            }
+
+            log::debug!(
+                target: LOG_TARGET,
+                "Oracle price isn't available, falling back to the static price."
+            );
+            return ActivePrice::<T>::get();
+        };
+
+        match ActivePriceBounds::<T>::get() {
+            Some(bounds) => {
+                let price = bounds.clamp(oracle_price);
+                if price != oracle_price {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Oracle price {:?} is out of bounds {:?}, clamping it to {:?}.",
+                        oracle_price,
+                        bounds,
+                        price
+                    );
+                }
+                price
+            }
+            None => oracle_price,
+        }
+    }
+}
+
 /// Used to update static price due to storage schema change.
 pub struct ActivePriceUpdate<T, P>(PhantomData<(T, P)>);
 impl<T: Config, P: Get<FixedU128>> OnRuntimeUpgrade for ActivePriceUpdate<T, P> {
@@ -131,3 +240,35 @@ impl<T: Config, P: Get<FixedU128>> OnRuntimeUpgrade for ActivePriceUpdate<T, P>
         T::DbWeight::get().reads_writes(1, 2)
     }
 }
+
+/// Used to reset the static price & price bounds in case the pallet is (re)added to the runtime.
+///
+/// Any leftover values are removed, so the fallback price & bounds must be set by the root.
+pub struct ActivePriceReset<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for ActivePriceReset<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        log::info!(
+            target: LOG_TARGET,
+            "Resetting the static price & price bounds."
+        );
+        ActivePrice::<T>::kill();
+        ActivePriceBounds::<T>::kill();
+
+        STORAGE_VERSION.put::<Pallet<T>>();
+
+        T::DbWeight::get().reads_writes(1, 3)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        ensure!(
+            Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION,
+            "Expected storage version 3."
+        );
+        Ok(())
+    }
+}
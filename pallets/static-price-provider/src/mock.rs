@@ -27,10 +27,13 @@ use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, FixedU128,
 };
 
-use astar_primitives::{Balance, BlockNumber};
+use astar_primitives::{
+    oracle::{FalliblePriceProvider, PriceProvider},
+    Balance, BlockNumber,
+};
 type AccountId = u64;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -88,6 +91,29 @@ impl pallet_static_price_provider::Config for Test {
     type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+    pub static OraclePrice: Option<FixedU128> = None;
+    pub static LastOraclePrice: FixedU128 = FixedU128::from_rational(1, 20);
+}
+
+/// Oracle price provider mock, price can be changed via `OraclePrice::set`.
+///
+/// In case oracle price isn't available, the last known price is `LastOraclePrice`.
+pub struct MockOraclePriceProvider;
+impl FalliblePriceProvider for MockOraclePriceProvider {
+    fn try_average_price() -> Option<FixedU128> {
+        OraclePrice::get()
+    }
+}
+impl PriceProvider for MockOraclePriceProvider {
+    fn average_price() -> FixedU128 {
+        OraclePrice::get().unwrap_or_else(LastOraclePrice::get)
+    }
+}
+
+pub type TestHybridPriceProvider =
+    pallet_static_price_provider::HybridPriceProvider<Test, MockOraclePriceProvider>;
+
 construct_runtime!(
     pub struct Test {
         System: frame_system,
@@ -58,3 +58,181 @@ fn force_set_price_with_invalid_origin_fails() {
         );
     })
 }
+
+#[test]
+fn force_set_price_bounds_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert!(ActivePriceBounds::<Test>::get().is_none(), "Sanity check");
+
+        // 1. Set the bounds
+        let bounds = PriceBounds {
+            min: FixedU128::from_rational(1, 100),
+            max: FixedU128::from_rational(1, 1),
+        };
+        assert_ok!(StaticPriceProvider::force_set_price_bounds(
+            RuntimeOrigin::root(),
+            Some(bounds)
+        ));
+        System::assert_last_event(RuntimeEvent::StaticPriceProvider(Event::PriceBoundsSet {
+            bounds: Some(bounds),
+        }));
+        assert_eq!(ActivePriceBounds::<Test>::get(), Some(bounds));
+
+        // 2. Min & max can be equal
+        let bounds = PriceBounds {
+            min: FixedU128::from_rational(1, 10),
+            max: FixedU128::from_rational(1, 10),
+        };
+        assert_ok!(StaticPriceProvider::force_set_price_bounds(
+            RuntimeOrigin::root(),
+            Some(bounds)
+        ));
+        assert_eq!(ActivePriceBounds::<Test>::get(), Some(bounds));
+
+        // 3. Remove the bounds
+        assert_ok!(StaticPriceProvider::force_set_price_bounds(
+            RuntimeOrigin::root(),
+            None
+        ));
+        System::assert_last_event(RuntimeEvent::StaticPriceProvider(Event::PriceBoundsSet {
+            bounds: None,
+        }));
+        assert!(ActivePriceBounds::<Test>::get().is_none());
+    })
+}
+
+#[test]
+fn force_set_invalid_price_bounds_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        // 1. Zero min price
+        assert_noop!(
+            StaticPriceProvider::force_set_price_bounds(
+                RuntimeOrigin::root(),
+                Some(PriceBounds {
+                    min: Zero::zero(),
+                    max: 1.into(),
+                })
+            ),
+            Error::<Test>::InvalidPriceBounds
+        );
+
+        // 2. Min price exceeds max price
+        assert_noop!(
+            StaticPriceProvider::force_set_price_bounds(
+                RuntimeOrigin::root(),
+                Some(PriceBounds {
+                    min: 2.into(),
+                    max: 1.into(),
+                })
+            ),
+            Error::<Test>::InvalidPriceBounds
+        );
+    })
+}
+
+#[test]
+fn force_set_price_bounds_with_invalid_origin_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            StaticPriceProvider::force_set_price_bounds(RuntimeOrigin::signed(1), None),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn hybrid_price_provider_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let static_price = ActivePrice::<Test>::get();
+        assert_ok!(StaticPriceProvider::force_set_price(
+            RuntimeOrigin::root(),
+            static_price
+        ));
+
+        // 1. Oracle price isn't available, static price is used
+        OraclePrice::set(None);
+        assert_eq!(TestHybridPriceProvider::average_price(), static_price);
+
+        // 2. Oracle price is available, and is used as is since there are no bounds
+        let oracle_price = static_price * 3.into();
+        OraclePrice::set(Some(oracle_price));
+        assert_eq!(TestHybridPriceProvider::average_price(), oracle_price);
+
+        // 3. Oracle price is within the bounds
+        let bounds = PriceBounds {
+            min: static_price,
+            max: static_price * 4.into(),
+        };
+        assert_ok!(StaticPriceProvider::force_set_price_bounds(
+            RuntimeOrigin::root(),
+            Some(bounds)
+        ));
+        assert_eq!(TestHybridPriceProvider::average_price(), oracle_price);
+
+        // 4. Oracle price is above the max bound, it's clamped to it
+        OraclePrice::set(Some(static_price * 10.into()));
+        assert_eq!(TestHybridPriceProvider::average_price(), bounds.max);
+
+        // 5. Oracle price is below the min bound, it's clamped to it
+        OraclePrice::set(Some(FixedU128::from_inner(1)));
+        assert_eq!(TestHybridPriceProvider::average_price(), bounds.min);
+
+        // 6. Oracle price becomes unavailable, static price is used, regardless of the bounds
+        OraclePrice::set(None);
+        let new_static_price = static_price * 5.into();
+        assert_ok!(StaticPriceProvider::force_set_price(
+            RuntimeOrigin::root(),
+            new_static_price
+        ));
+        assert_eq!(TestHybridPriceProvider::average_price(), new_static_price);
+    })
+}
+
+#[test]
+fn hybrid_price_provider_uses_last_oracle_price_until_static_price_is_set() {
+    ExternalityBuilder::build().execute_with(|| {
+        ActivePrice::<Test>::kill();
+        OraclePrice::set(None);
+
+        // 1. Static price isn't set, last known oracle price is used
+        assert_eq!(
+            TestHybridPriceProvider::average_price(),
+            LastOraclePrice::get()
+        );
+
+        // 2. Static price is set by the root, and is used from now on
+        let static_price = LastOraclePrice::get() * 2.into();
+        assert_ok!(StaticPriceProvider::force_set_price(
+            RuntimeOrigin::root(),
+            static_price
+        ));
+        assert_eq!(TestHybridPriceProvider::average_price(), static_price);
+    })
+}
+
+#[test]
+fn active_price_reset_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        // 1. Pallet is re-added, leftover price & bounds are removed
+        StorageVersion::new(2).put::<StaticPriceProvider>();
+        ActivePrice::<Test>::put(FixedU128::from_rational(13, 100));
+        ActivePriceBounds::<Test>::put(PriceBounds {
+            min: 1.into(),
+            max: 2.into(),
+        });
+
+        ActivePriceReset::<Test>::on_runtime_upgrade();
+        assert!(!ActivePrice::<Test>::exists());
+        assert!(!ActivePriceBounds::<Test>::exists());
+        assert_eq!(
+            StaticPriceProvider::on_chain_storage_version(),
+            STORAGE_VERSION
+        );
+
+        // 2. Price set by the root afterwards isn't removed
+        let new_price = FixedU128::from_rational(17, 100);
+        ActivePrice::<Test>::put(new_price);
+        ActivePriceReset::<Test>::on_runtime_upgrade();
+        assert_eq!(ActivePrice::<Test>::get(), new_price);
+    })
+}
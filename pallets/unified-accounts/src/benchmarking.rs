@@ -20,7 +20,7 @@
 
 use super::*;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::fungibles};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
@@ -29,7 +29,11 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
-#[benchmarks]
+#[benchmarks(
+    where
+        T::Assets: fungibles::Create<T::AccountId>,
+        AssetIdOf<T>: From<u32>,
+)]
 mod benchmarks {
     use super::*;
 
@@ -84,6 +88,131 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn rebind_evm_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        let old_evm_address = T::DefaultMappings::to_default_h160(&caller);
+        let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+        let evm_address = Pallet::<T>::eth_address(&eth_secret_key);
+        let signature = Pallet::<T>::eth_sign_prehash(
+            &Pallet::<T>::build_signing_payload(&caller),
+            &eth_secret_key,
+        )
+        .into();
+
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // claim mapping
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), evm_address, signature);
+
+        assert_last_event::<T>(
+            Event::<T>::AccountRebound {
+                account_id: caller,
+                old_evm_address,
+                new_evm_address: evm_address,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn unbind_evm_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        let evm_address = T::DefaultMappings::to_default_h160(&caller);
+
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // claim mapping
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T>(
+            Event::<T>::AccountUnbound {
+                account_id: caller,
+                evm_address,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn sweep_default_account(n: Linear<0, { T::MaxSweepAssets::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+        let evm_address = Pallet::<T>::eth_address(&eth_secret_key);
+        let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
+        let signature = Pallet::<T>::eth_sign_prehash(
+            &Pallet::<T>::build_signing_payload(&caller),
+            &eth_secret_key,
+        )
+        .into();
+
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // claim mapping
+        assert_ok!(Pallet::<T>::claim_evm_address(
+            RawOrigin::Signed(caller.clone()).into(),
+            evm_address,
+            signature
+        ));
+
+        // fund the default account
+        let native = T::Currency::minimum_balance().saturating_mul(100);
+        assert_ok!(T::Currency::mint_into(&default_account_id, native));
+        let mut assets = Vec::new();
+        for i in 0..n {
+            let asset_id: AssetIdOf<T> = i.into();
+            assert_ok!(<T::Assets as fungibles::Create<_>>::create(
+                asset_id.clone(),
+                caller.clone(),
+                true,
+                1
+            ));
+            let amount = T::Assets::minimum_balance(asset_id.clone()).saturating_mul(100);
+            assert_ok!(T::Assets::mint_into(
+                asset_id.clone(),
+                &default_account_id,
+                amount
+            ));
+            assets.push((asset_id, amount));
+        }
+        let asset_ids: BoundedVec<_, _> = assets
+            .iter()
+            .map(|(asset_id, _)| asset_id.clone())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), asset_ids, false);
+
+        assert_last_event::<T>(
+            Event::<T>::DefaultAccountSwept {
+                account_id: caller,
+                default_account_id,
+                native,
+                assets,
+                staking_locks: 0,
+            }
+            .into(),
+        );
+    }
+
     #[benchmark]
     fn to_account_id() {
         let caller: T::AccountId = whitelisted_caller();
@@ -30,61 +30,90 @@
 //! The Unified Accounts module provide functionality for native account holders to
 //! connect their evm address to have a unified experience across the different VMs.
 //! - Connect evm address you control
+//! - Connect evm address of a smart contract wallet you control (EIP-1271)
 //! - Connect default evm address
+//! - Rebind to another evm address you control, or unbind the evm address
+//! - Sweep the assets held by the default account of the connected evm address
 //!
 //! ## Interface
 //!
 //! * `claim_evm_address`: Creates the double mappings for the provided evm address with caller
 //!    account id given that no prior mapping exists for both and signature provided is valid.
+//! * `claim_contract_evm_address`: Creates the double mappings for the provided smart contract
+//!    wallet address with caller account id given that no prior mapping exists for both and the
+//!    contract accepts the provided signature via EIP-1271 `isValidSignature`.
 //! * `claim_default_evm_address`: Creates the double mapping with default evm address given that
 //!    no prior mapping exists.
+//! * `rebind_evm_address`: Replaces the evm address mapped to the caller account with the provided
+//!    one, given that it's not mapped yet and signature provided by the new address is valid.
+//! * `unbind_evm_address`: Removes the double mappings of the caller account.
+//! * `sweep_default_account`: Transfers the native balance, `pallet_assets` balances and optionally
+//!    dApp staking locks held by the default account id of the evm address mapped to the caller
+//!    account, to the caller account.
+//! * `claim_evm_address_and_sweep`: Same as `claim_evm_address`, followed by `sweep_default_account`.
 //!
 //! ## Storage Fee
 //! User is also charged a storage fee [`AccountMappingStorageFee`](`crate::Config::AccountMappingStorageFee`)
-//! before mappings are created to prevent storage abuse.
+//! before mappings are created to prevent storage abuse. The fee paid is recorded per account.
+//! Rebinding reuses the existing mappings, hence no additional fee is charged, while unbinding
+//! refunds exactly the recorded fee. Mappings claimed before the fee was recorded are unbound
+//! without a refund, since the fee paid for them is unknown.
 //!
 //! WARNINGS:
-//! * This pallet only handles transfer of native balance only, for the rest of native assets
-//!   hold by evm address like XC20, DAppStaking unclaimed rewards, etc should be transferred
+//! * Claiming only handles transfer of native balance, the rest of native assets hold by evm
+//!   address like XC20 and DAppStaking locks should be swept while claiming via
+//!   `claim_evm_address_and_sweep`, or afterwards via `sweep_default_account`,
+//!   while DAppStaking stake and unclaimed rewards cannot be transferred and should be handled
 //!   manually beforehand by user himself otherwise FUNDS WILL BE LOST FOREVER.
-//! * Once mapping is created it cannot be changed.
+//! * Once the mapping is rebound or unbound, the previous evm address is no longer connected to
+//!   the account, assets received by that evm address afterwards are held by its default account id.
 //!
 //! ## Traits
 //!
 //! * `UnifiedAddressMapper`: Interface to access pallet's mappings with defaults
+//! * [`ContractSignatureVerifier`]: Interface to verify EIP-1271 signatures of smart contract wallets
 //!
 //! ## Implementations
 //!
 //! * [`StaticLookup`](sp_runtime::traits::StaticLookup): Lookup implementations for accepting H160
 //! * [`AddressMapping`](pallet_evm::AddressMapping): Wrapper over `UnifiedAddressMapper` for evm address mapping
 //!   to account id.
+//! * [`EvmContractSignatureVerifier`]: `ContractSignatureVerifier` implementation executing a
+//!   read-only call of `isValidSignature` against the contract in EVM.
 //! * `KillAccountMapping`: [`OnKilledAccount`](frame_support::traits::OnKilledAccount) implementation to remove
 //!   the mappings from storage after account is reaped.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use astar_primitives::{
+    dapp_staking::LocksTransfer,
     evm::{EvmAddress, UnifiedAddressMapper},
     Balance,
 };
+use evm::{Capture, Context as EvmContext, Handler};
 use frame_support::{
+    dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
     pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
     traits::{
         fungible::{Inspect as FungibleInspect, Mutate as FungibleMutate},
+        fungibles::{Inspect as FungiblesInspect, Mutate as FungiblesMutate},
         tokens::{Fortitude::*, Precision::*, Preservation::*},
         IsType, OnKilledAccount,
     },
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
-use pallet_evm::AddressMapping;
-use precompile_utils::keccak256;
+use pallet_evm::{
+    runner::stack::Runner as StackRunner, AddressMapping, BalanceOf, GasWeightMapping,
+};
+use precompile_utils::{keccak256, solidity, solidity::codec::UnboundedBytes};
 use sp_core::{H160, H256, U256};
 use sp_io::hashing::keccak_256;
 use sp_runtime::{
-    traits::{LookupError, StaticLookup, Zero},
+    traits::{LookupError, StaticLookup, UniqueSaturatedInto, Zero},
     MultiAddress,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 pub use pallet::*;
 
@@ -99,13 +128,20 @@ mod tests;
 /// ECDSA Signature type, with last bit for recovering address
 type EvmSignature = [u8; 65];
 
+/// Asset id type of the `pallet_assets` balances
+pub type AssetIdOf<T> =
+    <<T as Config>::Assets as FungiblesInspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+/// EIP-1271 `isValidSignature(bytes32,bytes)` selector, which is also the magic value
+/// returned by the contract when the signature is valid.
+pub const EIP1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
     #[pallet::pallet]
     pub struct Pallet<T>(PhantomData<T>);
-
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching event type
@@ -122,6 +158,21 @@ pub mod pallet {
         /// respectively
         #[pallet::constant]
         type AccountMappingStorageFee: Get<Balance>;
+        /// Verifier for EIP-1271 signatures of smart contract wallets
+        type ContractSignatureVerifier: ContractSignatureVerifier;
+        /// The max weight that can be consumed by the EIP-1271 signature verification
+        #[pallet::constant]
+        type ContractSignatureWeightLimit: Get<Weight>;
+        /// The max length of the EIP-1271 signature
+        #[pallet::constant]
+        type MaxContractSignatureLength: Get<u32>;
+        /// The `pallet_assets` fungibles to sweep from the default accounts
+        type Assets: FungiblesMutate<Self::AccountId, Balance = Balance>;
+        /// Transfer of the dApp staking locks to sweep from the default accounts
+        type StakingLocks: LocksTransfer<Self::AccountId>;
+        /// The max number of assets that can be swept at once
+        #[pallet::constant]
+        type MaxSweepAssets: Get<u32>;
         /// Weight information for the extrinsics in this module
         type WeightInfo: WeightInfo;
     }
@@ -136,6 +187,8 @@ pub mod pallet {
         InvalidSignature,
         /// Funds unavailable to claim account
         FundsUnavailable,
+        /// AccountId has no mapped evm address
+        NotMapped,
     }
 
     #[pallet::event]
@@ -147,6 +200,28 @@ pub mod pallet {
             account_id: T::AccountId,
             evm_address: EvmAddress,
         },
+        /// Evm Address rebound.
+        /// Double Mapping b/w native and old evm address replaced with the new evm address
+        AccountRebound {
+            account_id: T::AccountId,
+            old_evm_address: EvmAddress,
+            new_evm_address: EvmAddress,
+        },
+        /// Evm Address unbound.
+        /// Double Mapping b/w native and evm address removed
+        AccountUnbound {
+            account_id: T::AccountId,
+            evm_address: EvmAddress,
+        },
+        /// Default account swept.
+        /// Funds held by the default account id of the mapped evm address are transferred to the account
+        DefaultAccountSwept {
+            account_id: T::AccountId,
+            default_account_id: T::AccountId,
+            native: Balance,
+            assets: Vec<(AssetIdOf<T>, Balance)>,
+            staking_locks: Balance,
+        },
     }
 
     /// Native accounts for evm address
@@ -161,6 +236,12 @@ pub mod pallet {
     pub type NativeToEvm<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+    /// Storage fee paid for the mappings of native accounts, refunded once unbound
+    /// StorageFeePaid: AccountId => Balance
+    #[pallet::storage]
+    pub type StorageFeePaid<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Claim account mapping between Substrate account and Evm address.
@@ -198,33 +279,7 @@ pub mod pallet {
 
             ensure!(evm_address == address, Error::<T>::InvalidSignature);
 
-            // charge the storage fee
-            Self::charge_storage_fee(&who)?;
-
-            // Check if the default account id already exists for this evm address
-            let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
-            if frame_system::Pallet::<T>::account_exists(&default_account_id) {
-                // Transfer all the free native balance from old account id to the newly
-                // since this `default_account_id` will no longer be connected to evm address
-                // and users cannot access it.
-                // For the reset of the assets types (like XC20, etc) that should be handled by UI.
-                T::Currency::transfer(
-                    &default_account_id,
-                    &who,
-                    T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
-                    Expendable,
-                )?;
-            }
-
-            // create double mappings for the pair
-            EvmToNative::<T>::insert(&evm_address, &who);
-            NativeToEvm::<T>::insert(&who, &evm_address);
-
-            Self::deposit_event(Event::AccountClaimed {
-                account_id: who,
-                evm_address,
-            });
-            Ok(())
+            Self::do_claim_evm_address(who, evm_address)
         }
 
         /// Claim default evm address for given account id
@@ -239,10 +294,217 @@ pub mod pallet {
             let _ = Self::do_claim_default_evm_address(who)?;
             Ok(())
         }
+
+        /// Rebind the caller's account to another evm address.
+        /// Ensure the caller has a prior mapping and no prior mapping exists for the new evm address.
+        ///
+        /// - `evm_address`: The new evm address to bind to the caller's account
+        /// - `signature`: A signature generated by the new address to prove ownership
+        ///
+        /// No additional storage fee is charged since the mappings are replaced.
+        ///
+        /// WARNING:
+        /// - This extrisic only handles transfer of native balance held by the default account
+        /// of the new evm address, the rest of native assets must be transferred before hand,
+        /// otherwise FUNDS WILL BE LOST FOREVER.
+        /// - The old evm address is no longer connected to the caller's account.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::rebind_evm_address())]
+        pub fn rebind_evm_address(
+            origin: OriginFor<T>,
+            evm_address: EvmAddress,
+            signature: EvmSignature,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let old_evm_address = NativeToEvm::<T>::get(&who).ok_or(Error::<T>::NotMapped)?;
+            // make sure no prior mapping exists for the new evm address
+            ensure!(
+                !EvmToNative::<T>::contains_key(evm_address),
+                Error::<T>::AlreadyMapped
+            );
+
+            // recover evm address from signature
+            let address = Self::verify_signature(&who, &signature)
+                .ok_or(Error::<T>::UnexpectedSignatureFormat)?;
+
+            ensure!(evm_address == address, Error::<T>::InvalidSignature);
+
+            Self::migrate_default_account_balance(&evm_address, &who)?;
+
+            // replace double mappings for the pair
+            EvmToNative::<T>::remove(&old_evm_address);
+            EvmToNative::<T>::insert(&evm_address, &who);
+            NativeToEvm::<T>::insert(&who, &evm_address);
+
+            Self::deposit_event(Event::AccountRebound {
+                account_id: who,
+                old_evm_address,
+                new_evm_address: evm_address,
+            });
+            Ok(())
+        }
+
+        /// Unbind the evm address mapped to the caller's account, and refund the storage fee paid for it.
+        /// Ensure the caller has a prior mapping.
+        ///
+        /// WARNING: The evm address is no longer connected to the caller's account, assets
+        /// received by it afterwards are held by its default account id.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::unbind_evm_address())]
+        pub fn unbind_evm_address(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let evm_address = NativeToEvm::<T>::take(&who).ok_or(Error::<T>::NotMapped)?;
+            EvmToNative::<T>::remove(&evm_address);
+
+            Self::refund_storage_fee(&who)?;
+
+            Self::deposit_event(Event::AccountUnbound {
+                account_id: who,
+                evm_address,
+            });
+            Ok(())
+        }
+
+        /// Claim account mapping between Substrate account and the evm address of a smart
+        /// contract wallet (like multisig or account abstraction wallets).
+        /// Ensure no prior mapping exists for evm address.
+        ///
+        /// - `evm_address`: The smart contract wallet address to bind to the caller's account
+        /// - `signature`: A signature accepted by the contract's EIP-1271 `isValidSignature`
+        /// method for the claim payload, to prove ownership
+        ///
+        /// The contract is called in read-only mode with at most
+        /// [`ContractSignatureWeightLimit`](`crate::Config::ContractSignatureWeightLimit`) weight,
+        /// unused weight is refunded.
+        ///
+        /// WARNING:
+        /// - This extrisic only handles transfer of native balance, if your EVM
+        /// address contains any other native assets like XC20, DAppStaking unclaimed rewards,
+        /// etc you need to transfer them before hand, otherwise FUNDS WILL BE LOST FOREVER.
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            T::WeightInfo::claim_evm_address()
+                .saturating_add(T::ContractSignatureWeightLimit::get())
+        )]
+        pub fn claim_contract_evm_address(
+            origin: OriginFor<T>,
+            evm_address: EvmAddress,
+            signature: BoundedVec<u8, T::MaxContractSignatureLength>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            // make sure no prior mapping exists
+            ensure!(
+                !NativeToEvm::<T>::contains_key(&who),
+                Error::<T>::AlreadyMapped
+            );
+            ensure!(
+                !EvmToNative::<T>::contains_key(evm_address),
+                Error::<T>::AlreadyMapped
+            );
+
+            // verify signature with the contract
+            let (is_valid, used_weight) =
+                Self::verify_contract_signature(&who, &evm_address, &signature);
+            let actual_weight = T::WeightInfo::claim_evm_address().saturating_add(used_weight);
+
+            ensure!(
+                is_valid,
+                DispatchErrorWithPostInfo {
+                    post_info: PostDispatchInfo {
+                        actual_weight: Some(actual_weight),
+                        pays_fee: Pays::Yes,
+                    },
+                    error: Error::<T>::InvalidSignature.into(),
+                }
+            );
+
+            Self::do_claim_evm_address(who, evm_address)?;
+            Ok(Some(actual_weight).into())
+        }
+
+        /// Sweep the funds held by the default account id of the evm address mapped to the
+        /// caller's account, which are no longer reachable once the evm address is claimed.
+        /// Ensure the caller has a prior mapping.
+        ///
+        /// - `asset_ids`: The `pallet_assets` asset ids to sweep the balances of
+        /// - `staking_locks`: Whether to transfer the dApp staking locks as well
+        ///
+        /// The free native balance is always swept. The dApp staking locks can only be transferred
+        /// if the default account has no stake.
+        #[pallet::call_index(5)]
+        #[pallet::weight(
+            T::WeightInfo::sweep_default_account(asset_ids.len() as u32).saturating_add(
+                if *staking_locks {
+                    T::StakingLocks::transfer_locks_weight()
+                } else {
+                    Weight::zero()
+                }
+            )
+        )]
+        pub fn sweep_default_account(
+            origin: OriginFor<T>,
+            asset_ids: BoundedVec<AssetIdOf<T>, T::MaxSweepAssets>,
+            staking_locks: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let evm_address = NativeToEvm::<T>::get(&who).ok_or(Error::<T>::NotMapped)?;
+
+            Self::do_sweep_default_account(who, &evm_address, asset_ids, staking_locks)
+        }
+
+        /// Claim account mapping between Substrate account and Evm address, and sweep the funds
+        /// held by the default account id of the evm address to the caller's account.
+        ///
+        /// - `evm_address`: The evm address to bind to the caller's account
+        /// - `signature`: A signature generated by the address to prove ownership
+        /// - `asset_ids`: The `pallet_assets` asset ids to sweep the balances of
+        /// - `staking_locks`: Whether to transfer the dApp staking locks as well
+        ///
+        /// Same as `claim_evm_address` followed by `sweep_default_account`, in a single call.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            T::WeightInfo::claim_evm_address()
+                .saturating_add(T::WeightInfo::sweep_default_account(asset_ids.len() as u32))
+                .saturating_add(if *staking_locks {
+                    T::StakingLocks::transfer_locks_weight()
+                } else {
+                    Weight::zero()
+                })
+        )]
+        pub fn claim_evm_address_and_sweep(
+            origin: OriginFor<T>,
+            evm_address: EvmAddress,
+            signature: EvmSignature,
+            asset_ids: BoundedVec<AssetIdOf<T>, T::MaxSweepAssets>,
+            staking_locks: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin.clone())?;
+            Self::claim_evm_address(origin, evm_address, signature)?;
+
+            Self::do_sweep_default_account(who, &evm_address, asset_ids, staking_locks)
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Create the double mappings between `who` and the verified `evm_address`
+    fn do_claim_evm_address(who: T::AccountId, evm_address: EvmAddress) -> DispatchResult {
+        // charge the storage fee
+        Self::charge_storage_fee(&who)?;
+
+        Self::migrate_default_account_balance(&evm_address, &who)?;
+
+        // create double mappings for the pair
+        EvmToNative::<T>::insert(&evm_address, &who);
+        NativeToEvm::<T>::insert(&who, &evm_address);
+
+        Self::deposit_event(Event::AccountClaimed {
+            account_id: who,
+            evm_address,
+        });
+        Ok(())
+    }
+
     /// Claim the default evm address
     fn do_claim_default_evm_address(account_id: T::AccountId) -> Result<EvmAddress, DispatchError> {
         ensure!(
@@ -271,13 +533,98 @@ impl<T: Config> Pallet<T> {
         Ok(evm_address)
     }
 
+    /// Transfer the funds held by the default account id of `evm_address` to `who`.
+    fn do_sweep_default_account(
+        who: T::AccountId,
+        evm_address: &EvmAddress,
+        asset_ids: BoundedVec<AssetIdOf<T>, T::MaxSweepAssets>,
+        staking_locks: bool,
+    ) -> DispatchResult {
+        let default_account_id = T::DefaultMappings::to_default_account_id(evm_address);
+
+        // assets go first, since they might prevent the default account from being reaped
+        let mut assets = Vec::with_capacity(asset_ids.len());
+        for asset_id in asset_ids {
+            let amount = T::Assets::reducible_balance(
+                asset_id.clone(),
+                &default_account_id,
+                Expendable,
+                Polite,
+            );
+            if !amount.is_zero() {
+                T::Assets::transfer(
+                    asset_id.clone(),
+                    &default_account_id,
+                    &who,
+                    amount,
+                    Expendable,
+                )?;
+                assets.push((asset_id, amount));
+            }
+        }
+
+        let staking_locks = if staking_locks {
+            T::StakingLocks::transfer_locks(&default_account_id, &who)?
+        } else {
+            Zero::zero()
+        };
+
+        let native = T::Currency::reducible_balance(&default_account_id, Expendable, Polite);
+        if !native.is_zero() {
+            T::Currency::transfer(&default_account_id, &who, native, Expendable)?;
+        }
+
+        Self::deposit_event(Event::DefaultAccountSwept {
+            account_id: who,
+            default_account_id,
+            native,
+            assets,
+            staking_locks,
+        });
+        Ok(())
+    }
+
     /// Charge the (exact) storage fee (politely) from the user and burn it
     /// while preserving the account from being reaped.
+    /// The fee paid is recorded so it can be refunded once the mappings are removed.
     fn charge_storage_fee(who: &T::AccountId) -> Result<Balance, DispatchError> {
         let balance = T::Currency::reducible_balance(who, Preserve, Polite);
         let fee = T::AccountMappingStorageFee::get();
         ensure!(balance >= fee, Error::<T>::FundsUnavailable);
-        T::Currency::burn_from(who, T::AccountMappingStorageFee::get(), Exact, Polite)
+        let burned = T::Currency::burn_from(who, fee, Exact, Polite)?;
+        StorageFeePaid::<T>::insert(who, burned);
+        Ok(burned)
+    }
+
+    /// Refund the storage fee burned on claim by minting exactly the paid amount back to the user.
+    /// Nothing is refunded in case no fee is recorded for the user.
+    fn refund_storage_fee(who: &T::AccountId) -> Result<Balance, DispatchError> {
+        let fee = StorageFeePaid::<T>::take(who);
+        if fee.is_zero() {
+            return Ok(Zero::zero());
+        }
+        T::Currency::mint_into(who, fee)
+    }
+
+    /// Transfer all the free native balance of the default account id of `evm_address` to `who`,
+    /// since the default account id will no longer be connected to the evm address once it's
+    /// mapped to `who`, and users cannot access it.
+    /// For the rest of the assets types (like XC20, etc) that should be handled by UI.
+    fn migrate_default_account_balance(
+        evm_address: &EvmAddress,
+        who: &T::AccountId,
+    ) -> DispatchResult {
+        // Check if the default account id already exists for this evm address
+        let default_account_id = T::DefaultMappings::to_default_account_id(evm_address);
+        if frame_system::Pallet::<T>::account_exists(&default_account_id) {
+            T::Currency::transfer(
+                &default_account_id,
+                who,
+                T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
+                Expendable,
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -304,6 +651,23 @@ impl<T: Config> Pallet<T> {
             .ok()
     }
 
+    /// Verify the EIP-1271 signature of the smart contract wallet `contract` for the signing
+    /// payload of `who`. Returns the verification result along with the consumed weight.
+    pub fn verify_contract_signature(
+        who: &T::AccountId,
+        contract: &EvmAddress,
+        sig: &[u8],
+    ) -> (bool, Weight) {
+        let payload_hash = Self::build_signing_payload(who);
+
+        T::ContractSignatureVerifier::is_valid_signature(
+            contract,
+            &payload_hash,
+            sig,
+            T::ContractSignatureWeightLimit::get(),
+        )
+    }
+
     fn build_domain_separator() -> [u8; 32] {
         let mut domain =
             keccak256!("EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)")
@@ -365,6 +729,127 @@ impl<T: Config> UnifiedAddressMapper<T::AccountId> for Pallet<T> {
     }
 }
 
+/// Verifier of EIP-1271 signatures for smart contract wallets
+/// https://eips.ethereum.org/EIPS/eip-1271
+pub trait ContractSignatureVerifier {
+    /// Check if `signature` of `hash` is valid for the smart contract wallet `contract`, without
+    /// modifying any state and consuming at most `weight_limit`.
+    /// Returns the verification result along with the consumed weight.
+    fn is_valid_signature(
+        contract: &EvmAddress,
+        hash: &[u8; 32],
+        signature: &[u8],
+        weight_limit: Weight,
+    ) -> (bool, Weight);
+}
+
+/// Reject all the contract signatures
+impl ContractSignatureVerifier for () {
+    fn is_valid_signature(
+        _contract: &EvmAddress,
+        _hash: &[u8; 32],
+        _signature: &[u8],
+        _weight_limit: Weight,
+    ) -> (bool, Weight) {
+        (false, Weight::zero())
+    }
+}
+
+/// EIP-1271 signature verifier executing `isValidSignature(bytes32,bytes)` of the contract
+/// in EVM. The call is executed as a static call, so the contract can't modify any state.
+/// The returned weight includes the proof size recorded by the call.
+pub struct EvmContractSignatureVerifier<T>(PhantomData<T>);
+impl<T: pallet_evm::Config> ContractSignatureVerifier for EvmContractSignatureVerifier<T>
+where
+    BalanceOf<T>: TryFrom<U256> + Into<U256>,
+{
+    fn is_valid_signature(
+        contract: &EvmAddress,
+        hash: &[u8; 32],
+        signature: &[u8],
+        weight_limit: Weight,
+    ) -> (bool, Weight) {
+        let code_read_weight = T::DbWeight::get().reads(1);
+        // only contracts are able to verify signatures
+        if pallet_evm::AccountCodes::<T>::decode_len(contract).unwrap_or_default() == 0 {
+            return (false, code_read_weight);
+        }
+
+        let input = solidity::encode_with_selector(
+            u32::from_be_bytes(EIP1271_MAGIC_VALUE),
+            (H256::from(*hash), UnboundedBytes::from(signature.to_vec())),
+        );
+        let gas_limit =
+            T::GasWeightMapping::weight_to_gas(weight_limit.saturating_sub(code_read_weight));
+        // input, source and target addresses (20 bytes each) are the base of the proof size
+        let proof_size_base_cost = (input.len() as u64).saturating_add(40);
+        let (evm_weight_limit, proof_size_base_cost) =
+            match T::GasWeightMapping::gas_to_weight(gas_limit, true) {
+                evm_weight_limit if evm_weight_limit.proof_size() > 0 => {
+                    (Some(evm_weight_limit), Some(proof_size_base_cost))
+                }
+                _ => (None, None),
+            };
+
+        // The call itself is static, but the runner bookkeeping is rolled back as well.
+        let result = with_transaction(|| {
+            let precompiles = T::PrecompilesValue::get();
+            let call_result = StackRunner::<T>::execute(
+                H160::zero(),
+                U256::zero(),
+                gas_limit,
+                None,
+                None,
+                T::config(),
+                &precompiles,
+                false,
+                evm_weight_limit,
+                proof_size_base_cost,
+                |executor| {
+                    let context = EvmContext {
+                        address: *contract,
+                        caller: H160::zero(),
+                        apparent_value: U256::zero(),
+                    };
+                    match Handler::call(
+                        executor,
+                        *contract,
+                        None,
+                        input,
+                        Some(gas_limit),
+                        true,
+                        context,
+                    ) {
+                        Capture::Exit(result) => result,
+                        Capture::Trap(interrupt) => match interrupt {},
+                    }
+                },
+            );
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(call_result))
+        });
+
+        match result {
+            Ok(Ok(info)) => {
+                let mut used_weight = T::GasWeightMapping::gas_to_weight(
+                    info.used_gas.standard.unique_saturated_into(),
+                    true,
+                );
+                if let Some(proof_size_usage) = info
+                    .weight_info
+                    .and_then(|weight_info| weight_info.proof_size_usage)
+                {
+                    *used_weight.proof_size_mut() = proof_size_usage;
+                }
+                let used_weight = used_weight.saturating_add(code_read_weight);
+                let is_valid = info.exit_reason.is_succeed()
+                    && info.value.get(0..4) == Some(&EIP1271_MAGIC_VALUE[..]);
+                (is_valid, used_weight.min(weight_limit))
+            }
+            _ => (false, weight_limit),
+        }
+    }
+}
+
 /// AddressMapping wrapper implementation
 impl<T: Config> AddressMapping<T::AccountId> for Pallet<T> {
     fn into_account_id(evm_address: H160) -> T::AccountId {
@@ -382,6 +867,7 @@ impl<T: Config> OnKilledAccount<T::AccountId> for KillAccountMapping<T> {
         if let Some(evm_addr) = NativeToEvm::<T>::take(who) {
             EvmToNative::<T>::remove(evm_addr);
             NativeToEvm::<T>::remove(who);
+            StorageFeePaid::<T>::remove(who);
         }
     }
 }
@@ -23,9 +23,10 @@ use crate as pallet_unified_accounts;
 use astar_primitives::evm::HashedDefaultMappings;
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{ConstU64, FindAuthor},
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU64, FindAuthor},
     weights::Weight,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_ethereum::PostLogContent;
 use pallet_evm::FeeCalculator;
 use sp_core::{keccak_256, H160, H256, U256};
@@ -84,6 +85,29 @@ impl pallet_balances::Config for TestRuntime {
     type MaxFreezes = ConstU32<0>;
 }
 
+impl pallet_assets::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<20>;
+    type Freezer = ();
+    type Extra = ();
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<0>;
+    type AssetIdParameter = AssetId;
+    type CallbackHandle = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 impl pallet_timestamp::Config for TestRuntime {
     type Moment = u64;
     type OnTimestampSet = ();
@@ -150,7 +174,8 @@ impl pallet_ethereum::Config for TestRuntime {
 }
 
 parameter_types! {
-    pub const AccountMappingStorageFee: u128 = 100_000_000;
+    pub static AccountMappingStorageFee: u128 = 100_000_000;
+    pub ContractSignatureWeightLimit: Weight = Weight::from_parts(1_000_000, 0);
 }
 
 impl pallet_unified_accounts::Config for TestRuntime {
@@ -159,11 +184,18 @@ impl pallet_unified_accounts::Config for TestRuntime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type ContractSignatureVerifier = EvmContractSignatureVerifier<Self>;
+    type ContractSignatureWeightLimit = ContractSignatureWeightLimit;
+    type MaxContractSignatureLength = ConstU32<1024>;
+    type Assets = Assets;
+    type StakingLocks = ();
+    type MaxSweepAssets = ConstU32<4>;
     type WeightInfo = ();
 }
 
 pub(crate) type AccountId = AccountId32;
 pub(crate) type Balance = u128;
+pub(crate) type AssetId = u128;
 
 pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
 pub const BOB: AccountId32 = AccountId32::new([1u8; 32]);
@@ -177,6 +209,29 @@ pub fn bob_secret() -> libsecp256k1::SecretKey {
     libsecp256k1::SecretKey::parse(&keccak_256(b"Bob")).unwrap()
 }
 
+/// Runtime code of a mock EIP-1271 smart contract wallet, which accepts the signed hash itself
+/// as the signature, i.e. `isValidSignature(hash, signature)` returns the magic value if
+/// `signature == abi.encodePacked(hash)`.
+pub const MOCK_SMART_WALLET_CODE: &str =
+    "606435600435146044356020141660165760206000f35b631626ba7e60e01b60005260206000f3";
+
+/// Deploy the mock smart contract wallet at given address
+pub fn deploy_mock_smart_wallet(address: H160) {
+    Evm::create_account(address, hex::decode(MOCK_SMART_WALLET_CODE).unwrap());
+}
+
+/// Runtime code of a mock EIP-1271 smart contract wallet, which writes to its storage and
+/// then accepts any signature.
+pub const MOCK_STATEFUL_SMART_WALLET_CODE: &str = "6001600055631626ba7e60e01b60005260206000f3";
+
+/// Deploy the mock stateful smart contract wallet at given address
+pub fn deploy_mock_stateful_smart_wallet(address: H160) {
+    Evm::create_account(
+        address,
+        hex::decode(MOCK_STATEFUL_SMART_WALLET_CODE).unwrap(),
+    );
+}
+
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
 
 construct_runtime!(
@@ -184,6 +239,7 @@ construct_runtime!(
         System: frame_system,
         Timestamp: pallet_timestamp,
         Balances: pallet_balances,
+        Assets: pallet_assets,
         Evm: pallet_evm,
         Ethereum: pallet_ethereum,
         UnifiedAccounts: pallet_unified_accounts,
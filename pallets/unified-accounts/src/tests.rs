@@ -19,9 +19,10 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok};
 use mock::*;
 
+use astar_primitives::evm::UnifiedAddress;
 use ethers::{
     contract::{Eip712, EthAbiType},
     core::types::{transaction::eip712::Eip712, Bytes},
@@ -397,3 +398,423 @@ fn charging_storage_fee_should_not_reap_account() {
         );
     });
 }
+
+#[test]
+fn account_rebind_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+        // default ss58 account associated with the new eth address
+        let bob_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&bob_eth);
+
+        connect_accounts(&ALICE, &alice_secret());
+        let alice_balance = Balances::total_balance(&ALICE);
+
+        // transfer some funds to bob_eth (H160)
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            bob_eth_old_account.clone().into(),
+            1001
+        ));
+
+        // rebind to the new eth address, signed by the new eth address
+        assert_ok!(UnifiedAccounts::rebind_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            bob_eth,
+            get_evm_signature(&ALICE, &bob_secret())
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountRebound {
+                account_id: ALICE.clone(),
+                old_evm_address: alice_eth,
+                new_evm_address: bob_eth,
+            },
+        ));
+
+        // balance of old account (bob_eth_old_account) is transfered to ALICE,
+        // and no additional storage fee is charged
+        assert_eq!(Balances::total_balance(&bob_eth_old_account), 0);
+        assert_eq!(Balances::total_balance(&ALICE), alice_balance + 1001);
+
+        // make sure mappings are replaced
+        assert_eq!(EvmToNative::<TestRuntime>::get(bob_eth), Some(ALICE));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(bob_eth));
+        assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth), None);
+
+        // old eth address can be claimed by others
+        connect_accounts(&BOB, &alice_secret());
+        assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth), Some(BOB));
+    });
+}
+
+#[test]
+fn account_rebind_should_not_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+
+        // ALICE has no mapping yet
+        assert_noop!(
+            UnifiedAccounts::rebind_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                bob_eth,
+                get_evm_signature(&ALICE, &bob_secret())
+            ),
+            Error::<TestRuntime>::NotMapped
+        );
+
+        connect_accounts(&ALICE, &alice_secret());
+        connect_accounts(&BOB, &bob_secret());
+
+        // new eth address already mapped
+        assert_noop!(
+            UnifiedAccounts::rebind_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                bob_eth,
+                get_evm_signature(&ALICE, &bob_secret())
+            ),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+
+        // signature not generated by the new eth address
+        assert_ok!(UnifiedAccounts::unbind_evm_address(RuntimeOrigin::signed(
+            BOB
+        )));
+        assert_noop!(
+            UnifiedAccounts::rebind_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                bob_eth,
+                get_evm_signature(&ALICE, &alice_secret())
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(alice_eth));
+    });
+}
+
+#[test]
+fn account_unbind_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+
+        // ALICE has no mapping yet
+        assert_noop!(
+            UnifiedAccounts::unbind_evm_address(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::NotMapped
+        );
+
+        let alice_balance = Balances::total_balance(&ALICE);
+        connect_accounts(&ALICE, &alice_secret());
+
+        assert_ok!(UnifiedAccounts::unbind_evm_address(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountUnbound {
+                account_id: ALICE.clone(),
+                evm_address: alice_eth,
+            },
+        ));
+
+        // storage fee is refunded
+        assert_eq!(Balances::total_balance(&ALICE), alice_balance);
+
+        // make sure mappings are removed, defaults are used afterwards
+        assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth), None);
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), None);
+        assert_eq!(
+            <UnifiedAccounts as UnifiedAddressMapper<_>>::to_account_id_or_default(&alice_eth),
+            UnifiedAddress::Default(
+                <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth)
+            )
+        );
+
+        // can be claimed again
+        connect_accounts(&ALICE, &alice_secret());
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(alice_eth));
+    });
+}
+
+#[test]
+fn account_claim_with_contract_signature_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0xAA);
+        deploy_mock_smart_wallet(wallet);
+
+        let signature: BoundedVec<_, _> = UnifiedAccounts::build_signing_payload(&ALICE)
+            .to_vec()
+            .try_into()
+            .unwrap();
+        let post_info = UnifiedAccounts::claim_contract_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            wallet,
+            signature,
+        )
+        .unwrap();
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountClaimed {
+                account_id: ALICE.clone(),
+                evm_address: wallet,
+            },
+        ));
+
+        // unused weight of contract call is refunded
+        assert!(post_info.actual_weight.unwrap().any_lt(
+            <TestRuntime as Config>::WeightInfo::claim_evm_address()
+                .saturating_add(ContractSignatureWeightLimit::get())
+        ));
+
+        // check mappings
+        assert_eq!(EvmToNative::<TestRuntime>::get(wallet), Some(ALICE));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(wallet));
+    });
+}
+
+#[test]
+fn account_claim_with_contract_signature_should_not_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0xAA);
+        let bob_signature: BoundedVec<_, _> = UnifiedAccounts::build_signing_payload(&BOB)
+            .to_vec()
+            .try_into()
+            .unwrap();
+
+        // not a contract
+        assert_err_ignore_postinfo!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(BOB),
+                wallet,
+                bob_signature.clone()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+
+        deploy_mock_smart_wallet(wallet);
+
+        // signature rejected by the contract
+        assert_err_ignore_postinfo!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                wallet,
+                bob_signature.clone()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        assert_eq!(EvmToNative::<TestRuntime>::get(wallet), None);
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), None);
+
+        // already mapped evm address
+        assert_ok!(UnifiedAccounts::claim_contract_evm_address(
+            RuntimeOrigin::signed(BOB),
+            wallet,
+            bob_signature
+        ));
+        let alice_signature: BoundedVec<_, _> = UnifiedAccounts::build_signing_payload(&ALICE)
+            .to_vec()
+            .try_into()
+            .unwrap();
+        assert_noop!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                wallet,
+                alice_signature
+            ),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+    });
+}
+
+#[test]
+fn account_claim_with_state_modifying_contract_signature_should_not_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0xBB);
+        deploy_mock_stateful_smart_wallet(wallet);
+
+        // signature verification is a static call, so the contract can't write to its storage
+        let signature: BoundedVec<_, _> = UnifiedAccounts::build_signing_payload(&ALICE)
+            .to_vec()
+            .try_into()
+            .unwrap();
+        assert_err_ignore_postinfo!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                wallet,
+                signature
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        assert_eq!(
+            pallet_evm::AccountStorages::<TestRuntime>::get(wallet, H256::zero()),
+            H256::zero()
+        );
+        assert_eq!(EvmToNative::<TestRuntime>::get(wallet), None);
+    });
+}
+
+#[test]
+fn sweep_default_account_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let default_account_id =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // ALICE has no mapping yet
+        assert_noop!(
+            UnifiedAccounts::sweep_default_account(
+                RuntimeOrigin::signed(ALICE),
+                Default::default(),
+                false
+            ),
+            Error::<TestRuntime>::NotMapped
+        );
+
+        connect_accounts(&ALICE, &alice_secret());
+
+        // funds received by the default account after claiming
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            default_account_id.clone().into(),
+            1_000
+        ));
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            1,
+            BOB.into(),
+            true,
+            1
+        ));
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            2,
+            BOB.into(),
+            false,
+            1
+        ));
+        assert_ok!(<Assets as FungiblesMutate<_>>::mint_into(
+            1,
+            &default_account_id,
+            500
+        ));
+        assert_ok!(<Assets as FungiblesMutate<_>>::mint_into(
+            2,
+            &default_account_id,
+            300
+        ));
+
+        let alice_balance = Balances::total_balance(&ALICE);
+        // unknown asset 3 is ignored
+        assert_ok!(UnifiedAccounts::sweep_default_account(
+            RuntimeOrigin::signed(ALICE),
+            vec![1, 2, 3].try_into().unwrap(),
+            false
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::DefaultAccountSwept {
+                account_id: ALICE.clone(),
+                default_account_id: default_account_id.clone(),
+                native: 1_000,
+                assets: vec![(1, 500), (2, 300)],
+                staking_locks: 0,
+            },
+        ));
+
+        // everything is transferred and default account is reaped
+        assert_eq!(Balances::total_balance(&ALICE), alice_balance + 1_000);
+        assert_eq!(Assets::balance(1, &ALICE), 500);
+        assert_eq!(Assets::balance(2, &ALICE), 300);
+        assert_eq!(Assets::balance(1, &default_account_id), 0);
+        assert_eq!(Assets::balance(2, &default_account_id), 0);
+        assert!(!System::account_exists(&default_account_id));
+    });
+}
+
+#[test]
+fn claim_evm_address_and_sweep_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let default_account_id =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // funds received by the default account before claiming
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            default_account_id.clone().into(),
+            1_000
+        ));
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            1,
+            BOB.into(),
+            true,
+            1
+        ));
+        assert_ok!(<Assets as FungiblesMutate<_>>::mint_into(
+            1,
+            &default_account_id,
+            500
+        ));
+
+        assert_ok!(UnifiedAccounts::claim_evm_address_and_sweep(
+            RuntimeOrigin::signed(ALICE),
+            alice_eth,
+            get_evm_signature(&ALICE, &alice_secret()),
+            vec![1].try_into().unwrap(),
+            false
+        ));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), Some(alice_eth));
+        // native balance is migrated by the claim itself, only the assets are left for the sweep
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::DefaultAccountSwept {
+                account_id: ALICE.clone(),
+                default_account_id: default_account_id.clone(),
+                native: 0,
+                assets: vec![(1, 500)],
+                staking_locks: 0,
+            },
+        ));
+        assert_eq!(Assets::balance(1, &ALICE), 500);
+        assert!(!System::account_exists(&default_account_id));
+
+        // nothing is swept in case the claim fails
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address_and_sweep(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                get_evm_signature(&ALICE, &alice_secret()),
+                Default::default(),
+                false
+            ),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+    });
+}
+
+#[test]
+fn account_unbind_refunds_recorded_storage_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_balance = Balances::total_balance(&ALICE);
+        let issuance = Balances::total_issuance();
+        let fee = AccountMappingStorageFee::get();
+        connect_accounts(&ALICE, &alice_secret());
+        assert_eq!(StorageFeePaid::<TestRuntime>::get(&ALICE), fee);
+
+        // fee is raised after the mapping was created, only the paid fee is refunded
+        AccountMappingStorageFee::set(fee * 2);
+        assert_ok!(UnifiedAccounts::unbind_evm_address(RuntimeOrigin::signed(
+            ALICE
+        )));
+        assert_eq!(Balances::total_balance(&ALICE), alice_balance);
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert!(!StorageFeePaid::<TestRuntime>::contains_key(&ALICE));
+
+        // mapping without any recorded fee is not refunded
+        let bob_balance = Balances::total_balance(&BOB);
+        connect_accounts(&BOB, &bob_secret());
+        StorageFeePaid::<TestRuntime>::remove(&BOB);
+        assert_ok!(UnifiedAccounts::unbind_evm_address(RuntimeOrigin::signed(
+            BOB
+        )));
+        assert_eq!(Balances::total_balance(&BOB), bob_balance - fee * 2);
+    });
+}
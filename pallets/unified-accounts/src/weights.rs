@@ -50,6 +50,9 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn claim_evm_address() -> Weight;
 	fn claim_default_evm_address() -> Weight;
+	fn rebind_evm_address() -> Weight;
+	fn unbind_evm_address() -> Weight;
+	fn sweep_default_account(n: u32, ) -> Weight;
 	fn to_account_id() -> Weight;
 	fn to_account_id_or_default() -> Weight;
 	fn to_h160() -> Weight;
@@ -69,6 +72,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts StorageFeePaid (r:0 w:1)
+	/// Proof: UnifiedAccounts StorageFeePaid (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	fn claim_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `256`
@@ -76,12 +81,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 91_231_000 picoseconds.
 		Weight::from_parts(91_688_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts StorageFeePaid (r:0 w:1)
+	/// Proof: UnifiedAccounts StorageFeePaid (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	fn claim_default_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
@@ -89,7 +96,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 40_749_000 picoseconds.
 		Weight::from_parts(41_411_000, 3533)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:2)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn rebind_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `335`
+		//  Estimated: `3593`
+		// Minimum execution time: 93_412_000 picoseconds.
+		Weight::from_parts(94_035_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts StorageFeePaid (r:1 w:1)
+	/// Proof: UnifiedAccounts StorageFeePaid (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn unbind_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `237`
+		//  Estimated: `3593`
+		// Minimum execution time: 42_967_000 picoseconds.
+		Weight::from_parts(43_528_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:64 w:64)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:128 w:128)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn sweep_default_account(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `344 + n * (354 ±0)`
+		//  Estimated: `8799 + n * (5242 ±0)`
+		// Minimum execution time: 62_309_000 picoseconds.
+		Weight::from_parts(63_817_000, 8799)
+			// Standard Error: 28_417
+			.saturating_add(Weight::from_parts(41_762_934, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5242).saturating_mul(n.into()))
 	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
@@ -145,6 +211,8 @@ impl WeightInfo for () {
 	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts StorageFeePaid (r:0 w:1)
+	/// Proof: UnifiedAccounts StorageFeePaid (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	fn claim_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `256`
@@ -152,12 +220,14 @@ impl WeightInfo for () {
 		// Minimum execution time: 91_231_000 picoseconds.
 		Weight::from_parts(91_688_000, 3593)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts StorageFeePaid (r:0 w:1)
+	/// Proof: UnifiedAccounts StorageFeePaid (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	fn claim_default_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
@@ -165,7 +235,66 @@ impl WeightInfo for () {
 		// Minimum execution time: 40_749_000 picoseconds.
 		Weight::from_parts(41_411_000, 3533)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:2)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn rebind_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `335`
+		//  Estimated: `3593`
+		// Minimum execution time: 93_412_000 picoseconds.
+		Weight::from_parts(94_035_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts StorageFeePaid (r:1 w:1)
+	/// Proof: UnifiedAccounts StorageFeePaid (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn unbind_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `237`
+		//  Estimated: `3593`
+		// Minimum execution time: 42_967_000 picoseconds.
+		Weight::from_parts(43_528_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:64 w:64)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:128 w:128)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn sweep_default_account(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `344 + n * (354 ±0)`
+		//  Estimated: `8799 + n * (5242 ±0)`
+		// Minimum execution time: 62_309_000 picoseconds.
+		Weight::from_parts(63_817_000, 8799)
+			// Standard Error: 28_417
+			.saturating_add(Weight::from_parts(41_762_934, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5242).saturating_mul(n.into()))
 	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
@@ -0,0 +1,137 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_runtime::Weight;
+
+use astar_primitives::{
+    xvm::{CallResult, FailureReason, FailureRevert, VmId},
+    AccountId, Balance,
+};
+pub use pallet_contracts_primitives::StorageDeposit;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+/// Selector of the Solidity `Error(string)` revert.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Storage deposit of a call, decoupled from the `pallet-contracts` type.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageDepositInfo {
+    /// Amount refunded to the caller.
+    Refund(Balance),
+    /// Amount charged from the caller.
+    Charge(Balance),
+}
+
+impl From<StorageDeposit<Balance>> for StorageDepositInfo {
+    fn from(storage_deposit: StorageDeposit<Balance>) -> Self {
+        match storage_deposit {
+            StorageDeposit::Refund(amount) => Self::Refund(amount),
+            StorageDeposit::Charge(amount) => Self::Charge(amount),
+        }
+    }
+}
+
+/// Outcome of an XVM call dry-run.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct XvmCallEstimate {
+    /// Call output on success, or call failure otherwise. Both contain the actual used weight.
+    pub result: CallResult,
+    /// Revert reason if the callee reverted. The message is decoded if the revert data is
+    /// a Solidity `Error(string)`, otherwise the raw revert data is returned.
+    pub revert_reason: Option<Vec<u8>>,
+    /// Storage deposit charged or refunded by the call. Only applies to WASM calls.
+    pub storage_deposit: StorageDepositInfo,
+}
+
+impl XvmCallEstimate {
+    /// Create a new `XvmCallEstimate` from the dry-run call result & storage deposit.
+    pub fn new(result: CallResult, storage_deposit: StorageDeposit<Balance>) -> Self {
+        let revert_reason = match &result {
+            Err(failure) => match &failure.reason {
+                FailureReason::Revert(FailureRevert::VmRevert(data)) => {
+                    Some(decode_revert_reason(data).unwrap_or_else(|| data.clone()))
+                }
+                _ => None,
+            },
+            Ok(_) => None,
+        };
+
+        Self {
+            result,
+            revert_reason,
+            storage_deposit: storage_deposit.into(),
+        }
+    }
+}
+
+/// Decode the message of a Solidity `Error(string)` revert, if `data` is one.
+fn decode_revert_reason(data: &[u8]) -> Option<Vec<u8>> {
+    let encoded = data.strip_prefix(&ERROR_SELECTOR[..])?;
+
+    // Read the ABI encoded word at `index` as `usize`, if it fits.
+    let read_usize = |index: usize| -> Option<usize> {
+        let word = encoded.get(index..index.checked_add(32)?)?;
+        if word[..24].iter().any(|byte| *byte != 0) {
+            return None;
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&word[24..]);
+        usize::try_from(u64::from_be_bytes(bytes)).ok()
+    };
+
+    let offset = read_usize(0)?;
+    let len = read_usize(offset)?;
+    let start = offset.checked_add(32)?;
+    encoded
+        .get(start..start.checked_add(len)?)
+        .map(|message| message.to_vec())
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// XVM Api.
+    ///
+    /// Used to dry-run XVM calls, so the weight limit & storage deposit limit can be
+    /// estimated before sending the call.
+    pub trait XvmApi {
+
+        /// Dry-run an XVM call against the current state, with all state changes rolled back.
+        ///
+        /// The maximum block weight is used if `weight_limit` isn't provided.
+        fn estimate(
+            source_vm_id: VmId,
+            vm_id: VmId,
+            source: AccountId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+        ) -> XvmCallEstimate;
+    }
+}
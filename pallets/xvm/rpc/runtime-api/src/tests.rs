@@ -0,0 +1,112 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use astar_primitives::xvm::{CallFailure, CallOutput, FailureError};
+
+/// ABI encoded Solidity `Error(string)` revert with the given message.
+fn error_revert(message: &[u8]) -> Vec<u8> {
+    let mut offset = [0u8; 32];
+    offset[31] = 0x20;
+    let mut len = [0u8; 32];
+    len[24..].copy_from_slice(&(message.len() as u64).to_be_bytes());
+    let mut padded_message = message.to_vec();
+    padded_message.resize(message.len().div_ceil(32) * 32, 0);
+
+    [&ERROR_SELECTOR[..], &offset, &len, &padded_message].concat()
+}
+
+#[test]
+fn decode_revert_reason_works() {
+    assert_eq!(
+        decode_revert_reason(&error_revert(b"Not enough balance")),
+        Some(b"Not enough balance".to_vec())
+    );
+    assert_eq!(decode_revert_reason(&error_revert(b"")), Some(Vec::new()));
+
+    // Message spanning multiple words
+    let message = [b'x'; 70];
+    assert_eq!(
+        decode_revert_reason(&error_revert(&message)),
+        Some(message.to_vec())
+    );
+}
+
+#[test]
+fn decode_revert_reason_rejects_invalid_data() {
+    // Not an `Error(string)` revert
+    assert_eq!(decode_revert_reason(&[]), None);
+    assert_eq!(decode_revert_reason(&[0x4e, 0x48, 0x7b, 0x71]), None);
+    let mut data = error_revert(b"Panic");
+    data[0] = 0x4e;
+    assert_eq!(decode_revert_reason(&data), None);
+
+    // Truncated message
+    let data = error_revert(b"Not enough balance");
+    assert_eq!(decode_revert_reason(&data[..4 + 64 + 10]), None);
+
+    // Length doesn't fit into the data
+    let mut data = error_revert(b"Not enough balance");
+    data[4 + 63] = 0xff;
+    assert_eq!(decode_revert_reason(&data), None);
+
+    // Offset doesn't fit into `usize`
+    let mut data = error_revert(b"Not enough balance");
+    data[4] = 0x01;
+    assert_eq!(decode_revert_reason(&data), None);
+}
+
+#[test]
+fn call_estimate_decodes_revert_reason() {
+    let used_weight = Weight::from_parts(1_000, 100);
+
+    // Solidity `Error(string)` revert is decoded
+    let estimate = XvmCallEstimate::new(
+        Err(CallFailure::revert(
+            FailureRevert::VmRevert(error_revert(b"Not enough balance")),
+            used_weight,
+        )),
+        StorageDeposit::Charge(0),
+    );
+    assert_eq!(estimate.revert_reason, Some(b"Not enough balance".to_vec()));
+
+    // Any other revert data is returned as is
+    let estimate = XvmCallEstimate::new(
+        Err(CallFailure::revert(
+            FailureRevert::VmRevert(vec![1, 2, 3]),
+            used_weight,
+        )),
+        StorageDeposit::Charge(0),
+    );
+    assert_eq!(estimate.revert_reason, Some(vec![1, 2, 3]));
+
+    // No revert reason for errors or successful calls
+    let estimate = XvmCallEstimate::new(
+        Err(CallFailure::error(FailureError::InvalidVmId, used_weight)),
+        StorageDeposit::Charge(0),
+    );
+    assert_eq!(estimate.revert_reason, None);
+
+    let estimate = XvmCallEstimate::new(
+        Ok(CallOutput::new(vec![1], used_weight)),
+        StorageDeposit::Refund(10),
+    );
+    assert_eq!(estimate.revert_reason, None);
+    assert_eq!(estimate.storage_deposit, StorageDepositInfo::Refund(10));
+}
@@ -0,0 +1,172 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the XVM pallet.
+//!
+//! Calls are thin wrappers around the `XvmApi` runtime API,
+//! executed against the best block in case no block hash is provided.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{traits::Block as BlockT, Weight};
+
+use astar_primitives::{xvm::VmId, AccountId, Balance};
+pub use xvm_runtime_api::{StorageDepositInfo, XvmApi as XvmRuntimeApi, XvmCallEstimate};
+
+#[cfg(test)]
+mod tests;
+
+/// XVM call to be dry-run.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct XvmCallRequest {
+    /// The VM the call is made from.
+    pub source_vm_id: VmId,
+    /// The VM of the target contract.
+    pub vm_id: VmId,
+    /// Caller of the call.
+    pub source: AccountId,
+    /// Encoded target contract address.
+    pub target: Bytes,
+    /// Call input data.
+    pub input: Bytes,
+    /// Value to transfer, zero if not provided.
+    pub value: Option<NumberOrHex>,
+    /// Weight limit, the maximum block weight if not provided.
+    pub weight_limit: Option<Weight>,
+    /// Storage deposit limit for WASM calls.
+    pub storage_deposit_limit: Option<NumberOrHex>,
+}
+
+/// XVM RPC methods.
+#[rpc(client, server)]
+pub trait XvmApi<BlockHash> {
+    /// Dry-run an XVM call against the state of the specified block, returning the call result
+    /// with the used weight, the decoded revert reason & the storage deposit.
+    #[method(name = "xvm_estimate")]
+    fn estimate(
+        &self,
+        call_request: XvmCallRequest,
+        at: Option<BlockHash>,
+    ) -> RpcResult<XvmCallEstimate>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+    /// The provided call parameters are invalid.
+    InvalidParams,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::InvalidParams => 2,
+        }
+    }
+}
+
+/// Provides RPC methods to dry-run XVM calls.
+pub struct Xvm<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Xvm<C, Block> {
+    /// Creates a new instance of the XVM RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Converts a `NumberOrHex` balance, rejecting values which don't fit into `Balance`.
+fn try_into_balance(value: NumberOrHex) -> RpcResult<Balance> {
+    value.into_u256().try_into().map_err(|_| {
+        CallError::Custom(ErrorObject::owned(
+            Error::InvalidParams.into(),
+            "Balance value is too large.",
+            Some(format!("{:?}", value)),
+        ))
+        .into()
+    })
+}
+
+impl<C, Block> XvmApiServer<<Block as BlockT>::Hash> for Xvm<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: XvmRuntimeApi<Block>,
+{
+    fn estimate(
+        &self,
+        call_request: XvmCallRequest,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<XvmCallEstimate> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let XvmCallRequest {
+            source_vm_id,
+            vm_id,
+            source,
+            target,
+            input,
+            value,
+            weight_limit,
+            storage_deposit_limit,
+        } = call_request;
+        let value = value.map(try_into_balance).transpose()?.unwrap_or_default();
+        let storage_deposit_limit = storage_deposit_limit.map(try_into_balance).transpose()?;
+
+        api.estimate(
+            at_hash,
+            source_vm_id,
+            vm_id,
+            source,
+            target.to_vec(),
+            input.to_vec(),
+            value,
+            weight_limit,
+            storage_deposit_limit,
+        )
+        .map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to dry-run XVM call.",
+                Some(format!("{:?}", e)),
+            ))
+            .into()
+        })
+    }
+}
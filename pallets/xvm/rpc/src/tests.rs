@@ -0,0 +1,228 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use astar_primitives::xvm::{CallFailure, CallOutput, FailureRevert};
+use jsonrpsee::{core::Error as RpcError, RpcModule};
+use sp_api::{ApiError, ApiRef};
+use sp_blockchain::{BlockStatus, Info};
+use sp_core::{H256, U256};
+use sp_runtime::testing::{Block as RawBlock, ExtrinsicWrapper, Header};
+use xvm_runtime_api::StorageDeposit;
+
+type Block = RawBlock<ExtrinsicWrapper<u64>>;
+
+const BEST_HASH: H256 = H256::repeat_byte(0x01);
+const REVERTING_TARGET: [u8; 20] = [0xee; 20];
+
+/// ABI encoded Solidity `Error(string)` revert with a 32 bytes long message.
+fn error_revert(message: [u8; 32]) -> Vec<u8> {
+    let mut offset = [0u8; 32];
+    offset[31] = 0x20;
+    let mut len = [0u8; 32];
+    len[31] = 0x20;
+
+    [&[0x08, 0xc3, 0x79, 0xa0][..], &offset, &len, &message].concat()
+}
+
+/// Client, only aware of the best block.
+struct TestApi;
+
+struct TestRuntimeApi;
+
+impl ProvideRuntimeApi<Block> for TestApi {
+    type Api = TestRuntimeApi;
+
+    fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+        TestRuntimeApi.into()
+    }
+}
+
+impl HeaderBackend<Block> for TestApi {
+    fn header(&self, _hash: H256) -> sp_blockchain::Result<Option<Header>> {
+        Ok(None)
+    }
+
+    fn info(&self) -> Info<Block> {
+        Info {
+            best_hash: BEST_HASH,
+            best_number: 1,
+            genesis_hash: Default::default(),
+            finalized_hash: Default::default(),
+            finalized_number: 0,
+            finalized_state: None,
+            number_leaves: 1,
+            block_gap: None,
+        }
+    }
+
+    fn status(&self, _hash: H256) -> sp_blockchain::Result<BlockStatus> {
+        Ok(BlockStatus::Unknown)
+    }
+
+    fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<u64>> {
+        Ok(None)
+    }
+
+    fn hash(&self, _number: u64) -> sp_blockchain::Result<Option<H256>> {
+        Ok(None)
+    }
+}
+
+// Echoes the input as output, uses the whole weight limit & charges the value as storage deposit.
+// Calls to `REVERTING_TARGET` revert with a Solidity `Error(string)`.
+sp_api::mock_impl_runtime_apis! {
+    impl XvmRuntimeApi<Block> for TestRuntimeApi {
+        #[advanced]
+        fn estimate(
+            &self,
+            at: <Block as BlockT>::Hash,
+            _source_vm_id: VmId,
+            _vm_id: VmId,
+            _source: AccountId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+            _storage_deposit_limit: Option<Balance>,
+        ) -> Result<XvmCallEstimate, ApiError> {
+            if at != BEST_HASH {
+                return Err(ApiError::UnknownBlock(format!("{:?}", at)));
+            }
+
+            let used_weight = weight_limit.unwrap_or_default();
+            let result = if target == REVERTING_TARGET {
+                Err(CallFailure::revert(
+                    FailureRevert::VmRevert(error_revert([b'x'; 32])),
+                    used_weight,
+                ))
+            } else {
+                Ok(CallOutput::new(input, used_weight))
+            };
+
+            Ok(XvmCallEstimate::new(result, StorageDeposit::Charge(value)))
+        }
+    }
+}
+
+fn xvm_rpc() -> RpcModule<Xvm<TestApi, Block>> {
+    Xvm::new(Arc::new(TestApi)).into_rpc()
+}
+
+fn call_request(target: Vec<u8>) -> XvmCallRequest {
+    XvmCallRequest {
+        source_vm_id: VmId::Evm,
+        vm_id: VmId::Wasm,
+        source: AccountId::new([1; 32]),
+        target: target.into(),
+        input: vec![1, 2, 3].into(),
+        value: Some(NumberOrHex::Number(7)),
+        weight_limit: Some(Weight::from_parts(1_000, 100)),
+        storage_deposit_limit: None,
+    }
+}
+
+/// Asserts that the RPC call failed with the expected error code & message.
+fn assert_call_error(result: Result<XvmCallEstimate, RpcError>, code: Error, message: &str) {
+    match result {
+        Err(RpcError::Call(CallError::Custom(error))) => {
+            assert_eq!(error.code(), i32::from(code));
+            assert_eq!(error.message(), message);
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn estimate_works() {
+    let io = xvm_rpc();
+
+    let estimate: XvmCallEstimate = io
+        .call("xvm_estimate", (call_request(vec![0x42; 20]), None::<H256>))
+        .await
+        .unwrap();
+    assert_eq!(
+        estimate,
+        XvmCallEstimate {
+            result: Ok(CallOutput::new(
+                vec![1, 2, 3],
+                Weight::from_parts(1_000, 100)
+            )),
+            revert_reason: None,
+            storage_deposit: StorageDepositInfo::Charge(7),
+        }
+    );
+
+    // Best block is used if no block is provided, an unknown block results in a runtime error
+    let result = io
+        .call(
+            "xvm_estimate",
+            (call_request(vec![0x42; 20]), Some(H256::repeat_byte(0x02))),
+        )
+        .await;
+    assert_call_error(result, Error::RuntimeError, "Unable to dry-run XVM call.");
+}
+
+#[tokio::test]
+async fn estimate_decodes_revert_reason() {
+    let io = xvm_rpc();
+
+    let estimate: XvmCallEstimate = io
+        .call(
+            "xvm_estimate",
+            (call_request(REVERTING_TARGET.to_vec()), Some(BEST_HASH)),
+        )
+        .await
+        .unwrap();
+    assert!(estimate.result.is_err());
+    assert_eq!(estimate.revert_reason, Some([b'x'; 32].to_vec()));
+}
+
+#[tokio::test]
+async fn estimate_defaults_missing_values() {
+    let io = xvm_rpc();
+
+    let mut request = call_request(vec![0x42; 20]);
+    request.value = None;
+    request.weight_limit = None;
+    let estimate: XvmCallEstimate = io
+        .call("xvm_estimate", (request, None::<H256>))
+        .await
+        .unwrap();
+    assert_eq!(
+        estimate.result,
+        Ok(CallOutput::new(vec![1, 2, 3], Weight::zero()))
+    );
+    assert_eq!(estimate.storage_deposit, StorageDepositInfo::Charge(0));
+}
+
+#[tokio::test]
+async fn estimate_rejects_too_large_balances() {
+    let io = xvm_rpc();
+
+    let mut request = call_request(vec![0x42; 20]);
+    request.value = Some(NumberOrHex::Hex(U256::MAX));
+    let result = io.call("xvm_estimate", (request, None::<H256>)).await;
+    assert_call_error(result, Error::InvalidParams, "Balance value is too large.");
+
+    let mut request = call_request(vec![0x42; 20]);
+    request.storage_deposit_limit = Some(NumberOrHex::Hex(U256::from(u128::MAX) + 1));
+    let result = io.call("xvm_estimate", (request, None::<H256>)).await;
+    assert_call_error(result, Error::InvalidParams, "Balance value is too large.");
+}
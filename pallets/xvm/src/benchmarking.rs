@@ -19,7 +19,7 @@
 use super::*;
 
 use frame_benchmarking::v2::*;
-use frame_support::weights::Weight;
+use frame_support::{traits::fungible::Mutate, weights::Weight};
 use parity_scale_codec::Encode;
 use sp_core::H160;
 use sp_runtime::MultiAddress;
@@ -42,7 +42,14 @@ mod benchmarks {
         let source = whitelisted_caller();
         let target = H160::repeat_byte(1).encode();
         let input = vec![1, 2, 3];
-        let value = 1_000_000u128;
+        let value = T::Currency::minimum_balance();
+
+        // Fund the account mapped to the caller's EVM address.
+        let payer = T::AddressMapper::to_account_id_or_default(
+            &T::AddressMapper::to_h160_or_default(&source).into_address(),
+        )
+        .into_address();
+        let _ = T::Currency::set_balance(&payer, value * 2);
 
         #[block]
         {
@@ -61,7 +68,9 @@ mod benchmarks {
         let source = whitelisted_caller();
         let target = MultiAddress::<T::AccountId, ()>::Id(whitelisted_caller()).encode();
         let input = vec![1, 2, 3];
-        let value = 1_000_000u128;
+        let value = T::Currency::minimum_balance();
+
+        let _ = T::Currency::set_balance(&source, value * 2);
 
         #[block]
         {
@@ -28,6 +28,36 @@
 //! Together with other functionalities like Chain Extension and precompiles,
 //! the XVM pallet enables the runtime to support cross-VM calls.
 //!
+//! Cross-VM calls can be nested, e.g. EVM -> WASM -> EVM, up to `MaxCallDepth`
+//! levels. Each nested call is executed in its own call frame, and its weight
+//! limit cannot exceed the weight remaining in the enclosing frame, i.e. its weight
+//! limit reduced by the weight used by the previous nested calls.
+//!
+//! Events of the callee are propagated to the caller. WASM contract events emitted
+//! during a call from EVM are re-emitted as `WasmContractEmitted` events with the
+//! call context. EVM calls made from WASM are applied as separate Ethereum transactions via
+//! `EthereumTransact`, so their logs end up in the receipts of those transactions rather than
+//! being returned to the WASM caller. Read-only EVM calls are static, and can't emit logs.
+//!
+//! Native token value transfers follow the same rules for both VMs:
+//! - EVM values and native balances share the same unit, values passed from EVM are only
+//!   narrowed into `Balance`, and fail with `ValueOverflow` if they don't fit.
+//! - The account paying the value must be able to transfer it and stay alive, otherwise
+//!   the call fails with `InsufficientBalance`. For EVM calls, the paying account is the
+//!   one mapped to the caller's EVM address.
+//! - If the callee account doesn't exist, the value must be at least the existential
+//!   deposit, otherwise the call fails with `ValueBelowExistentialDeposit`.
+//! - All state changes of a failed call are rolled back, so the value is refunded to the caller.
+//!
+//! Contracts can also be queried in read-only mode. A query is executed like a call
+//! without value transfer, in a storage layer which is always rolled back, so none
+//! of the state changes made by the target VM, including nested calls, are persisted.
+//! EVM targets are executed as static calls, any state change fails the query. WASM
+//! targets are executed as dry-runs with enforced determinism.
+//!
+//! Calls can also be dry-run via `Pallet::estimate_call`, e.g. from runtime APIs, to
+//! estimate the used weight & storage deposit of a call before submitting it.
+//!
 //! ## Interface
 //!
 //! ### Implementation
@@ -41,20 +71,30 @@ extern crate alloc;
 use alloc::format;
 
 use fp_evm::ExitReason;
-use frame_support::{ensure, traits::fungible::Inspect, weights::Weight};
+use frame_support::{
+    ensure,
+    storage::{with_transaction, TransactionOutcome},
+    traits::{
+        fungible::Inspect,
+        tokens::{Fortitude, Preservation},
+        Get,
+    },
+    weights::Weight,
+};
 use pallet_contracts::{CollectEvents, DebugInfo, Determinism};
-use pallet_contracts_primitives::ReturnFlags;
+use pallet_contracts_primitives::{ReturnFlags, StorageDeposit};
 use pallet_evm::GasWeightMapping;
 use parity_scale_codec::Decode;
 use sp_core::{H160, U256};
+use sp_runtime::{traits::Saturating, DispatchError};
 use sp_std::{marker::PhantomData, prelude::*};
 
 use astar_primitives::{
     ethereum_checked::{CheckedEthereumTransact, CheckedEthereumTx, EthereumTxInput},
     evm::UnifiedAddressMapper,
     xvm::{
-        CallFailure, CallOutput, CallResult, Context, FailureError::*, FailureRevert::*, VmId,
-        XvmCall,
+        CallFailure, CallOutput, CallResult, Context, FailureError, FailureError::*,
+        FailureRevert::*, VmId, XvmCall,
     },
     Balance,
 };
@@ -72,17 +112,56 @@ pub use pallet::*;
 
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
 
-environmental::thread_local_impl!(static IN_XVM: environmental::RefCell<bool> = environmental::RefCell::new(false));
+pub type EventRecordOf<T> = frame_system::EventRecord<
+    <T as frame_system::Config>::RuntimeEvent,
+    <T as frame_system::Config>::Hash,
+>;
+
+/// An ongoing XVM call frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CallFrame {
+    /// Weight limit of the call, capped by the remaining weight of the enclosing frame.
+    pub(crate) weight_limit: Weight,
+    /// Weight used by the nested XVM calls made so far.
+    pub(crate) nested_used_weight: Weight,
+    /// Ranges `[start, end)` of the events deposited by the successful nested XVM calls.
+    pub(crate) nested_events: Vec<(u32, u32)>,
+}
+
+impl CallFrame {
+    pub(crate) fn new(weight_limit: Weight) -> Self {
+        Self {
+            weight_limit,
+            nested_used_weight: Weight::zero(),
+            nested_events: Vec::new(),
+        }
+    }
+
+    /// Weight left for the next nested XVM call.
+    pub(crate) fn remaining_weight(&self) -> Weight {
+        self.weight_limit.saturating_sub(self.nested_used_weight)
+    }
+}
+
+// Ongoing XVM call frames, the last one being the innermost call.
+environmental::thread_local_impl!(static XVM_CALL_FRAMES: environmental::RefCell<Vec<CallFrame>> = environmental::RefCell::new(Vec::new()));
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use frame_support::traits::IsType;
 
     #[pallet::pallet]
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_contracts::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>
+            + TryInto<Event<Self>>
+            + TryInto<pallet_contracts::Event<Self>>;
+
         /// Mapping from `Account` to `H160`.
         type AddressMapper: UnifiedAddressMapper<Self::AccountId>;
 
@@ -92,9 +171,28 @@ pub mod pallet {
         /// `CheckedEthereumTransact` implementation.
         type EthereumTransact: CheckedEthereumTransact;
 
+        /// Max depth of nested XVM calls.
+        #[pallet::constant]
+        type MaxCallDepth: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
+
+    #[pallet::event]
+    pub enum Event<T: Config> {
+        /// A WASM contract emitted an event during an XVM call from EVM.
+        WasmContractEmitted {
+            /// Caller of the XVM call.
+            caller: T::AccountId,
+            /// Target contract of the XVM call.
+            target: T::AccountId,
+            /// The contract that emitted the event, the target or one of the contracts it called.
+            contract: T::AccountId,
+            /// Data supplied by the contract.
+            data: Vec<u8>,
+        },
+    }
 }
 
 impl<T> XvmCall<T::AccountId> for Pallet<T>
@@ -111,7 +209,8 @@ where
         value: Balance,
         storage_deposit_limit: Option<Balance>,
     ) -> CallResult {
-        Pallet::<T>::do_call(
+        let event_count = frame_system::Pallet::<T>::event_count();
+        let res = Pallet::<T>::do_call(
             context,
             vm_id,
             source,
@@ -120,7 +219,33 @@ where
             value,
             storage_deposit_limit,
             false,
+            false,
         )
+        .0;
+
+        // Events of a successful call were handled in its own frame, they must be skipped
+        // by the enclosing frame.
+        if res.is_ok() {
+            XVM_CALL_FRAMES.with(|frames| {
+                if let Some(frame) = frames.borrow_mut().last_mut() {
+                    frame
+                        .nested_events
+                        .push((event_count, frame_system::Pallet::<T>::event_count()));
+                }
+            });
+        }
+
+        res
+    }
+
+    fn query(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+    ) -> CallResult {
+        Pallet::<T>::do_query(context, vm_id, source, target, input)
     }
 }
 
@@ -137,50 +262,180 @@ where
         input: Vec<u8>,
         value: Balance,
         storage_deposit_limit: Option<Balance>,
+        read_only: bool,
         skip_execution: bool,
-    ) -> CallResult {
+    ) -> (CallResult, StorageDeposit<Balance>) {
         let overheads = match vm_id {
             VmId::Evm => WeightInfoOf::<T>::evm_call_overheads(),
             VmId::Wasm => WeightInfoOf::<T>::wasm_call_overheads(),
         };
 
-        ensure!(
-            context.source_vm_id != vm_id,
-            CallFailure::error(SameVmCallDenied, overheads)
-        );
-
-        // Set `IN_XVM` to true & check reentrance.
-        if IN_XVM.with(|in_xvm| in_xvm.replace(true)) {
-            return Err(CallFailure::error(ReentranceDenied, overheads));
+        if context.source_vm_id == vm_id {
+            return (
+                Err(CallFailure::error(SameVmCallDenied, overheads)),
+                Default::default(),
+            );
         }
 
-        let res = match vm_id {
-            VmId::Evm => Pallet::<T>::evm_call(
-                context,
-                source,
-                target,
-                input,
-                value,
+        // Enter a new call frame & check the call depth. The weight limit of a nested
+        // call is capped by the weight remaining in the enclosing frame.
+        let weight_limit = XVM_CALL_FRAMES.with(|frames| {
+            let mut frames = frames.borrow_mut();
+            if frames.len() >= T::MaxCallDepth::get() as usize {
+                return None;
+            }
+            let weight_limit = match frames.last() {
+                Some(parent) => context.weight_limit.min(parent.remaining_weight()),
+                None => context.weight_limit,
+            };
+            frames.push(CallFrame::new(weight_limit));
+            Some(weight_limit)
+        });
+        let context = match weight_limit {
+            Some(weight_limit) => Context {
+                weight_limit,
+                ..context
+            },
+            None => {
+                return (
+                    Err(CallFailure::error(CallDepthLimitReached, overheads)),
+                    Default::default(),
+                )
+            }
+        };
+
+        // Roll back all the state changes of a failed call, including the value transfer.
+        let res = with_transaction(|| {
+            // Storage deposit only applies to WASM calls.
+            let res = match vm_id {
+                VmId::Evm => (
+                    Pallet::<T>::evm_call(
+                        context,
+                        source,
+                        target,
+                        input,
+                        value,
+                        overheads,
+                        read_only,
+                        skip_execution,
+                    ),
+                    Default::default(),
+                ),
+                VmId::Wasm => Pallet::<T>::wasm_call(
+                    context,
+                    source,
+                    target,
+                    input,
+                    value,
+                    overheads,
+                    storage_deposit_limit,
+                    read_only,
+                    skip_execution,
+                ),
+            };
+            if res.0.is_ok() {
+                TransactionOutcome::Commit(Ok::<_, DispatchError>(res))
+            } else {
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(res))
+            }
+        })
+        .unwrap_or_else(|e| {
+            (
+                Err(CallFailure::error(
+                    VmError(format!("XVM call error: {:?}", e).into()),
+                    overheads,
+                )),
+                Default::default(),
+            )
+        });
+
+        // Exit the call frame, and account its used weight in the enclosing frame.
+        // We should make sure that this line is executed whatever the execution path.
+        let used_weight = match &res.0 {
+            Ok(output) => output.used_weight,
+            Err(failure) => failure.used_weight,
+        };
+        XVM_CALL_FRAMES.with(|frames| {
+            let mut frames = frames.borrow_mut();
+            frames.pop();
+            if let Some(parent) = frames.last_mut() {
+                parent.nested_used_weight.saturating_accrue(used_weight);
+            }
+        });
+
+        res
+    }
+
+    fn do_query(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+    ) -> CallResult {
+        let overheads = match vm_id {
+            VmId::Evm => WeightInfoOf::<T>::evm_call_overheads(),
+            VmId::Wasm => WeightInfoOf::<T>::wasm_call_overheads(),
+        };
+
+        // Execute the query in a new storage layer, and always roll it back. EVM calls are
+        // executed as static calls, and WASM calls as dry-runs.
+        with_transaction(|| {
+            let res =
+                Pallet::<T>::do_call(context, vm_id, source, target, input, 0, None, true, false).0;
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(res))
+        })
+        .unwrap_or_else(|e| {
+            Err(CallFailure::error(
+                VmError(format!("XVM query error: {:?}", e).into()),
                 overheads,
-                skip_execution,
-            ),
-            VmId::Wasm => Pallet::<T>::wasm_call(
+            ))
+        })
+    }
+
+    /// Dry-run an XVM call against the current state.
+    ///
+    /// The call is fully executed, including nested calls, in a new storage layer which is
+    /// always rolled back. Returns the call result, with the actual used weight, and the
+    /// storage deposit charged or refunded by the call.
+    pub fn estimate_call(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+        value: Balance,
+        storage_deposit_limit: Option<Balance>,
+    ) -> (CallResult, StorageDeposit<Balance>) {
+        let overheads = match vm_id {
+            VmId::Evm => WeightInfoOf::<T>::evm_call_overheads(),
+            VmId::Wasm => WeightInfoOf::<T>::wasm_call_overheads(),
+        };
+
+        // Execute the call in a new storage layer, and always roll it back.
+        with_transaction(|| {
+            let res = Pallet::<T>::do_call(
                 context,
+                vm_id,
                 source,
                 target,
                 input,
                 value,
-                overheads,
                 storage_deposit_limit,
-                skip_execution,
-            ),
-        };
-
-        // Set `IN_XVM` to false.
-        // We should make sure that this line is executed whatever the execution path.
-        let _ = IN_XVM.with(|in_xvm| in_xvm.take());
-
-        res
+                false,
+                false,
+            );
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(res))
+        })
+        .unwrap_or_else(|e| {
+            (
+                Err(CallFailure::error(
+                    VmError(format!("XVM dry-run error: {:?}", e).into()),
+                    overheads,
+                )),
+                Default::default(),
+            )
+        })
     }
 
     fn evm_call(
@@ -190,6 +445,7 @@ where
         input: Vec<u8>,
         value: Balance,
         overheads: Weight,
+        read_only: bool,
         skip_execution: bool,
     ) -> CallResult {
         log::trace!(
@@ -207,12 +463,21 @@ where
         let bounded_input = EthereumTxInput::try_from(input)
             .map_err(|_| CallFailure::revert(InputTooLarge, overheads))?;
 
+        // The value is paid by, and received by, the accounts mapped to the EVM addresses.
+        let source = T::AddressMapper::to_h160_or_default(&source).into_address();
+        Pallet::<T>::ensure_value_transferable(
+            &T::AddressMapper::to_account_id_or_default(&source).into_address(),
+            &T::AddressMapper::to_account_id_or_default(&target_decoded).into_address(),
+            value,
+        )
+        .map_err(|e| CallFailure::error(e, overheads))?;
+
+        // Native balance & EVM value are in the same unit, widening is lossless.
         let value_u256 = U256::from(value);
         // With overheads, less weight is available.
         let weight_limit = context.weight_limit.saturating_sub(overheads);
         let gas_limit = U256::from(T::GasWeightMapping::weight_to_gas(weight_limit));
 
-        let source = T::AddressMapper::to_h160_or_default(&source).into_address();
         let tx = CheckedEthereumTx {
             gas_limit,
             target: target_decoded,
@@ -227,7 +492,14 @@ where
             return Ok(CallOutput::new(vec![], overheads));
         }
 
-        let transact_result = T::EthereumTransact::xvm_transact(source, tx);
+        // Read-only calls are static, any state change fails the call.
+        let transact_result = if read_only {
+            T::EthereumTransact::xvm_static_call(source, tx)
+        } else {
+            T::EthereumTransact::xvm_transact(source, tx)
+        };
+        // EVM logs aren't returned to the caller. Transactions applied via `xvm_transact` record them in
+        // their own Ethereum receipts, while static calls can't emit any.
         log::trace!(
             target: "xvm::evm_call",
             "EVM call result: {:?}", transact_result,
@@ -278,18 +550,27 @@ where
         value: Balance,
         overheads: Weight,
         storage_deposit_limit: Option<Balance>,
+        read_only: bool,
         skip_execution: bool,
-    ) -> CallResult {
+    ) -> (CallResult, StorageDeposit<Balance>) {
         log::trace!(
             target: "xvm::wasm_call",
             "Calling WASM: {:?} {:?}, {:?}, {:?}, {:?}, {:?}",
             context, source, target, input, value, storage_deposit_limit,
         );
 
-        let dest = {
-            let error = CallFailure::revert(InvalidTarget, overheads);
-            Decode::decode(&mut target.as_ref()).map_err(|_| error.clone())
-        }?;
+        let dest = match Decode::decode(&mut target.as_ref()) {
+            Ok(dest) => dest,
+            Err(_) => {
+                return (
+                    Err(CallFailure::revert(InvalidTarget, overheads)),
+                    Default::default(),
+                )
+            }
+        };
+        if let Err(e) = Pallet::<T>::ensure_value_transferable(&source, &dest, value) {
+            return (Err(CallFailure::error(e, overheads)), Default::default());
+        }
 
         // With overheads, less weight is available.
         let weight_limit = context.weight_limit.saturating_sub(overheads);
@@ -297,12 +578,13 @@ where
         // Note the skip execution check should be exactly before `pallet_contracts::bare_call`
         // to benchmark the correct overheads.
         if skip_execution {
-            return Ok(CallOutput::new(vec![], overheads));
+            return (Ok(CallOutput::new(vec![], overheads)), Default::default());
         }
 
+        let event_count = frame_system::Pallet::<T>::event_count();
         let call_result = pallet_contracts::Pallet::<T>::bare_call(
-            source,
-            dest,
+            source.clone(),
+            dest.clone(),
             value,
             weight_limit,
             storage_deposit_limit,
@@ -314,19 +596,115 @@ where
         log::trace!(target: "xvm::wasm_call", "WASM call result: {:?}", call_result);
 
         let used_weight = call_result.gas_consumed.saturating_add(overheads);
-        match call_result.result {
+        let res = match call_result.result {
             Ok(val) => {
                 if val.flags.contains(ReturnFlags::REVERT) {
                     Err(CallFailure::revert(VmRevert(val.data), used_weight))
-                } else {
+                } else if read_only {
+                    // Read-only calls are dry-runs, their events are discarded.
                     Ok(CallOutput::new(val.data, used_weight))
+                } else {
+                    let events_weight =
+                        Pallet::<T>::deposit_wasm_contract_events(event_count, &source, &dest);
+                    Ok(CallOutput::new(
+                        val.data,
+                        used_weight.saturating_add(events_weight),
+                    ))
                 }
             }
             Err(error) => Err(CallFailure::error(
                 VmError(format!("WASM call error: {:?}", error).into()),
                 used_weight,
             )),
+        };
+        (res, call_result.storage_deposit)
+    }
+
+    /// Ensure `value` can be transferred from `payer` to `payee`.
+    ///
+    /// The payer must stay alive after the transfer, and the value must be at least the
+    /// existential deposit if the payee account doesn't exist yet.
+    fn ensure_value_transferable(
+        payer: &T::AccountId,
+        payee: &T::AccountId,
+        value: Balance,
+    ) -> Result<(), FailureError> {
+        if value == 0 {
+            return Ok(());
+        }
+
+        let transferable =
+            T::Currency::reducible_balance(payer, Preservation::Preserve, Fortitude::Polite);
+        ensure!(transferable >= value, InsufficientBalance);
+        ensure!(
+            T::Currency::total_balance(payee) > 0 || value >= T::Currency::minimum_balance(),
+            ValueBelowExistentialDeposit
+        );
+
+        Ok(())
+    }
+
+    /// Read the event records deposited after the first `event_count` events of the block.
+    fn read_events_since(event_count: u32) -> Vec<EventRecordOf<T>> {
+        let new_events = frame_system::Pallet::<T>::event_count().saturating_sub(event_count);
+        if new_events == 0 {
+            return Vec::new();
         }
+
+        frame_system::Pallet::<T>::read_events_no_consensus()
+            .skip(event_count as usize)
+            .take(new_events as usize)
+            .map(|record| *record)
+            .collect()
+    }
+
+    /// Re-emit the WASM contract events emitted after the first `event_count` events of the block,
+    /// with the XVM call context.
+    /// Returns the weight of reading and re-emitting the events.
+    ///
+    /// Events deposited by the successful nested XVM calls of the current frame are skipped,
+    /// as their contract events were already re-emitted with the nested call context.
+    fn deposit_wasm_contract_events(
+        event_count: u32,
+        caller: &T::AccountId,
+        target: &T::AccountId,
+    ) -> Weight {
+        let nested_events = XVM_CALL_FRAMES.with(|frames| {
+            frames
+                .borrow()
+                .last()
+                .map(|frame| frame.nested_events.clone())
+                .unwrap_or_default()
+        });
+
+        let mut deposited = 0_u64;
+        for (index, record) in (event_count..).zip(Self::read_events_since(event_count)) {
+            if nested_events
+                .iter()
+                .any(|(start, end)| (*start..*end).contains(&index))
+            {
+                continue;
+            }
+
+            let event = <T as Config>::RuntimeEvent::from(record.event);
+            if let Ok(pallet_contracts::Event::<T>::ContractEmitted { contract, data }) =
+                TryInto::<pallet_contracts::Event<T>>::try_into(event)
+            {
+                let event = Event::<T>::WasmContractEmitted {
+                    caller: caller.clone(),
+                    target: target.clone(),
+                    contract,
+                    data,
+                };
+                frame_system::Pallet::<T>::deposit_event_indexed(
+                    &record.topics,
+                    <T as Config>::RuntimeEvent::from(event).into(),
+                );
+                deposited.saturating_inc();
+            }
+        }
+
+        T::DbWeight::get().reads_writes(1, deposited)
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -347,7 +725,9 @@ where
             input,
             value,
             storage_deposit_limit,
+            false,
             true,
         )
+        .0
     }
 }
@@ -134,6 +134,7 @@ impl pallet_contracts::Config for TestRuntime {
 
 thread_local! {
     static TRANSACTED: RefCell<Option<(H160, CheckedEthereumTx)>> = RefCell::new(None);
+    static STATIC_CALLED: RefCell<Option<(H160, CheckedEthereumTx)>> = RefCell::new(None);
 }
 
 pub struct MockEthereumTransact;
@@ -142,13 +143,14 @@ impl MockEthereumTransact {
         let transacted = TRANSACTED.with(|v| v.borrow().clone());
         assert_eq!(transacted, Some((source, checked_tx)));
     }
-}
-impl CheckedEthereumTransact for MockEthereumTransact {
-    fn xvm_transact(
-        source: H160,
-        checked_tx: CheckedEthereumTx,
-    ) -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
-        TRANSACTED.with(|v| *v.borrow_mut() = Some((source, checked_tx)));
+
+    pub(crate) fn assert_static_called(source: H160, checked_tx: CheckedEthereumTx) {
+        let static_called = STATIC_CALLED.with(|v| v.borrow().clone());
+        assert_eq!(static_called, Some((source, checked_tx)));
+        assert_eq!(TRANSACTED.with(|v| v.borrow().clone()), None);
+    }
+
+    fn call_result() -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
         Ok((
             PostDispatchInfo {
                 actual_weight: Default::default(),
@@ -167,6 +169,23 @@ impl CheckedEthereumTransact for MockEthereumTransact {
         ))
     }
 }
+impl CheckedEthereumTransact for MockEthereumTransact {
+    fn xvm_transact(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
+        TRANSACTED.with(|v| *v.borrow_mut() = Some((source, checked_tx)));
+        Self::call_result()
+    }
+
+    fn xvm_static_call(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
+        STATIC_CALLED.with(|v| *v.borrow_mut() = Some((source, checked_tx)));
+        Self::call_result()
+    }
+}
 
 pub struct MockGasWeightMapping;
 impl GasWeightMapping for MockGasWeightMapping {
@@ -179,9 +198,11 @@ impl GasWeightMapping for MockGasWeightMapping {
 }
 
 impl pallet_xvm::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
     type GasWeightMapping = MockGasWeightMapping;
     type AddressMapper = HashedDefaultMappings<BlakeTwo256>;
     type EthereumTransact = MockEthereumTransact;
+    type MaxCallDepth = ConstU32<4>;
     type WeightInfo = weights::SubstrateWeight<TestRuntime>;
 }
 
@@ -201,6 +222,13 @@ construct_runtime!(
 );
 
 pub(crate) const ALICE: AccountId = AccountId32::new([0u8; 32]);
+pub(crate) const INITIAL_BALANCE: Balance = 1_000_000_000;
+
+/// The account mapped to ALICE's default EVM address, paying the value of ALICE's EVM calls.
+pub(crate) fn alice_evm_account() -> AccountId {
+    let evm_address = HashedDefaultMappings::<BlakeTwo256>::to_default_h160(&ALICE);
+    HashedDefaultMappings::<BlakeTwo256>::to_default_account_id(&evm_address)
+}
 
 #[derive(Default)]
 pub struct ExtBuilder;
@@ -210,9 +238,17 @@ impl ExtBuilder {
     pub fn build(self) -> TestExternalities {
         TRANSACTED.with(|v| *v.borrow_mut() = None);
 
-        let t = frame_system::GenesisConfig::<TestRuntime>::default()
+        let mut t = frame_system::GenesisConfig::<TestRuntime>::default()
             .build_storage()
             .unwrap();
+        pallet_balances::GenesisConfig::<TestRuntime> {
+            balances: vec![
+                (ALICE, INITIAL_BALANCE),
+                (alice_evm_account(), INITIAL_BALANCE),
+            ],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
 
         let mut ext = TestExternalities::from(t);
         ext.execute_with(|| {
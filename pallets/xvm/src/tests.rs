@@ -21,9 +21,11 @@
 use super::*;
 use mock::*;
 
+use astar_primitives::xvm::balance_from_evm_value;
 use frame_support::{assert_noop, assert_ok, weights::Weight};
 use parity_scale_codec::Encode;
 use sp_core::H160;
+use sp_runtime::AccountId32;
 
 #[test]
 fn calling_into_same_vm_is_not_allowed() {
@@ -174,6 +176,214 @@ fn evm_call_works() {
     });
 }
 
+#[test]
+fn evm_call_fails_if_insufficient_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF);
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::evm_call_overheads();
+
+        // The caller must stay alive after the transfer.
+        assert_noop!(
+            Xvm::call(
+                context,
+                VmId::Evm,
+                ALICE,
+                target.encode(),
+                vec![],
+                INITIAL_BALANCE,
+                None
+            ),
+            CallFailure::error(InsufficientBalance, used_weight),
+        );
+    });
+}
+
+#[test]
+fn evm_call_fails_if_value_below_existential_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF);
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::evm_call_overheads();
+
+        assert_noop!(
+            Xvm::call(context, VmId::Evm, ALICE, target.encode(), vec![], 1, None),
+            CallFailure::error(ValueBelowExistentialDeposit, used_weight),
+        );
+    });
+}
+
+#[test]
+fn wasm_call_fails_if_insufficient_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let source = AccountId32::new([1u8; 32]);
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::wasm_call_overheads();
+
+        assert_noop!(
+            Xvm::call(
+                context,
+                VmId::Wasm,
+                source,
+                ALICE.encode(),
+                vec![],
+                1_000,
+                None
+            ),
+            CallFailure::error(InsufficientBalance, used_weight),
+        );
+    });
+}
+
+#[test]
+fn evm_value_conversion_works() {
+    assert_eq!(balance_from_evm_value(U256::from(1_000_000)), Ok(1_000_000));
+    assert_eq!(
+        balance_from_evm_value(U256::from(Balance::MAX)),
+        Ok(Balance::MAX)
+    );
+    assert_eq!(
+        balance_from_evm_value(U256::from(Balance::MAX) + 1),
+        Err(ValueOverflow)
+    );
+}
+
+#[test]
+fn evm_query_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let vm_id = VmId::Evm;
+        let target = H160::repeat_byte(0xFF);
+        let input = vec![1; 65_536];
+
+        assert_ok!(Xvm::query(
+            context,
+            vm_id,
+            ALICE,
+            target.encode(),
+            input.clone()
+        ));
+        let source = Decode::decode(
+            &mut hex::decode("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9")
+                .expect("invalid source hex")
+                .as_ref(),
+        )
+        .expect("invalid source");
+        MockEthereumTransact::assert_static_called(
+            source,
+            CheckedEthereumTx {
+                gas_limit: U256::from(246000),
+                target: H160::repeat_byte(0xFF),
+                value: U256::zero(),
+                input: EthereumTxInput::try_from(input).expect("input too large"),
+                maybe_access_list: None,
+            },
+        );
+    });
+}
+
+#[test]
+fn query_into_same_vm_is_not_allowed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::wasm_call_overheads();
+
+        assert_noop!(
+            Xvm::query(context, VmId::Wasm, ALICE, ALICE.encode(), vec![1, 2, 3]),
+            CallFailure::error(SameVmCallDenied, used_weight,),
+        );
+    });
+}
+
+#[test]
+fn evm_estimate_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let vm_id = VmId::Evm;
+        let target = H160::repeat_byte(0xFF);
+        let input = vec![1; 65_536];
+        let value = 1_000_000u128;
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::evm_call_overheads();
+
+        assert_eq!(
+            Xvm::estimate_call(
+                context,
+                vm_id,
+                ALICE,
+                target.encode(),
+                input.clone(),
+                value,
+                None
+            ),
+            (
+                Ok(CallOutput::new(vec![], used_weight)),
+                StorageDeposit::Charge(0)
+            ),
+        );
+        let source = Decode::decode(
+            &mut hex::decode("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9")
+                .expect("invalid source hex")
+                .as_ref(),
+        )
+        .expect("invalid source");
+        MockEthereumTransact::assert_transacted(
+            source,
+            CheckedEthereumTx {
+                gas_limit: U256::from(246000),
+                target: H160::repeat_byte(0xFF),
+                value: U256::from(value),
+                input: EthereumTxInput::try_from(input).expect("input too large"),
+                maybe_access_list: None,
+            },
+        );
+    });
+}
+
+#[test]
+fn wasm_estimate_call_returns_failure() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::wasm_call_overheads();
+
+        assert_eq!(
+            Xvm::estimate_call(
+                context,
+                VmId::Wasm,
+                ALICE,
+                vec![1, 2, 3].encode(),
+                vec![1, 2, 3],
+                1_000_000,
+                None
+            ),
+            (
+                Err(CallFailure::revert(InvalidTarget, used_weight)),
+                StorageDeposit::Charge(0)
+            ),
+        );
+    });
+}
+
 #[test]
 fn wasm_call_fails_if_invalid_target() {
     ExtBuilder::default().build().execute_with(|| {
@@ -193,3 +403,143 @@ fn wasm_call_fails_if_invalid_target() {
         );
     });
 }
+
+#[test]
+fn call_fails_if_max_call_depth_reached() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let vm_id = VmId::Evm;
+        let target = H160::repeat_byte(0xFF);
+        let input = vec![1, 2, 3];
+        let value = 1_000_000u128;
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::evm_call_overheads();
+
+        // Enter max depth call frames.
+        let max_call_depth = <TestRuntime as Config>::MaxCallDepth::get() as usize;
+        XVM_CALL_FRAMES.with(|frames| {
+            *frames.borrow_mut() = vec![CallFrame::new(context.weight_limit); max_call_depth];
+        });
+
+        assert_noop!(
+            Xvm::call(context, vm_id, ALICE, target.encode(), input, value, None),
+            CallFailure::error(CallDepthLimitReached, used_weight,),
+        );
+        assert_eq!(
+            XVM_CALL_FRAMES.with(|frames| frames.borrow().len()),
+            max_call_depth
+        );
+
+        XVM_CALL_FRAMES.with(|frames| frames.borrow_mut().clear());
+    });
+}
+
+#[test]
+fn nested_call_weight_limit_is_capped_by_enclosing_frame_remaining_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let vm_id = VmId::Evm;
+        let target = H160::repeat_byte(0xFF);
+        let input = vec![1, 2, 3];
+        let value = 1_000_000u128;
+
+        // Enter an enclosing call frame with the same weight limit, part of which
+        // has already been used by the previous nested calls.
+        let previously_used_weight = Weight::from_parts(100_000, 100_000);
+        XVM_CALL_FRAMES.with(|frames| {
+            frames.borrow_mut().push(CallFrame {
+                nested_used_weight: previously_used_weight,
+                ..CallFrame::new(context.weight_limit)
+            })
+        });
+
+        let used_weight = Xvm::call(
+            context.clone(),
+            vm_id,
+            ALICE,
+            target.encode(),
+            input.clone(),
+            value,
+            None,
+        )
+        .expect("nested call should succeed")
+        .used_weight;
+        let source = Decode::decode(
+            &mut hex::decode("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9")
+                .expect("invalid source hex")
+                .as_ref(),
+        )
+        .expect("invalid source");
+        MockEthereumTransact::assert_transacted(
+            source,
+            CheckedEthereumTx {
+                gas_limit: U256::from(146000),
+                target: H160::repeat_byte(0xFF),
+                value: U256::from(value),
+                input: EthereumTxInput::try_from(input).expect("input too large"),
+                maybe_access_list: None,
+            },
+        );
+
+        // Nested call frame is exited, and its used weight is accounted in the enclosing frame.
+        XVM_CALL_FRAMES.with(|frames| {
+            let frames = frames.borrow();
+            assert_eq!(frames.len(), 1);
+            assert_eq!(
+                frames[0].nested_used_weight,
+                previously_used_weight + used_weight
+            );
+            assert_eq!(
+                frames[0].remaining_weight(),
+                context.weight_limit - previously_used_weight - used_weight
+            );
+        });
+
+        XVM_CALL_FRAMES.with(|frames| frames.borrow_mut().clear());
+    });
+}
+
+#[test]
+fn successful_nested_call_events_are_skipped_by_enclosing_frame() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let vm_id = VmId::Evm;
+        let target = H160::repeat_byte(0xFF);
+        let input = vec![1, 2, 3];
+
+        // Enter an enclosing call frame.
+        XVM_CALL_FRAMES.with(|frames| {
+            frames
+                .borrow_mut()
+                .push(CallFrame::new(context.weight_limit))
+        });
+
+        let event_count = System::event_count();
+        assert_ok!(Xvm::call(
+            context.clone(),
+            vm_id,
+            ALICE,
+            target.encode(),
+            input.clone(),
+            0,
+            None
+        ));
+        // Failed nested calls are rolled back, no events to skip.
+        assert!(Xvm::call(context, vm_id, ALICE, vec![1, 2, 3], input, 0, None).is_err());
+
+        assert_eq!(
+            XVM_CALL_FRAMES.with(|frames| frames.borrow()[0].nested_events.clone()),
+            vec![(event_count, System::event_count())]
+        );
+
+        XVM_CALL_FRAMES.with(|frames| frames.borrow_mut().clear());
+    });
+}
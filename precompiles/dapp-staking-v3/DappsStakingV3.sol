@@ -74,6 +74,10 @@ interface DAppStaking {
     /// @notice Claims one or more pending staker rewards.
     function claim_staker_rewards() external returns (bool);
 
+    /// @notice Claims pending staker rewards across multiple reward spans, up to & including the specified era.
+    /// @param era: The last era for which staker rewards should be claimed.
+    function claim_staker_rewards_up_to(uint256 era) external returns (bool);
+
     /// @notice Claim the bonus reward for the specified smart contract.
     /// @param smart_contract: The smart contract for which the bonus reward should be claimed.
     function claim_bonus_reward(SmartContract calldata smart_contract) external returns (bool);
@@ -83,6 +87,13 @@ interface DAppStaking {
     /// @param era: The era for which the dApp reward should be claimed.
     function claim_dapp_reward(SmartContract calldata smart_contract, uint256 era) external returns (bool);
 
+    /// @notice Move stake from one smart contract to another, without losing the voting subperiod stake or loyalty.
+    ///         If the remaining stake on the source contract would be below the minimum, everything is moved.
+    /// @param source_contract: The smart contract from which the stake is moved.
+    /// @param destination_contract: The smart contract to which the stake is moved.
+    /// @param amount: The amount of tokens to be moved.
+    function move_stake(SmartContract calldata source_contract, SmartContract calldata destination_contract, uint128 amount) external returns (bool);
+
     /// @notice Unstake all funds from the unregistered smart contract.
     /// @param smart_contract: The smart contract which was unregistered and from which all funds should be unstaked.
     function unstake_from_unregistered(SmartContract calldata smart_contract) external returns (bool);
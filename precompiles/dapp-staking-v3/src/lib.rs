@@ -515,8 +515,9 @@ where
     }
 
     /// Transfers stake from one contract to another.
-    /// This is a legacy functionality that is no longer supported via direct call to dApp staking v3.
-    /// However, it can be achieved by chaining `unstake` and `stake` calls.
+    /// This is a legacy functionality, achieved by calling `move_stake` which keeps the moved stake's
+    /// subperiod composition & loyalty intact. In line with the legacy behavior, in case the remaining
+    /// staked amount on the origin contract would fall below the minimum allowed stake amount, everything is moved.
     #[precompile::public("nomination_transfer(address,uint128,address)")]
     fn nomination_transfer(
         handle: &mut impl PrecompileHandle,
@@ -524,56 +525,20 @@ where
         amount: u128,
         target_contract_h160: Address,
     ) -> EvmResult<bool> {
-        // TODO: benchmark this function so we can measure ref time & PoV correctly
-        // Storage item: StakerInfo:
-        // Blake2_128Concat(16 + SmartContract::max_encoded_len) + SingularStakingInfo::max_encoded_len
-        handle.record_db_read::<R>(
-            16 + <R as pallet_dapp_staking_v3::Config>::SmartContract::max_encoded_len()
-                + SingularStakingInfo::max_encoded_len(),
-        )?;
-
         let origin_smart_contract =
             <R as pallet_dapp_staking_v3::Config>::SmartContract::evm(origin_contract_h160.into());
         let target_smart_contract =
             <R as pallet_dapp_staking_v3::Config>::SmartContract::evm(target_contract_h160.into());
         log::trace!(target: "ds-precompile", "nomination_transfer {:?} {:?} {:?}", origin_smart_contract, amount, target_smart_contract);
 
-        // Find out how much staker has staked on the origin contract
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
-        let staker_info = StakerInfo::<R>::get(&origin, &origin_smart_contract).unwrap_or_default();
-
-        // We don't care from which period the staked amount is, the logic takes care of the situation
-        // if value comes from the past period.
-        let staked_amount = staker_info.total_staked_amount();
-        let minimum_allowed_stake_amount =
-            <R as pallet_dapp_staking_v3::Config>::MinimumStakeAmount::get();
-
-        // In case the remaining staked amount on the origin contract is less than the minimum allowed stake amount,
-        // everything will be unstaked. To keep in line with legacy `nomination_transfer` behavior, we should transfer
-        // the entire amount from the origin to target contract.
-        //
-        // In case value comes from the past period, we don't care, since the `unstake` call will fall apart.
-        let stake_amount = if staked_amount > 0
-            && staked_amount.saturating_sub(amount) < minimum_allowed_stake_amount
-        {
-            staked_amount
-        } else {
-            amount
-        };
-
-        // First call unstake from the origin smart contract
-        let unstake_call = pallet_dapp_staking_v3::Call::<R>::unstake {
-            smart_contract: origin_smart_contract,
+        let call = pallet_dapp_staking_v3::Call::<R>::move_stake {
+            source_contract: origin_smart_contract,
+            destination_contract: target_smart_contract,
             amount,
         };
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin.clone()).into(), unstake_call)?;
 
-        // Then call stake on the target smart contract
-        let stake_call = pallet_dapp_staking_v3::Call::<R>::stake {
-            smart_contract: target_smart_contract,
-            amount: stake_amount,
-        };
-        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), stake_call)?;
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
 
         Ok(true)
     }
@@ -690,6 +655,30 @@ where
         Ok(true)
     }
 
+    /// Attempts to claim pending staker rewards across multiple reward spans, up to & including the given era.
+    #[precompile::public("claim_staker_rewards_up_to(uint256)")]
+    fn claim_staker_rewards_up_to(
+        handle: &mut impl PrecompileHandle,
+        era: U256,
+    ) -> EvmResult<bool> {
+        let era = era
+            .try_into()
+            .map_err::<Revert, _>(|_| RevertReason::value_is_too_large("Era number.").into())
+            .in_field("era")?;
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let claim_staker_rewards_up_to_call =
+            pallet_dapp_staking_v3::Call::<R>::claim_staker_rewards_up_to { era };
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            claim_staker_rewards_up_to_call,
+        )?;
+
+        Ok(true)
+    }
+
     /// Attempts to claim bonus reward for being a loyal staker of the given dApp.
     #[precompile::public("claim_bonus_reward((uint8,bytes))")]
     fn claim_bonus_reward(
@@ -731,6 +720,29 @@ where
         Ok(true)
     }
 
+    /// Attempts to move stake from one dApp to another, keeping the voting subperiod stake & loyalty intact.
+    #[precompile::public("move_stake((uint8,bytes),(uint8,bytes),uint128)")]
+    fn move_stake(
+        handle: &mut impl PrecompileHandle,
+        source_contract: SmartContractV2,
+        destination_contract: SmartContractV2,
+        amount: u128,
+    ) -> EvmResult<bool> {
+        let source_contract = Self::decode_smart_contract(source_contract)?;
+        let destination_contract = Self::decode_smart_contract(destination_contract)?;
+
+        // Prepare call & dispatch it
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let move_stake_call = pallet_dapp_staking_v3::Call::<R>::move_stake {
+            source_contract,
+            destination_contract,
+            amount,
+        };
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), move_stake_call)?;
+
+        Ok(true)
+    }
+
     /// Attempts to unstake everything from an unregistered contract.
     #[precompile::public("unstake_from_unregistered((uint8,bytes))")]
     fn unstake_from_unregistered(
@@ -36,7 +36,7 @@ use sp_core::{H160, H256};
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, ConstU32, IdentityLookup},
-    BuildStorage,
+    BuildStorage, Perbill,
 };
 extern crate alloc;
 
@@ -245,6 +245,11 @@ impl pallet_dapp_staking_v3::BenchmarkHelper<MockSmartContract, AccountId>
     fn set_balance(_account: &AccountId, _amount: Balance) {}
 }
 
+parameter_types! {
+    pub const LoyaltyMultiplierStep: Perbill = Perbill::from_percent(10);
+    pub const MaxLoyaltyPeriods: u8 = 1;
+}
+
 impl pallet_dapp_staking_v3::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
@@ -258,6 +263,8 @@ impl pallet_dapp_staking_v3::Config for Test {
     type AccountCheck = ();
     type TierSlots = StandardTierSlots;
     type EraRewardSpanLength = ConstU32<8>;
+    type MaxRewardSpansPerClaim = ConstU32<4>;
+    type MaxDelegatedClaimsPerBlock = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<2>;
     type MaxNumberOfContracts = ConstU32<10>;
     type MaxUnlockingChunks = ConstU32<5>;
@@ -266,6 +273,8 @@ impl pallet_dapp_staking_v3::Config for Test {
     type MaxNumberOfStakedContracts = ConstU32<5>;
     type MinimumStakeAmount = ConstU128<3>;
     type NumberOfTiers = ConstU32<4>;
+    type LoyaltyMultiplierStep = LoyaltyMultiplierStep;
+    type MaxLoyaltyPeriods = MaxLoyaltyPeriods;
     type WeightInfo = pallet_dapp_staking_v3::weights::SubstrateWeight<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<MockSmartContract, AccountId>;
@@ -806,24 +806,19 @@ fn nomination_transfer_is_ok() {
             .expect_no_logs()
             .execute_returns(true);
 
-        // We expect the same amount to be staked on the second contract
+        // We expect the same amount to be moved to the second contract
         let events = dapp_staking_events();
-        assert_eq!(events.len(), 2);
+        assert_eq!(events.len(), 1);
         assert_matches!(
             events[0].clone(),
-            pallet_dapp_staking_v3::Event::Unstake {
-                smart_contract,
-                amount,
-                ..
-            } if smart_contract == smart_contract_1 && amount == minimum_stake_amount
-        );
-        assert_matches!(
-            events[1].clone(),
-            pallet_dapp_staking_v3::Event::Stake {
-                smart_contract,
+            pallet_dapp_staking_v3::Event::StakeMoved {
+                source_contract,
+                destination_contract,
                 amount,
                 ..
-            } if smart_contract == smart_contract_2 && amount == minimum_stake_amount
+            } if source_contract == smart_contract_1
+                && destination_contract == smart_contract_2
+                && amount == minimum_stake_amount
         );
 
         // 2nd scenario - transfer almost the entire amount from the first to second dApp.
@@ -845,24 +840,19 @@ fn nomination_transfer_is_ok() {
             .expect_no_logs()
             .execute_returns(true);
 
-        // We expect the same amount to be staked on the second contract
+        // We expect the same amount to be moved to the second contract
         let events = dapp_staking_events();
-        assert_eq!(events.len(), 2);
+        assert_eq!(events.len(), 1);
         assert_matches!(
             events[0].clone(),
-            pallet_dapp_staking_v3::Event::Unstake {
-                smart_contract,
-                amount,
-                ..
-            } if smart_contract == smart_contract_1 && amount == expected_stake_unstake_amount
-        );
-        assert_matches!(
-            events[1].clone(),
-            pallet_dapp_staking_v3::Event::Stake {
-                smart_contract,
+            pallet_dapp_staking_v3::Event::StakeMoved {
+                source_contract,
+                destination_contract,
                 amount,
                 ..
-            } if smart_contract == smart_contract_2 && amount == expected_stake_unstake_amount
+            } if source_contract == smart_contract_1
+                && destination_contract == smart_contract_2
+                && amount == expected_stake_unstake_amount
         );
     });
 }
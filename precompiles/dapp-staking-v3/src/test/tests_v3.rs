@@ -346,6 +346,104 @@ fn claim_staker_rewards_is_ok() {
     });
 }
 
+#[test]
+fn claim_staker_rewards_up_to_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register a dApp and stake on it
+        let staker_h160 = ALICE;
+        let smart_contract_address = [0xAF; 32];
+        let smart_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            smart_contract_address.into(),
+        );
+        let amount = 1234;
+        register_and_stake(staker_h160, smart_contract.clone(), amount);
+
+        // Advance enough eras so rewards span across multiple reward spans
+        advance_to_era(16);
+
+        // Claim staker rewards up to the bound and verify a single event is emitted
+        let up_to_era: EraNumber = 12;
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::claim_staker_rewards_up_to {
+                    era: up_to_era.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 1);
+        assert_matches!(
+            events[0].clone(),
+            pallet_dapp_staking_v3::Event::BatchReward { last_era, .. } if last_era == up_to_era
+        );
+    });
+}
+
+#[test]
+fn move_stake_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize();
+
+        // Register two dApps and stake on the first one
+        let staker_h160 = ALICE;
+        let source_address = [0xAF; 32];
+        let destination_address = [0xFA; 32];
+        let source_contract =
+            <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(source_address.into());
+        let destination_contract = <Test as pallet_dapp_staking_v3::Config>::SmartContract::wasm(
+            destination_address.into(),
+        );
+        let amount = 2000;
+        register_and_stake(staker_h160, source_contract.clone(), amount);
+        assert_ok!(DappStaking::register(
+            RawOrigin::Root.into(),
+            AddressMapper::into_account_id(staker_h160),
+            destination_contract.clone()
+        ));
+
+        // Move some of the stake and verify event
+        let move_amount = 500;
+        System::reset_events();
+        precompiles()
+            .prepare_test(
+                staker_h160,
+                precompile_address(),
+                PrecompileCall::move_stake {
+                    source_contract: SmartContractV2 {
+                        contract_type: SmartContractTypes::Wasm,
+                        address: source_address.into(),
+                    },
+                    destination_contract: SmartContractV2 {
+                        contract_type: SmartContractTypes::Wasm,
+                        address: destination_address.into(),
+                    },
+                    amount: move_amount,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        let events = dapp_staking_events();
+        assert_eq!(events.len(), 1);
+        assert_matches!(
+            events[0].clone(),
+            pallet_dapp_staking_v3::Event::StakeMoved {
+                source_contract: source,
+                destination_contract: destination,
+                amount,
+                ..
+            } if source == source_contract && destination == destination_contract && amount == move_amount
+        );
+    });
+}
+
 #[test]
 fn claim_bonus_reward_is_ok() {
     ExternalityBuilder::build().execute_with(|| {
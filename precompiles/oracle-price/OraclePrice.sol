@@ -0,0 +1,28 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Oracle price interface.
+ */
+
+/// Interface to the precompiled contract
+/// Predeployed at the address 0x0000000000000000000000000000000000005008
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/astar
+/// code: precompiles/oracle-price/src/lib.rs
+///
+/// All prices are fixed point numbers with 18 decimals, e.g. `150000000000000000` is `0.15`.
+interface OraclePrice {
+    /// Reads the latest validated native currency price.
+    /// Only values aggregated by the price aggregator, which passed its outlier & quorum checks, are considered.
+    /// @return (price, block number) of the most recently validated value, or (0, 0) if there is none.
+    function read_latest_price() external view returns (uint256, uint256);
+
+    /// Reads the moving average of the native currency price.
+    /// In case the price feed has gone stale, the price frozen at that moment is returned instead.
+    /// @return Moving average price, or 0 if no values have been aggregated yet.
+    function read_moving_average() external view returns (uint256);
+
+    /// Reads the number of values used to calculate the moving average.
+    /// @return Number of values currently in the moving average buffer.
+    function read_buffer_length() external view returns (uint32);
+}
@@ -0,0 +1,117 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar oracle price interface.
+//!
+//! Exposes the native currency price data, as provided by the oracle & the price aggregator, to the EVM.
+//! All the prices are returned as fixed point numbers with 18 decimals.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::FixedPointNumber;
+use sp_std::marker::PhantomData;
+
+use astar_primitives::{
+    oracle::{CurrencyAmount, CurrencyId, PriceProvider},
+    BlockNumber,
+};
+use pallet_price_aggregator::{CircularBuffer, LastValidValueBlock, ValuesCircularBuffer};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A precompile that exposes the oracle price data of the native currency.
+pub struct OraclePricePrecompile<R>(PhantomData<R>);
+
+#[precompile_utils::precompile]
+impl<R> OraclePricePrecompile<R>
+where
+    R: pallet_evm::Config + pallet_price_aggregator::Config,
+{
+    /// Read the latest validated native currency price, together with the block number in which it was validated.
+    ///
+    /// The price is the most recent value aggregated by the price aggregator which passed its
+    /// outlier & quorum checks, raw oracle submissions are never returned directly.
+    ///
+    /// Returns `(0, 0)` if no value has been validated yet.
+    #[precompile::public("read_latest_price()")]
+    #[precompile::view]
+    fn read_latest_price(handle: &mut impl PrecompileHandle) -> EvmResult<(U256, U256)> {
+        // LastValidValueBlock: Twox64Concat(8) + CurrencyId + BlockNumber
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len() + BlockNumber::max_encoded_len(),
+        )?;
+        // ValuesCircularBuffer: Twox64Concat(8) + CurrencyId + CircularBuffer
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len()
+                + CircularBuffer::<R::CircularBufferLength>::max_encoded_len(),
+        )?;
+
+        let native_currency_id = <R as pallet_price_aggregator::Config>::NativeCurrencyId::get();
+        let latest_price = ValuesCircularBuffer::<R>::get(native_currency_id).latest();
+        let validated_at = LastValidValueBlock::<R>::get(native_currency_id);
+
+        Ok(match (latest_price, validated_at) {
+            (Some(price), Some(block)) => (price.into_inner().into(), block.into()),
+            _ => (U256::zero(), U256::zero()),
+        })
+    }
+
+    /// Read the moving average of the native currency price.
+    ///
+    /// Returns the frozen price in case the stale feed circuit breaker is tripped,
+    /// or `0` if the moving average buffer is empty.
+    #[precompile::public("read_moving_average()")]
+    #[precompile::view]
+    fn read_moving_average(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        // FrozenPrice: Twox64Concat(8) + CurrencyId + CurrencyAmount
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len() + CurrencyAmount::max_encoded_len(),
+        )?;
+        // ValuesCircularBuffer: Twox64Concat(8) + CurrencyId + CircularBuffer
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len()
+                + CircularBuffer::<R::CircularBufferLength>::max_encoded_len(),
+        )?;
+
+        Ok(pallet_price_aggregator::Pallet::<R>::average_price()
+            .into_inner()
+            .into())
+    }
+
+    /// Read the number of values in the moving average buffer of the native currency price.
+    #[precompile::public("read_buffer_length()")]
+    #[precompile::view]
+    fn read_buffer_length(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // ValuesCircularBuffer: Twox64Concat(8) + CurrencyId + CircularBuffer
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len()
+                + CircularBuffer::<R::CircularBufferLength>::max_encoded_len(),
+        )?;
+
+        let native_currency_id = <R as pallet_price_aggregator::Config>::NativeCurrencyId::get();
+        Ok(ValuesCircularBuffer::<R>::get(native_currency_id).len())
+    }
+}
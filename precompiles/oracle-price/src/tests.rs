@@ -0,0 +1,168 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use frame_support::assert_ok;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn feed_value(origin: RuntimeOrigin, values: Vec<(CurrencyId, CurrencyAmount)>) {
+    assert_ok!(Oracle::feed_values(origin, values.try_into().unwrap()));
+}
+
+#[test]
+fn read_latest_price_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let native_currency_id = NativeCurrencyId::get();
+
+        // 1. No values have been validated yet
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_latest_price {},
+            )
+            .expect_no_logs()
+            .execute_returns((U256::zero(), U256::zero()));
+
+        // 2. Submit a raw value to the oracle, it must not be returned before it's been aggregated
+        Timestamp::set_timestamp(1_000);
+        feed_value(
+            RuntimeOrigin::signed(TestAccount::Alice),
+            vec![(CurrencyId::ASTR, CurrencyAmount::from_rational(15, 100))],
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_latest_price {},
+            )
+            .expect_no_logs()
+            .execute_returns((U256::zero(), U256::zero()));
+
+        // 3. Push a validated value, expect it's returned together with the validation block
+        let price_1 = CurrencyAmount::from_rational(13, 100);
+        ValuesCircularBuffer::<Runtime>::mutate(native_currency_id, |buffer| buffer.add(price_1));
+        LastValidValueBlock::<Runtime>::insert(native_currency_id, 17);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_latest_price {},
+            )
+            .expect_no_logs()
+            .execute_returns((U256::from(price_1.into_inner()), U256::from(17)));
+
+        // 4. Push another validated value, expect the most recent one is returned, not the average
+        let price_2 = CurrencyAmount::from_rational(11, 100);
+        ValuesCircularBuffer::<Runtime>::mutate(native_currency_id, |buffer| buffer.add(price_2));
+        LastValidValueBlock::<Runtime>::insert(native_currency_id, 33);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_latest_price {},
+            )
+            .expect_no_logs()
+            .execute_returns((U256::from(price_2.into_inner()), U256::from(33)));
+    });
+}
+
+#[test]
+fn read_moving_average_and_buffer_length_are_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let native_currency_id = NativeCurrencyId::get();
+
+        // 1. Empty buffer
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_moving_average {},
+            )
+            .expect_no_logs()
+            .execute_returns(U256::zero());
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_buffer_length {},
+            )
+            .expect_no_logs()
+            .execute_returns(0_u32);
+
+        // 2. Add some values into the buffer, verify results are as expected
+        let price_1 = CurrencyAmount::from_rational(11, 100);
+        let price_2 = CurrencyAmount::from_rational(15, 100);
+        ValuesCircularBuffer::<Runtime>::mutate(native_currency_id, |buffer| {
+            buffer.add(price_1);
+            buffer.add(price_2);
+        });
+
+        let expected_average = CurrencyAmount::from_rational(13, 100);
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_moving_average {},
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(expected_average.into_inner()));
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_buffer_length {},
+            )
+            .expect_no_logs()
+            .execute_returns(2_u32);
+
+        // 3. Overflow the buffer, verify length is capped at the buffer capacity
+        let capacity = <Runtime as pallet_price_aggregator::Config>::CircularBufferLength::get();
+        ValuesCircularBuffer::<Runtime>::mutate(native_currency_id, |buffer| {
+            for _ in 0..capacity {
+                buffer.add(price_1);
+            }
+        });
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_moving_average {},
+            )
+            .expect_no_logs()
+            .execute_returns(U256::from(price_1.into_inner()));
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::read_buffer_length {},
+            )
+            .expect_no_logs()
+            .execute_returns(capacity);
+    });
+}
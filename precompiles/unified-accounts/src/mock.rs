@@ -19,7 +19,12 @@
 use super::*;
 
 use fp_evm::{IsPrecompileResult, Precompile};
-use frame_support::{construct_runtime, parameter_types, traits::ConstU64, weights::Weight};
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU64},
+    weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
 pub use pallet_evm::{
     AddressMapping, EnsureAddressNever, EnsureAddressRoot, PrecompileResult, PrecompileSet,
 };
@@ -186,6 +191,29 @@ impl pallet_balances::Config for TestRuntime {
     type MaxFreezes = ConstU32<0>;
 }
 
+impl pallet_assets::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = u128;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<20>;
+    type Freezer = ();
+    type Extra = ();
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<0>;
+    type AssetIdParameter = u128;
+    type CallbackHandle = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 parameter_types! {
     pub const MinimumPeriod: u64 = 5;
 }
@@ -233,6 +261,7 @@ parameter_types! {
     // 2 storage items with value size 20 and 32
     pub const AccountMappingStorageFee: u128 = 0;
     pub ChainId: u64 = 1024;
+    pub ContractSignatureWeightLimit: Weight = Weight::zero();
 }
 
 impl pallet_unified_accounts::Config for TestRuntime {
@@ -241,6 +270,12 @@ impl pallet_unified_accounts::Config for TestRuntime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type ContractSignatureVerifier = ();
+    type ContractSignatureWeightLimit = ContractSignatureWeightLimit;
+    type MaxContractSignatureLength = ConstU32<1024>;
+    type Assets = Assets;
+    type StakingLocks = ();
+    type MaxSweepAssets = ConstU32<4>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
@@ -252,6 +287,7 @@ construct_runtime!(
         Evm: pallet_evm,
         UnifiedAccounts: pallet_unified_accounts,
         Balances : pallet_balances,
+        Assets: pallet_assets,
         Timestamp: pallet_timestamp,
     }
 );
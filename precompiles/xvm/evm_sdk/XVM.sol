@@ -21,4 +21,18 @@ interface XVM {
         uint256 value,
         uint256 storage_deposit_limit
     ) external payable returns (bool success, bytes memory data);
+
+    /**
+     * @dev Query external VM in read-only mode, all state changes are rolled back
+     * @param vm_id - target VM id
+     * @param to - call recipient
+     * @param input - SCALE-encoded call arguments
+     * @return success - operation outcome
+     * @return data - output data if successful, error data on error
+     */
+    function xvm_query(
+        uint8 vm_id,
+        bytes calldata to,
+        bytes calldata input
+    ) external view returns (bool success, bytes memory data);
 }
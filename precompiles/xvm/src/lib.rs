@@ -21,7 +21,9 @@
 extern crate alloc;
 use alloc::format;
 
-use astar_primitives::xvm::{Context, FailureReason, VmId, XvmCall};
+use astar_primitives::xvm::{
+    balance_from_evm_value, CallResult, Context, FailureReason, VmId, XvmCall,
+};
 use fp_evm::{ExitRevert, PrecompileFailure, PrecompileHandle};
 use pallet_evm::{AddressMapping, GasWeightMapping};
 use sp_core::U256;
@@ -58,21 +60,11 @@ where
         storage_deposit_limit: U256,
     ) -> EvmResult<(bool, UnboundedBytes)> {
         let vm_id = vm_id.try_into().map_err(|_| revert("invalid vm id"))?;
-
-        let mut gas_limit = handle.remaining_gas();
-        // If user specified a gas limit, make sure it's not exceeded.
-        if let Some(user_limit) = handle.gas_limit() {
-            gas_limit = gas_limit.min(user_limit);
-        }
-        let weight_limit = R::GasWeightMapping::gas_to_weight(gas_limit, true);
-        let xvm_context = Context {
-            source_vm_id: VmId::Evm,
-            weight_limit,
-        };
+        let xvm_context = Self::xvm_context(handle);
 
         let call_to = call_to.into();
         let call_input = call_input.into();
-        let value = value.try_into().map_err(|_| revert("value overflow"))?;
+        let value = balance_from_evm_value(value).map_err(|_| revert("value overflow"))?;
         let storage_deposit_limit: u128 = storage_deposit_limit
             .try_into()
             .map_err(|_| revert("value overflow"))?;
@@ -93,6 +85,53 @@ where
         );
         let call_result = XC::call(xvm_context, vm_id, from, call_to, call_input, value, limit);
 
+        Self::handle_result(handle, call_result)
+    }
+
+    #[precompile::public("xvm_query(uint8,bytes,bytes)")]
+    #[precompile::view]
+    fn xvm_query(
+        handle: &mut impl PrecompileHandle,
+        vm_id: u8,
+        call_to: UnboundedBytes,
+        call_input: UnboundedBytes,
+    ) -> EvmResult<(bool, UnboundedBytes)> {
+        let vm_id = vm_id.try_into().map_err(|_| revert("invalid vm id"))?;
+        let xvm_context = Self::xvm_context(handle);
+
+        let call_to = call_to.into();
+        let call_input = call_input.into();
+
+        let from = R::AddressMapping::into_account_id(handle.context().caller);
+
+        log::trace!(
+            target: "xvm-precompile::xvm_query",
+            "vm_id: {:?}, from: {:?}, call_to: {:?}, call_input: {:?}", vm_id, from, call_to, call_input
+        );
+        let query_result = XC::query(xvm_context, vm_id, from, call_to, call_input);
+
+        Self::handle_result(handle, query_result)
+    }
+
+    /// XVM context with the weight limit derived from the gas left.
+    fn xvm_context(handle: &mut impl PrecompileHandle) -> Context {
+        let mut gas_limit = handle.remaining_gas();
+        // If user specified a gas limit, make sure it's not exceeded.
+        if let Some(user_limit) = handle.gas_limit() {
+            gas_limit = gas_limit.min(user_limit);
+        }
+        let weight_limit = R::GasWeightMapping::gas_to_weight(gas_limit, true);
+        Context {
+            source_vm_id: VmId::Evm,
+            weight_limit,
+        }
+    }
+
+    /// Records the used weight, and maps the XVM call result into the precompile output.
+    fn handle_result(
+        handle: &mut impl PrecompileHandle,
+        call_result: CallResult,
+    ) -> EvmResult<(bool, UnboundedBytes)> {
         let used_weight = match &call_result {
             Ok(s) => s.used_weight,
             Err(f) => f.used_weight,
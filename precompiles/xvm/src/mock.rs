@@ -287,6 +287,16 @@ impl XvmCall<AccountId> for MockXvmWithArgsCheck {
 
         Ok(CallOutput::new(vec![], Weight::zero()))
     }
+
+    fn query(
+        context: Context,
+        vm_id: VmId,
+        source: AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+    ) -> CallResult {
+        Self::call(context, vm_id, source, target, input, 0, None)
+    }
 }
 
 // Configure a mock runtime to test the pallet.
@@ -76,6 +76,26 @@ fn correct_arguments_works() {
     })
 }
 
+#[test]
+fn value_overflow_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::xvm_call {
+                    vm_id: 0x1Fu8.into(),
+                    call_to: b"".into(),
+                    call_input: b"".into(),
+                    value: U256::from(u128::MAX) + 1,
+                    storage_deposit_limit: 0.into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"value overflow");
+    })
+}
+
 #[test]
 fn weight_limit_is_min_of_remaining_and_user_limit() {
     ExtBuilder::default().build().execute_with(|| {
@@ -126,3 +146,45 @@ fn weight_limit_is_min_of_remaining_and_user_limit() {
         );
     });
 }
+
+#[test]
+fn query_works_in_static_call() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::xvm_query {
+                    vm_id: 0x1Fu8.into(),
+                    call_to: hex::decode("0000000000000000000000000000000000000000")
+                        .expect("invalid hex")
+                        .into(),
+                    call_input: b"".into(),
+                },
+            )
+            .with_static_call(true)
+            .expect_no_logs()
+            .execute_returns((true, UnboundedBytes::from(vec![])));
+
+        // State changing call isn't allowed in static call.
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::xvm_call {
+                    vm_id: 0x1Fu8.into(),
+                    call_to: hex::decode("0000000000000000000000000000000000000000")
+                        .expect("invalid hex")
+                        .into(),
+                    call_input: b"".into(),
+                    value: 0.into(),
+                    storage_deposit_limit: 0.into(),
+                },
+            )
+            .with_static_call(true)
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                output == b"Can't call non-static function in static context"
+            });
+    });
+}
@@ -22,8 +22,8 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 
 use frame_support::pallet_prelude::{RuntimeDebug, Weight};
 use sp_core::H160;
-use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber};
-use sp_std::hash::Hash;
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError, FixedPointNumber};
+use sp_std::{collections::btree_map::BTreeMap, hash::Hash, vec::Vec};
 
 /// Era number type
 pub type EraNumber = u32;
@@ -148,6 +148,7 @@ pub trait SmartContractHandle<AccountId> {
     Hash,
     scale_info::TypeInfo,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum SmartContract<AccountId> {
     /// EVM smart contract instance.
     Evm(H160),
@@ -177,6 +178,114 @@ impl<AccountId> AccountCheck<AccountId> for () {
     }
 }
 
+/// Interface for transferring the dApp staking locks of an account to another account.
+pub trait LocksTransfer<AccountId> {
+    /// Transfers all the locked & unlocking funds of `from` to `to`, along with the locks.
+    /// Only possible if `from` has no stake, since stake cannot be transferred.
+    ///
+    /// Returns the total transferred amount.
+    fn transfer_locks(from: &AccountId, to: &AccountId) -> Result<Balance, DispatchError>;
+
+    /// Weight of the `transfer_locks` call.
+    fn transfer_locks_weight() -> Weight;
+}
+
+impl<AccountId> LocksTransfer<AccountId> for () {
+    fn transfer_locks(_from: &AccountId, _to: &AccountId) -> Result<Balance, DispatchError> {
+        Ok(0)
+    }
+
+    fn transfer_locks_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Subperiod of the dApp staking protocol, as exposed via the runtime API.
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubperiodInfo {
+    /// Subperiod during which the focus is on voting. No rewards are earned during this subperiod.
+    Voting,
+    /// Subperiod during which dApps and stakers earn rewards.
+    BuildAndEarn,
+}
+
+/// General information & state of the dApp staking protocol, as exposed via the runtime API.
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolStateInfo {
+    /// Ongoing era number.
+    pub era: EraNumber,
+    /// Block number at which the next era should start.
+    pub next_era_start: BlockNumber,
+    /// Ongoing period number.
+    pub period: PeriodNumber,
+    /// Ongoing subperiod.
+    pub subperiod: SubperiodInfo,
+    /// Era in which the next subperiod starts.
+    pub next_subperiod_start_era: EraNumber,
+    /// `true` if dApp staking is in maintenance mode (disabled), `false` otherwise.
+    pub maintenance: bool,
+}
+
+/// Amount undergoing the unlocking period, as exposed via the runtime API.
+#[derive(
+    PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, Default,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnlockingChunkInfo {
+    /// Amount undergoing the unlocking period.
+    pub amount: Balance,
+    /// Block in which the unlocking period is finished for this chunk.
+    pub unlock_block: BlockNumber,
+}
+
+/// Staked amount in a specific era & period, as exposed via the runtime API.
+#[derive(
+    PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, Default,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeAmountInfo {
+    /// Amount of staked funds accounting for the voting subperiod.
+    pub voting: Balance,
+    /// Amount of staked funds accounting for the build&earn subperiod.
+    pub build_and_earn: Balance,
+    /// Era to which this stake amount refers to.
+    pub era: EraNumber,
+    /// Period to which this stake amount refers to.
+    pub period: PeriodNumber,
+}
+
+/// Locked & staked information about an account, as exposed via the runtime API.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountLedgerInfo {
+    /// How much active locked amount an account has. This can be used for staking.
+    pub locked: Balance,
+    /// All the unlocking chunks of the account.
+    pub unlocking: Vec<UnlockingChunkInfo>,
+    /// Stake information for the _current_ era.
+    pub staked: StakeAmountInfo,
+    /// Stake information for the _next_ era.
+    pub staked_future: Option<StakeAmountInfo>,
+    /// Number of contract stake entries in storage.
+    pub contract_stake_count: u32,
+    /// Whether claimed rewards are automatically locked & restaked.
+    pub auto_restake: bool,
+}
+
+/// dApp tier assignment & tier rewards for a single era, as exposed via the runtime API.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DAppTierRewardsInfo {
+    /// dApps and their corresponding tiers. dApps which already claimed their reward are not included.
+    pub dapps: BTreeMap<DAppId, TierId>,
+    /// Rewards for each tier. First entry refers to the first tier, and so on.
+    pub rewards: Vec<Balance>,
+    /// Period during which the era took place.
+    pub period: PeriodNumber,
+}
+
 /// Trait for calculating the total number of tier slots for the given price.
 pub trait TierSlots {
     /// Returns the total number of tier slots for the given price.
@@ -95,4 +95,11 @@ pub trait CheckedEthereumTransact {
         source: H160,
         checked_tx: CheckedEthereumTx,
     ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo>;
+
+    /// Execute an checked Ethereum transaction as a static call in XVM queries. The tx is not
+    /// applied, and any state changing operation fails the call.
+    fn xvm_static_call(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo>;
 }
@@ -20,6 +20,8 @@ use frame_support::{pallet_prelude::*, traits::Time};
 use sp_arithmetic::fixed_point::FixedU128;
 use sp_std::vec::Vec;
 
+use crate::AssetId;
+
 /// Interface for fetching price of the native token.
 ///
 /// **NOTE:** This is just a temporary interface, and will be replaced with a proper oracle which will average
@@ -29,12 +31,35 @@ pub trait PriceProvider {
     fn average_price() -> CurrencyAmount;
 }
 
+/// Interface for fetching price of any tracked currency.
+pub trait MultiCurrencyPriceProvider {
+    /// Get the average price of the specified currency.
+    ///
+    /// Returns `None` if the currency isn't tracked, or if no price is available for it yet.
+    fn average_price_of(currency_id: CurrencyId) -> Option<CurrencyAmount>;
+}
+
+/// Interface for fetching price of the native token, in case it's available & considered reliable.
+pub trait FalliblePriceProvider {
+    /// Get the average price of the native token.
+    ///
+    /// Returns `None` if no price is available, or if the price data feed is considered stale.
+    fn try_average_price() -> Option<CurrencyAmount>;
+}
+
 pub type CurrencyAmount = FixedU128;
 
+/// Identifier of the currency whose price is reported by the oracle.
+///
+/// **NOTE:** new variants must only be appended, to preserve the encoding of the existing ones.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub enum CurrencyId {
     ASTR,
     SDN,
+    DOT,
+    USDT,
+    /// Any other XC asset, identified by its asset Id.
+    XcAsset(#[codec(compact)] AssetId),
 }
 
 type TimestampedValue<T, I = ()> = orml_oracle::TimestampedValue<
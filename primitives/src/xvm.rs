@@ -21,12 +21,14 @@ use crate::Balance;
 use frame_support::weights::Weight;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_core::U256;
 use sp_runtime::RuntimeDebug;
 use sp_std::{convert::TryFrom, prelude::*, result::Result};
 
 /// Vm Id.
 #[repr(u8)]
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum VmId {
     Evm = 0x0F,
     Wasm = 0x1F,
@@ -48,6 +50,7 @@ impl TryFrom<u8> for VmId {
 
 /// XVM call info on success.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct CallOutput {
     /// Output of the call.
     pub output: Vec<u8>,
@@ -67,6 +70,7 @@ impl CallOutput {
 
 /// XVM call failure.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct CallFailure {
     /// Failure reason.
     pub reason: FailureReason,
@@ -102,6 +106,7 @@ impl CallFailure {
 ///  the case that wrong input was passed to XVM call, for instance invalid target,
 ///  as from VM/WASM perspective, it's an input guard condition failure.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum FailureReason {
     /// XVM call failed with explicit revert.
     Revert(FailureRevert),
@@ -111,6 +116,7 @@ pub enum FailureReason {
 
 /// Failure reason on revert.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum FailureRevert {
     /// Target contract address is invalid.
     InvalidTarget,
@@ -122,17 +128,37 @@ pub enum FailureRevert {
 
 /// Failure reason on error.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum FailureError {
     /// Invalid VM id.
     InvalidVmId,
     /// Calling the contracts in the same VM is not allowed.
     SameVmCallDenied,
     /// Reentrance is not allowed.
+    ///
+    /// No longer returned, nested calls are bounded by the max call depth instead.
+    /// Kept for encoding compatibility.
     ReentranceDenied,
     /// The call failed with error on EVM or WASM execution.
     VmError(Vec<u8>),
     /// Out of gas.
     OutOfGas,
+    /// Max call depth of nested XVM calls is reached.
+    CallDepthLimitReached,
+    /// Value to transfer doesn't fit into the native `Balance` type.
+    ValueOverflow,
+    /// Caller cannot transfer the value without going below the existential deposit.
+    InsufficientBalance,
+    /// Value to transfer is below the existential deposit, and the callee account doesn't exist.
+    ValueBelowExistentialDeposit,
+}
+
+/// Convert an EVM value into the native `Balance`.
+///
+/// EVM values and native balances are in the same unit, the smallest unit of the native
+/// token, so no scaling is applied. The conversion fails if the value overflows `Balance`.
+pub fn balance_from_evm_value(value: U256) -> Result<Balance, FailureError> {
+    Balance::try_from(value).map_err(|_| FailureError::ValueOverflow)
 }
 
 /// XVM call result.
@@ -142,6 +168,7 @@ pub type CallResult = Result<CallOutput, CallFailure>;
 ///
 /// Note this should be set by runtime, instead of passed by callers.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct Context {
     /// The source VM of the call.
     pub source_vm_id: VmId,
@@ -169,4 +196,23 @@ pub trait XvmCall<AccountId> {
         value: Balance,
         storage_deposit_limit: Option<Balance>,
     ) -> CallResult;
+
+    /// Query a contract in XVM, in read-only mode.
+    ///
+    /// The target contract is executed without value transfer, and all the state
+    /// changes it made are rolled back afterwards.
+    ///
+    /// Parameters:
+    /// - `context`: XVM context.
+    /// - `vm_id`: the VM Id of the target contract.
+    /// - `source`: Caller Id.
+    /// - `target`: Target contract address.
+    /// - `input`: call input data.
+    fn query(
+        context: Context,
+        vm_id: VmId,
+        source: AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+    ) -> CallResult;
 }
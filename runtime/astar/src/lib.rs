@@ -326,6 +326,8 @@ impl pallet_multisig::Config for Runtime {
 
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 500 * ASTR;
+    pub const LoyaltyMultiplierStep: Perbill = Perbill::from_percent(10);
+    pub const MaxLoyaltyPeriods: u8 = 5;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -362,13 +364,16 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type Currency = Balances;
     type SmartContract = SmartContract<AccountId>;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
-    type NativePriceProvider = PriceAggregator;
+    type NativePriceProvider =
+        pallet_static_price_provider::HybridPriceProvider<Runtime, PriceAggregator>;
     type StakingRewardHandler = Inflation;
     type CycleConfiguration = InflationCycleConfig;
     type Observers = Inflation;
     type AccountCheck = AccountCheck;
     type TierSlots = StandardTierSlots;
     type EraRewardSpanLength = ConstU32<16>;
+    type MaxRewardSpansPerClaim = ConstU32<4>;
+    type MaxDelegatedClaimsPerBlock = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<4>;
     type MaxNumberOfContracts = ConstU32<500>;
     type MaxUnlockingChunks = ConstU32<8>;
@@ -377,6 +382,8 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MaxNumberOfStakedContracts = ConstU32<16>;
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
+    type LoyaltyMultiplierStep = LoyaltyMultiplierStep;
+    type MaxLoyaltyPeriods = MaxLoyaltyPeriods;
     type WeightInfo = weights::pallet_dapp_staking_v3::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -417,6 +424,7 @@ impl pallet_inflation::Config for Runtime {
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
+    type MaxScheduledParams = ConstU32<8>;
     type WeightInfo = weights::pallet_inflation::SubstrateWeight<Runtime>;
 }
 
@@ -987,6 +995,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     c,
                     RuntimeCall::DappStaking(
                         pallet_dapp_staking_v3::Call::claim_staker_rewards { .. }
+                            | pallet_dapp_staking_v3::Call::claim_staker_rewards_up_to { .. }
                     )
                 )
             }
@@ -1032,8 +1041,17 @@ impl pallet_proxy::Config for Runtime {
 
 parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
+    pub TrackedCurrencies: Vec<CurrencyId> =
+        vec![NativeCurrencyId::get(), CurrencyId::DOT, CurrencyId::USDT];
     // Aggregate values for one day.
     pub const AggregationDuration: BlockNumber = 7200;
+    // Reject values which deviate more than 50% from the moving average, e.g. faulty decimals.
+    // Values are accepted again once the circuit breaker is tripped, so a sustained move recovers.
+    pub const OracleMaxDeviation: Perbill = Perbill::from_percent(50);
+    // At least two distinct oracle operators must submit values during an aggregation period.
+    pub const OracleMinSubmitters: u32 = 2;
+    // Freeze the price if no valid values have been received for one day.
+    pub const StaleFeedThreshold: BlockNumber = 7200;
 }
 
 impl pallet_price_aggregator::Config for Runtime {
@@ -1041,12 +1059,21 @@ impl pallet_price_aggregator::Config for Runtime {
     type MaxValuesPerBlock = ConstU32<8>;
     type ProcessBlockValues = pallet_price_aggregator::MedianBlockValue;
     type NativeCurrencyId = NativeCurrencyId;
+    type TrackedCurrencies = TrackedCurrencies;
     // 7 days
     type CircularBufferLength = ConstU32<7>;
+    type MovingAverage = pallet_price_aggregator::SimpleMovingAverage;
     type AggregationDuration = AggregationDuration;
+    type MaxDeviation = OracleMaxDeviation;
+    type MinSubmitters = OracleMinSubmitters;
+    type StaleFeedThreshold = StaleFeedThreshold;
     type WeightInfo = pallet_price_aggregator::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_static_price_provider::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+}
+
 parameter_types! {
     // Cannot specify `Root` so need to do it like this, unfortunately.
     pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
@@ -1106,6 +1133,7 @@ construct_runtime!(
         // TODO: Address this later. It would be best if Inflation was first pallet.
         Inflation: pallet_inflation = 33,
         DappStaking: pallet_dapp_staking_v3 = 34,
+        StaticPriceProvider: pallet_static_price_provider = 35,
         Assets: pallet_assets = 36,
         PriceAggregator: pallet_price_aggregator = 37,
         Oracle: orml_oracle = 38,
@@ -1169,7 +1197,6 @@ pub type Executive = frame_executive::Executive<
 >;
 
 parameter_types! {
-    pub const StaticPriceProviderName: &'static str = "StaticPriceProvider";
     // 0.18 $
     pub const InitPrice: CurrencyAmount = CurrencyAmount::from_rational(18, 100);
 }
@@ -1178,12 +1205,14 @@ parameter_types! {
 ///
 /// Once done, migrations should be removed from the tuple.
 pub type Migrations = (
-    frame_support::migrations::RemovePallet<
-        StaticPriceProviderName,
-        <Runtime as frame_system::Config>::DbWeight,
-    >,
     OracleIntegrationLogic,
     pallet_price_aggregator::PriceAggregatorInitializer<Runtime, InitPrice>,
+    pallet_static_price_provider::ActivePriceReset<Runtime>,
+    pallet_dapp_staking_v3::migration::v7::MigrateToV7<Runtime>,
+    pallet_dapp_staking_v3::migration::v8::MigrateToV8<Runtime>,
+    pallet_inflation::migration::v2::MigrateToV2<Runtime>,
+    pallet_price_aggregator::migration::v2::MigrateToV2<Runtime>,
+    pallet_price_aggregator::migration::v3::MigrateToV3<Runtime>,
 );
 
 use frame_support::traits::OnRuntimeUpgrade;
@@ -1741,6 +1770,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl dapp_staking_v3_runtime_api::DappStakingApi<Block> for Runtime {
         fn periods_per_cycle() -> PeriodNumber {
             InflationCycleConfig::periods_per_cycle()
@@ -1761,6 +1791,70 @@ impl_runtime_apis! {
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, TierId> {
             DappStaking::get_dapp_tier_assignment()
         }
+
+        fn get_dapp_tier_assignment_for_era(era: EraNumber) -> Option<BTreeMap<DAppId, TierId>> {
+            DappStaking::get_dapp_tier_assignment_for_era(era)
+        }
+
+        fn get_dapp_tier_rewards_for_era(era: EraNumber) -> Option<dapp_staking_v3_runtime_api::DAppTierRewardsInfo> {
+            DappStaking::get_dapp_tier_rewards_for_era(era).map(Into::into)
+        }
+
+        fn protocol_state() -> dapp_staking_v3_runtime_api::ProtocolStateInfo {
+            pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get().into()
+        }
+
+        fn account_ledger(account: AccountId) -> dapp_staking_v3_runtime_api::AccountLedgerInfo {
+            pallet_dapp_staking_v3::Ledger::<Runtime>::get(&account).into()
+        }
+
+        fn pending_staker_rewards(account: AccountId) -> Balance {
+            DappStaking::pending_staker_rewards(&account)
+        }
+
+        fn staker_rewards_per_era(account: AccountId) -> Vec<(EraNumber, Balance)> {
+            DappStaking::staker_rewards_per_era(&account)
+        }
+
+        fn bonus_reward(account: AccountId, smart_contract: SmartContract<AccountId>) -> Balance {
+            DappStaking::bonus_reward(&account, &smart_contract)
+        }
+
+        fn pending_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::pending_bonus_rewards(&account)
+        }
+
+        fn pending_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)> {
+            DappStaking::pending_dapp_rewards(&smart_contract)
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn inflation_configuration() -> inflation_runtime_api::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn reward_pools() -> inflation_runtime_api::RewardPools {
+            let total_value_staked =
+                pallet_dapp_staking_v3::CurrentEraInfo::<Runtime>::get().total_staked_amount();
+            Inflation::reward_pools(total_value_staked)
+        }
+
+        fn cycle_payout_projection() -> inflation_runtime_api::CyclePayoutProjection {
+            let protocol_state: dapp_staking_v3_runtime_api::ProtocolStateInfo =
+                pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get().into();
+            let total_value_staked =
+                pallet_dapp_staking_v3::CurrentEraInfo::<Runtime>::get().total_staked_amount();
+            Inflation::cycle_payout_projection(
+                protocol_state,
+                protocol_state.next_era_start.saturating_sub(System::block_number()),
+                total_value_staked,
+            )
+        }
+
+        fn issuance_projection(cycles: u32) -> Vec<inflation_runtime_api::CycleIssuanceProjection> {
+            Inflation::issuance_projection(cycles)
+        }
     }
 
 
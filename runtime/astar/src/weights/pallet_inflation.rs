@@ -52,7 +52,7 @@ use pallet_inflation::WeightInfo;
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Inflation InflationParams (r:0 w:1)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(72), added: 567, mode: MaxEncodedLen)
 	fn force_set_inflation_params() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -61,25 +61,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_183_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: Inflation InflationParams (r:1 w:0)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(609), added: 1104, mode: MaxEncodedLen)
+	/// Storage: Inflation InflationParams (r:1 w:1)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(72), added: 567, mode: MaxEncodedLen)
 	fn force_inflation_recalculation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `40`
-		//  Estimated: `1549`
+		//  Estimated: `2094`
 		// Minimum execution time: 13_226_000 picoseconds.
-		Weight::from_parts(13_460_000, 1549)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(13_460_000, 2094)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: Inflation InflationParams (r:1 w:0)
-	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(64), added: 559, mode: MaxEncodedLen)
+	/// Storage: Inflation ScheduledInflationParams (r:1 w:1)
+	/// Proof: Inflation ScheduledInflationParams (max_values: Some(1), max_size: Some(609), added: 1104, mode: MaxEncodedLen)
+	/// Storage: Inflation InflationParams (r:1 w:1)
+	/// Proof: Inflation InflationParams (max_values: Some(1), max_size: Some(72), added: 567, mode: MaxEncodedLen)
 	fn recalculation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `58`
-		//  Estimated: `1549`
+		//  Estimated: `2094`
 		// Minimum execution time: 13_150_000 picoseconds.
-		Weight::from_parts(13_395_000, 1549)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(13_395_000, 2094)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
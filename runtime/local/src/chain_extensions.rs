@@ -20,6 +20,7 @@ use super::{Runtime, UnifiedAccounts, Xvm};
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
+pub use pallet_chain_extension_dapp_staking::DappStakingExtension;
 use pallet_contracts::chain_extension::RegisteredChainExtension;
 
 pub use pallet_chain_extension_unified_accounts::UnifiedAccountsExtension;
@@ -39,8 +40,13 @@ impl RegisteredChainExtension<Runtime> for UnifiedAccountsExtension<Runtime, Uni
     const ID: u16 = 03;
 }
 
+impl RegisteredChainExtension<Runtime> for DappStakingExtension<Runtime> {
+    const ID: u16 = 04;
+}
+
 pub type LocalChainExtensions<Runtime, UnifiedAccounts, Xvm> = (
     AssetsExtension<Runtime>,
     UnifiedAccountsExtension<Runtime, UnifiedAccounts>,
     XvmExtension<Runtime, Xvm, UnifiedAccounts>,
+    DappStakingExtension<Runtime>,
 );
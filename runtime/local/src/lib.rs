@@ -448,6 +448,11 @@ impl pallet_dapp_staking_v3::BenchmarkHelper<SmartContract<AccountId>, AccountId
     }
 }
 
+parameter_types! {
+    pub const LoyaltyMultiplierStep: Perbill = Perbill::from_percent(10);
+    pub const MaxLoyaltyPeriods: u8 = 5;
+}
+
 impl pallet_dapp_staking_v3::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeFreezeReason = RuntimeFreezeReason;
@@ -461,6 +466,8 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type AccountCheck = ();
     type TierSlots = StandardTierSlots;
     type EraRewardSpanLength = ConstU32<8>;
+    type MaxRewardSpansPerClaim = ConstU32<4>;
+    type MaxDelegatedClaimsPerBlock = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<2>;
     type MaxNumberOfContracts = ConstU32<100>;
     type MaxUnlockingChunks = ConstU32<5>;
@@ -469,6 +476,8 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MaxNumberOfStakedContracts = ConstU32<3>;
     type MinimumStakeAmount = ConstU128<AST>;
     type NumberOfTiers = ConstU32<4>;
+    type LoyaltyMultiplierStep = LoyaltyMultiplierStep;
+    type MaxLoyaltyPeriods = MaxLoyaltyPeriods;
     type WeightInfo = pallet_dapp_staking_v3::weights::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -509,6 +518,7 @@ impl pallet_inflation::Config for Runtime {
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
+    type MaxScheduledParams = ConstU32<8>;
     type WeightInfo = pallet_inflation::weights::SubstrateWeight<Runtime>;
 }
 
@@ -522,6 +532,9 @@ impl pallet_utility::Config for Runtime {
 parameter_types! {
     // 2 storage items with value size 20 and 32
     pub const AccountMappingStorageFee: u128 = deposit(2, 32 + 20);
+    // Enough for smart contract wallets verifying a few ECDSA signatures (multisig)
+    pub ContractSignatureWeightLimit: Weight =
+        <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(500_000, false);
 }
 
 impl pallet_unified_accounts::Config for Runtime {
@@ -530,6 +543,12 @@ impl pallet_unified_accounts::Config for Runtime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type ContractSignatureVerifier = pallet_unified_accounts::EvmContractSignatureVerifier<Self>;
+    type ContractSignatureWeightLimit = ContractSignatureWeightLimit;
+    type MaxContractSignatureLength = ConstU32<2048>;
+    type Assets = Assets;
+    type StakingLocks = DappStaking;
+    type MaxSweepAssets = ConstU32<64>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
@@ -549,10 +568,16 @@ impl pallet_ethereum_checked::Config for Runtime {
     type WeightInfo = pallet_ethereum_checked::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const XvmMaxCallDepth: u32 = 4;
+}
+
 impl pallet_xvm::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
     type AddressMapper = UnifiedAccounts;
     type EthereumTransact = EthereumChecked;
+    type MaxCallDepth = XvmMaxCallDepth;
     type WeightInfo = pallet_xvm::weights::SubstrateWeight<Runtime>;
 }
 
@@ -832,6 +857,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     c,
                     RuntimeCall::DappStaking(
                         pallet_dapp_staking_v3::Call::claim_staker_rewards { .. }
+                            | pallet_dapp_staking_v3::Call::claim_staker_rewards_up_to { .. }
                     )
                 )
             }
@@ -1500,6 +1526,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl dapp_staking_v3_runtime_api::DappStakingApi<Block> for Runtime {
         fn periods_per_cycle() -> PeriodNumber {
             InflationCycleConfig::periods_per_cycle()
@@ -1520,6 +1547,98 @@ impl_runtime_apis! {
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, TierId> {
             DappStaking::get_dapp_tier_assignment()
         }
+
+        fn get_dapp_tier_assignment_for_era(era: EraNumber) -> Option<BTreeMap<DAppId, TierId>> {
+            DappStaking::get_dapp_tier_assignment_for_era(era)
+        }
+
+        fn get_dapp_tier_rewards_for_era(era: EraNumber) -> Option<dapp_staking_v3_runtime_api::DAppTierRewardsInfo> {
+            DappStaking::get_dapp_tier_rewards_for_era(era).map(Into::into)
+        }
+
+        fn protocol_state() -> dapp_staking_v3_runtime_api::ProtocolStateInfo {
+            pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get().into()
+        }
+
+        fn account_ledger(account: AccountId) -> dapp_staking_v3_runtime_api::AccountLedgerInfo {
+            pallet_dapp_staking_v3::Ledger::<Runtime>::get(&account).into()
+        }
+
+        fn pending_staker_rewards(account: AccountId) -> Balance {
+            DappStaking::pending_staker_rewards(&account)
+        }
+
+        fn staker_rewards_per_era(account: AccountId) -> Vec<(EraNumber, Balance)> {
+            DappStaking::staker_rewards_per_era(&account)
+        }
+
+        fn bonus_reward(account: AccountId, smart_contract: SmartContract<AccountId>) -> Balance {
+            DappStaking::bonus_reward(&account, &smart_contract)
+        }
+
+        fn pending_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::pending_bonus_rewards(&account)
+        }
+
+        fn pending_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)> {
+            DappStaking::pending_dapp_rewards(&smart_contract)
+        }
+    }
+
+    impl xvm_runtime_api::XvmApi<Block> for Runtime {
+        fn estimate(
+            source_vm_id: astar_primitives::xvm::VmId,
+            vm_id: astar_primitives::xvm::VmId,
+            source: AccountId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+        ) -> xvm_runtime_api::XvmCallEstimate {
+            let context = astar_primitives::xvm::Context {
+                source_vm_id,
+                weight_limit: weight_limit.unwrap_or(RuntimeBlockWeights::get().max_block),
+            };
+            let (result, storage_deposit) = Xvm::estimate_call(
+                context,
+                vm_id,
+                source,
+                target,
+                input,
+                value,
+                storage_deposit_limit,
+            );
+            xvm_runtime_api::XvmCallEstimate::new(result, storage_deposit)
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn inflation_configuration() -> inflation_runtime_api::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn reward_pools() -> inflation_runtime_api::RewardPools {
+            let total_value_staked =
+                pallet_dapp_staking_v3::CurrentEraInfo::<Runtime>::get().total_staked_amount();
+            Inflation::reward_pools(total_value_staked)
+        }
+
+        fn cycle_payout_projection() -> inflation_runtime_api::CyclePayoutProjection {
+            let protocol_state: dapp_staking_v3_runtime_api::ProtocolStateInfo =
+                pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get().into();
+            let total_value_staked =
+                pallet_dapp_staking_v3::CurrentEraInfo::<Runtime>::get().total_staked_amount();
+            Inflation::cycle_payout_projection(
+                protocol_state,
+                protocol_state.next_era_start.saturating_sub(System::block_number()),
+                total_value_staked,
+            )
+        }
+
+        fn issuance_projection(cycles: u32) -> Vec<inflation_runtime_api::CycleIssuanceProjection> {
+            Inflation::issuance_projection(cycles)
+        }
     }
 
 
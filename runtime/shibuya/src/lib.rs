@@ -419,6 +419,8 @@ impl DappStakingAccountCheck<AccountId> for AccountCheck {
 
 parameter_types! {
     pub const MinimumStakingAmount: Balance = 5 * SBY;
+    pub const LoyaltyMultiplierStep: Perbill = Perbill::from_percent(10);
+    pub const MaxLoyaltyPeriods: u8 = 5;
 }
 
 impl pallet_dapp_staking_v3::Config for Runtime {
@@ -427,13 +429,16 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type Currency = Balances;
     type SmartContract = SmartContract<AccountId>;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
-    type NativePriceProvider = PriceAggregator;
+    type NativePriceProvider =
+        pallet_static_price_provider::HybridPriceProvider<Runtime, PriceAggregator>;
     type StakingRewardHandler = Inflation;
     type CycleConfiguration = InflationCycleConfig;
     type Observers = Inflation;
     type AccountCheck = AccountCheck;
     type TierSlots = StandardTierSlots;
     type EraRewardSpanLength = ConstU32<16>;
+    type MaxRewardSpansPerClaim = ConstU32<4>;
+    type MaxDelegatedClaimsPerBlock = ConstU32<16>;
     type RewardRetentionInPeriods = ConstU32<2>;
     type MaxNumberOfContracts = ConstU32<500>;
     type MaxUnlockingChunks = ConstU32<8>;
@@ -442,6 +447,8 @@ impl pallet_dapp_staking_v3::Config for Runtime {
     type MaxNumberOfStakedContracts = ConstU32<8>;
     type MinimumStakeAmount = MinimumStakingAmount;
     type NumberOfTiers = ConstU32<4>;
+    type LoyaltyMultiplierStep = LoyaltyMultiplierStep;
+    type MaxLoyaltyPeriods = MaxLoyaltyPeriods;
     type WeightInfo = weights::pallet_dapp_staking_v3::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = BenchmarkHelper<SmartContract<AccountId>, AccountId>;
@@ -482,6 +489,7 @@ impl pallet_inflation::Config for Runtime {
     type PayoutPerBlock = InflationPayoutPerBlock;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
+    type MaxScheduledParams = ConstU32<8>;
     type WeightInfo = pallet_inflation::weights::SubstrateWeight<Runtime>;
 }
 
@@ -841,10 +849,16 @@ impl pallet_ethereum_checked::Config for Runtime {
     type WeightInfo = pallet_ethereum_checked::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const XvmMaxCallDepth: u32 = 4;
+}
+
 impl pallet_xvm::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
     type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
     type AddressMapper = UnifiedAccounts;
     type EthereumTransact = EthereumChecked;
+    type MaxCallDepth = XvmMaxCallDepth;
     type WeightInfo = pallet_xvm::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1055,6 +1069,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     c,
                     RuntimeCall::DappStaking(
                         pallet_dapp_staking_v3::Call::claim_staker_rewards { .. }
+                            | pallet_dapp_staking_v3::Call::claim_staker_rewards_up_to { .. }
                     )
                 )
             }
@@ -1105,6 +1120,9 @@ impl pallet_xc_asset_config::Config for Runtime {
 parameter_types! {
     // 2 storage items with values 20 and 32
     pub const AccountMappingStorageFee: u128 = deposit(2, 32 + 20);
+    // Enough for smart contract wallets verifying a few ECDSA signatures (multisig)
+    pub ContractSignatureWeightLimit: Weight =
+        <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(500_000, false);
 }
 
 impl pallet_unified_accounts::Config for Runtime {
@@ -1113,14 +1131,29 @@ impl pallet_unified_accounts::Config for Runtime {
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = EVMChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type ContractSignatureVerifier = pallet_unified_accounts::EvmContractSignatureVerifier<Self>;
+    type ContractSignatureWeightLimit = ContractSignatureWeightLimit;
+    type MaxContractSignatureLength = ConstU32<2048>;
+    type Assets = Assets;
+    type StakingLocks = DappStaking;
+    type MaxSweepAssets = ConstU32<64>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
 parameter_types! {
     // Of course it's not true for Shibuya, but SBY is worthless, a test token.
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
+    pub TrackedCurrencies: Vec<CurrencyId> =
+        vec![NativeCurrencyId::get(), CurrencyId::DOT, CurrencyId::USDT];
     // Aggregate values for one day.
     pub const AggregationDuration: BlockNumber = 7200;
+    // Reject values which deviate more than 50% from the moving average, e.g. faulty decimals.
+    // Values are accepted again once the circuit breaker is tripped, so a sustained move recovers.
+    pub const OracleMaxDeviation: Perbill = Perbill::from_percent(50);
+    // At least two distinct oracle operators must submit values during an aggregation period.
+    pub const OracleMinSubmitters: u32 = 2;
+    // Freeze the price if no valid values have been received for one day.
+    pub const StaleFeedThreshold: BlockNumber = 7200;
 }
 
 impl pallet_price_aggregator::Config for Runtime {
@@ -1128,12 +1161,21 @@ impl pallet_price_aggregator::Config for Runtime {
     type MaxValuesPerBlock = ConstU32<8>;
     type ProcessBlockValues = pallet_price_aggregator::MedianBlockValue;
     type NativeCurrencyId = NativeCurrencyId;
+    type TrackedCurrencies = TrackedCurrencies;
     // 7 days
     type CircularBufferLength = ConstU32<7>;
+    type MovingAverage = pallet_price_aggregator::SimpleMovingAverage;
     type AggregationDuration = AggregationDuration;
+    type MaxDeviation = OracleMaxDeviation;
+    type MinSubmitters = OracleMinSubmitters;
+    type StaleFeedThreshold = StaleFeedThreshold;
     type WeightInfo = pallet_price_aggregator::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_static_price_provider::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+}
+
 parameter_types! {
     // Cannot specify `Root` so need to do it like this, unfortunately.
     pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
@@ -1218,6 +1260,7 @@ construct_runtime!(
         TransactionPayment: pallet_transaction_payment = 30,
         Balances: pallet_balances = 31,
         Vesting: pallet_vesting = 32,
+        StaticPriceProvider: pallet_static_price_provider = 33,
         DappStaking: pallet_dapp_staking_v3 = 34,
         Inflation: pallet_inflation = 35,
         Assets: pallet_assets = 36,
@@ -1295,7 +1338,14 @@ pub type Executive = frame_executive::Executive<
 /// All migrations that will run on the next runtime upgrade.
 ///
 /// Once done, migrations should be removed from the tuple.
-pub type Migrations = ();
+pub type Migrations = (
+    pallet_static_price_provider::ActivePriceReset<Runtime>,
+    pallet_dapp_staking_v3::migration::v7::MigrateToV7<Runtime>,
+    pallet_dapp_staking_v3::migration::v8::MigrateToV8<Runtime>,
+    pallet_inflation::migration::v2::MigrateToV2<Runtime>,
+    pallet_price_aggregator::migration::v2::MigrateToV2<Runtime>,
+    pallet_price_aggregator::migration::v3::MigrateToV3<Runtime>,
+);
 
 type EventRecord = frame_system::EventRecord<
     <Runtime as frame_system::Config>::RuntimeEvent,
@@ -1843,6 +1893,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl dapp_staking_v3_runtime_api::DappStakingApi<Block> for Runtime {
         fn periods_per_cycle() -> PeriodNumber {
             InflationCycleConfig::periods_per_cycle()
@@ -1863,8 +1914,99 @@ impl_runtime_apis! {
         fn get_dapp_tier_assignment() -> BTreeMap<DAppId, TierId> {
             DappStaking::get_dapp_tier_assignment()
         }
+
+        fn get_dapp_tier_assignment_for_era(era: EraNumber) -> Option<BTreeMap<DAppId, TierId>> {
+            DappStaking::get_dapp_tier_assignment_for_era(era)
+        }
+
+        fn get_dapp_tier_rewards_for_era(era: EraNumber) -> Option<dapp_staking_v3_runtime_api::DAppTierRewardsInfo> {
+            DappStaking::get_dapp_tier_rewards_for_era(era).map(Into::into)
+        }
+
+        fn protocol_state() -> dapp_staking_v3_runtime_api::ProtocolStateInfo {
+            pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get().into()
+        }
+
+        fn account_ledger(account: AccountId) -> dapp_staking_v3_runtime_api::AccountLedgerInfo {
+            pallet_dapp_staking_v3::Ledger::<Runtime>::get(&account).into()
+        }
+
+        fn pending_staker_rewards(account: AccountId) -> Balance {
+            DappStaking::pending_staker_rewards(&account)
+        }
+
+        fn staker_rewards_per_era(account: AccountId) -> Vec<(EraNumber, Balance)> {
+            DappStaking::staker_rewards_per_era(&account)
+        }
+
+        fn bonus_reward(account: AccountId, smart_contract: SmartContract<AccountId>) -> Balance {
+            DappStaking::bonus_reward(&account, &smart_contract)
+        }
+
+        fn pending_bonus_rewards(account: AccountId) -> Vec<(SmartContract<AccountId>, Balance)> {
+            DappStaking::pending_bonus_rewards(&account)
+        }
+
+        fn pending_dapp_rewards(smart_contract: SmartContract<AccountId>) -> Vec<(EraNumber, Balance)> {
+            DappStaking::pending_dapp_rewards(&smart_contract)
+        }
+    }
+
+    impl xvm_runtime_api::XvmApi<Block> for Runtime {
+        fn estimate(
+            source_vm_id: astar_primitives::xvm::VmId,
+            vm_id: astar_primitives::xvm::VmId,
+            source: AccountId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+        ) -> xvm_runtime_api::XvmCallEstimate {
+            let context = astar_primitives::xvm::Context {
+                source_vm_id,
+                weight_limit: weight_limit.unwrap_or(RuntimeBlockWeights::get().max_block),
+            };
+            let (result, storage_deposit) = Xvm::estimate_call(
+                context,
+                vm_id,
+                source,
+                target,
+                input,
+                value,
+                storage_deposit_limit,
+            );
+            xvm_runtime_api::XvmCallEstimate::new(result, storage_deposit)
+        }
     }
 
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn inflation_configuration() -> inflation_runtime_api::InflationConfiguration {
+            pallet_inflation::ActiveInflationConfig::<Runtime>::get()
+        }
+
+        fn reward_pools() -> inflation_runtime_api::RewardPools {
+            let total_value_staked =
+                pallet_dapp_staking_v3::CurrentEraInfo::<Runtime>::get().total_staked_amount();
+            Inflation::reward_pools(total_value_staked)
+        }
+
+        fn cycle_payout_projection() -> inflation_runtime_api::CyclePayoutProjection {
+            let protocol_state: dapp_staking_v3_runtime_api::ProtocolStateInfo =
+                pallet_dapp_staking_v3::ActiveProtocolState::<Runtime>::get().into();
+            let total_value_staked =
+                pallet_dapp_staking_v3::CurrentEraInfo::<Runtime>::get().total_staked_amount();
+            Inflation::cycle_payout_projection(
+                protocol_state,
+                protocol_state.next_era_start.saturating_sub(System::block_number()),
+                total_value_staked,
+            )
+        }
+
+        fn issuance_projection(cycles: u32) -> Vec<inflation_runtime_api::CycleIssuanceProjection> {
+            Inflation::issuance_projection(cycles)
+        }
+    }
 
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
         fn create_default_config() -> Vec<u8> {
@@ -30,6 +30,7 @@ use pallet_evm_precompile_dispatch::Dispatch;
 use pallet_evm_precompile_dispatch_lockdrop::DispatchLockdrop;
 use pallet_evm_precompile_ed25519::Ed25519Verify;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_oracle_price::OraclePricePrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
 use pallet_evm_precompile_sr25519::Sr25519Precompile;
@@ -164,6 +165,11 @@ pub type ShibuyaPrecompilesSetAt<R, C> = (
         // Not callable from smart contract nor precompiled, only EOA accounts
         (),
     >,
+    PrecompileAt<
+        AddressU64<20488>,
+        OraclePricePrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
 pub type ShibuyaPrecompiles<R, C> = PrecompileSetBuilder<
@@ -0,0 +1,44 @@
+;; Forwards the call input to a chain extension and returns its result.
+;;
+;; Input:  `func_id: u32` (chain extension ID in the upper 16 bits) ++ chain extension input
+;; Output: `0x00` (ink! `MessageResult::Ok`) ++ `ret_val: u32` ++ chain extension output
+(module
+	(import "seal0" "input" (func $input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal0" "call_chain_extension"
+		(func $call_chain_extension (param i32 i32 i32 i32 i32) (result i32))
+	)
+	(import "env" "memory" (memory 1 16))
+
+	;; [0, 4) input length
+	;; [4, 8) output length
+	;; [256, 16640) input buffer
+	;; [32768, 32769) ink! `MessageResult::Ok` variant
+	;; [32769, 32773) chain extension return value
+	;; [32773, 49157) chain extension output buffer
+
+	(func (export "call")
+		(i32.store (i32.const 0) (i32.const 16384))
+		(call $input (i32.const 256) (i32.const 0))
+
+		(i32.store (i32.const 4) (i32.const 16384))
+		(i32.store align=1
+			(i32.const 32769)
+			(call $call_chain_extension
+				(i32.load (i32.const 256))
+				(i32.const 260)
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))
+				(i32.const 32773)
+				(i32.const 4)
+			)
+		)
+
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 32768)
+			(i32.add (i32.load (i32.const 4)) (i32.const 5))
+		)
+	)
+
+	(func (export "deploy"))
+)
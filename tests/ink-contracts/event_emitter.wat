@@ -0,0 +1,27 @@
+;; Emits a contract event with the call input as data.
+;;
+;; Input:  event data
+;; Output: none
+(module
+	(import "seal0" "input" (func $input (param i32 i32)))
+	(import "seal0" "seal_deposit_event" (func $seal_deposit_event (param i32 i32 i32 i32)))
+	(import "env" "memory" (memory 1 16))
+
+	;; [0, 4) input length
+	;; [256, 16640) input buffer
+
+	(func (export "call")
+		(i32.store (i32.const 0) (i32.const 16384))
+		(call $input (i32.const 256) (i32.const 0))
+
+		;; No topics.
+		(call $seal_deposit_event
+			(i32.const 0)
+			(i32.const 0)
+			(i32.const 256)
+			(i32.load (i32.const 0))
+		)
+	)
+
+	(func (export "deploy"))
+)
@@ -0,0 +1,209 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use astar_primitives::dapp_staking::SmartContract;
+use dapp_staking_chain_extension_types::{Command, Outcome, ProtocolStateInfo, Subperiod};
+use pallet_dapp_staking_v3::{ActiveProtocolState, ForcingType};
+use parity_scale_codec::{Decode, Encode};
+
+// Contract forwards the call input to the chain extension, and returns its return value & output.
+const CE_PROXY: &'static str = "chain_extension_proxy";
+
+const DAPP_STAKING_CE_ID: u32 = 4;
+
+/// Call dApp staking chain extension via the proxy contract, returning the chain extension return value & output.
+fn call_dapp_staking_ce<V: Decode>(
+    contract_id: &AccountId,
+    command: Command,
+    input: Vec<u8>,
+) -> (u32, V) {
+    let func_id = (DAPP_STAKING_CE_ID << 16) | u16::from(command) as u32;
+    call_wasm_contract_method::<(u32, V)>(
+        ALICE,
+        contract_id.clone(),
+        [func_id.encode(), input].concat(),
+    )
+}
+
+#[test]
+fn protocol_state_read_works() {
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_wasm_contract(CE_PROXY);
+
+        let (ret_val, state) =
+            call_dapp_staking_ce::<ProtocolStateInfo>(&contract_id, Command::ProtocolState, vec![]);
+        assert_eq!(ret_val, Outcome::Success as u32);
+
+        let protocol_state = ActiveProtocolState::<Runtime>::get();
+        assert_eq!(
+            state,
+            ProtocolStateInfo {
+                era: protocol_state.era,
+                period: protocol_state.period_number(),
+                subperiod: Subperiod::Voting,
+                next_era_start: protocol_state.next_era_start,
+                maintenance: false,
+            }
+        );
+    });
+}
+
+#[test]
+fn lock_stake_unstake_unlock_works() {
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_wasm_contract(CE_PROXY);
+        let smart_contract = SmartContract::Wasm(contract_id.clone());
+        assert_ok!(DappStaking::register(
+            RuntimeOrigin::root(),
+            ALICE,
+            smart_contract.clone()
+        ));
+
+        // Contract needs some free balance to lock
+        let amount = 1000 * UNIT;
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            contract_id.clone().into(),
+            amount,
+        ));
+
+        // Lock
+        let (ret_val, _) = call_dapp_staking_ce::<()>(&contract_id, Command::Lock, amount.encode());
+        assert_eq!(ret_val, Outcome::Success as u32);
+        assert_eq!(
+            call_dapp_staking_ce::<Balance>(
+                &contract_id,
+                Command::LockedAmount,
+                contract_id.encode()
+            ),
+            (Outcome::Success as u32, amount)
+        );
+
+        // Stake
+        let (ret_val, _) = call_dapp_staking_ce::<()>(
+            &contract_id,
+            Command::Stake,
+            (smart_contract.clone(), amount).encode(),
+        );
+        assert_eq!(ret_val, Outcome::Success as u32);
+        assert_eq!(
+            call_dapp_staking_ce::<Balance>(
+                &contract_id,
+                Command::StakedAmount,
+                (contract_id.clone(), smart_contract.clone()).encode()
+            ),
+            (Outcome::Success as u32, amount)
+        );
+
+        // Unstake
+        let (ret_val, _) = call_dapp_staking_ce::<()>(
+            &contract_id,
+            Command::Unstake,
+            (smart_contract.clone(), amount).encode(),
+        );
+        assert_eq!(ret_val, Outcome::Success as u32);
+        assert_eq!(
+            call_dapp_staking_ce::<Balance>(
+                &contract_id,
+                Command::StakedAmount,
+                (contract_id.clone(), smart_contract.clone()).encode()
+            ),
+            (Outcome::Success as u32, 0)
+        );
+
+        // Unlock
+        let (ret_val, _) =
+            call_dapp_staking_ce::<()>(&contract_id, Command::Unlock, amount.encode());
+        assert_eq!(ret_val, Outcome::Success as u32);
+        assert_eq!(
+            call_dapp_staking_ce::<Balance>(
+                &contract_id,
+                Command::LockedAmount,
+                contract_id.encode()
+            ),
+            (Outcome::Success as u32, 0)
+        );
+    });
+}
+
+#[test]
+fn claim_staker_rewards_works() {
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_wasm_contract(CE_PROXY);
+        let smart_contract = SmartContract::Wasm(contract_id.clone());
+        assert_ok!(DappStaking::register(
+            RuntimeOrigin::root(),
+            ALICE,
+            smart_contract.clone()
+        ));
+
+        let amount = 1000 * UNIT;
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            contract_id.clone().into(),
+            amount,
+        ));
+        let (ret_val, _) = call_dapp_staking_ce::<()>(&contract_id, Command::Lock, amount.encode());
+        assert_eq!(ret_val, Outcome::Success as u32);
+        let (ret_val, _) = call_dapp_staking_ce::<()>(
+            &contract_id,
+            Command::Stake,
+            (smart_contract.clone(), amount).encode(),
+        );
+        assert_eq!(ret_val, Outcome::Success as u32);
+
+        // Nothing to claim yet
+        let (ret_val, _) =
+            call_dapp_staking_ce::<()>(&contract_id, Command::ClaimStakerRewards, vec![]);
+        assert_eq!(ret_val, Outcome::NoClaimableRewards as u32);
+
+        // Generate some rewards
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_for_blocks(1);
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_for_blocks(1);
+
+        let init_balance = Balances::free_balance(&contract_id);
+        let (ret_val, _) =
+            call_dapp_staking_ce::<()>(&contract_id, Command::ClaimStakerRewards, vec![]);
+        assert_eq!(ret_val, Outcome::Success as u32);
+        assert!(Balances::free_balance(&contract_id) > init_balance);
+    });
+}
+
+#[test]
+fn dapp_staking_errors_are_mapped() {
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_wasm_contract(CE_PROXY);
+        let smart_contract = SmartContract::Wasm(contract_id.clone());
+
+        // Contract isn't registered
+        let (ret_val, _) = call_dapp_staking_ce::<()>(
+            &contract_id,
+            Command::Stake,
+            (smart_contract.clone(), 100 * UNIT).encode(),
+        );
+        assert_eq!(ret_val, Outcome::ContractNotFound as u32);
+
+        // Nothing to unlock
+        let (ret_val, _) =
+            call_dapp_staking_ce::<()>(&contract_id, Command::Unlock, (100 * UNIT).encode());
+        assert_eq!(ret_val, Outcome::ZeroAmount as u32);
+    });
+}
@@ -46,3 +46,6 @@ mod assets_chain_extensions;
 
 #[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
 mod oracle;
+
+#[cfg(feature = "shibuya")]
+mod dapp_staking_chain_extension;
@@ -18,8 +18,12 @@
 
 use crate::setup::*;
 
-use astar_primitives::oracle::{CurrencyAmount, PriceProvider};
-use pallet_price_aggregator::{IntermediateValueAggregator, ValueAggregator};
+use astar_primitives::{
+    dapp_staking::TierSlots,
+    oracle::{CurrencyAmount, PriceProvider},
+};
+use pallet_dapp_staking_v3::{ForcingType, TierConfig};
+use pallet_price_aggregator::{FrozenPrice, IntermediateValueAggregator, ValueAggregator};
 
 #[test]
 fn price_submission_works() {
@@ -30,16 +34,19 @@ fn price_submission_works() {
 
         // 0. Need to set limit block to something sensible, otherwise we'll waste time on many redundant iterations
         let limit_block = 10;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         // 1. Submit a price for a valid asset - the native currency
-        let price_1 = CurrencyAmount::from_rational(15, 100);
+        let price_1 = CurrencyAmount::from_rational(11, 100);
         assert_ok!(Oracle::feed_values(
             RuntimeOrigin::signed(ALICE.clone()),
             vec![(native_currency_id, price_1)].try_into().unwrap()
         ));
 
-        let price_2 = CurrencyAmount::from_rational(17, 100);
+        let price_2 = CurrencyAmount::from_rational(13, 100);
         assert_ok!(Oracle::feed_values(
             RuntimeOrigin::signed(BOB.clone()),
             vec![(native_currency_id, price_2)].try_into().unwrap()
@@ -50,7 +57,7 @@ fn price_submission_works() {
         run_for_blocks(1);
         let expected_average = (price_1 + price_2) * CurrencyAmount::from_rational(1, 2);
         assert_eq!(
-            IntermediateValueAggregator::<Runtime>::get().average(),
+            IntermediateValueAggregator::<Runtime>::get(native_currency_id).average(),
             expected_average
         );
 
@@ -83,9 +90,54 @@ fn price_submission_works() {
 
         // 5. Run until next limit block without any transactions, don't expect any changes
         let limit_block = limit_block * 2;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         run_to_block(limit_block + 1);
         assert_eq!(PriceAggregator::average_price(), expected_moving_average);
     })
 }
+
+#[test]
+fn static_price_is_used_for_tier_slots_once_feed_goes_stale() {
+    new_test_ext().execute_with(|| {
+        let native_currency_id =
+            <Runtime as pallet_price_aggregator::Config>::NativeCurrencyId::get();
+        type NativePriceProvider = <Runtime as pallet_dapp_staking_v3::Config>::NativePriceProvider;
+        type RuntimeTierSlots = <Runtime as pallet_dapp_staking_v3::Config>::TierSlots;
+
+        let static_price = CurrencyAmount::from_rational(7, 10);
+        assert_ok!(StaticPriceProvider::force_set_price(
+            RuntimeOrigin::root(),
+            static_price
+        ));
+
+        // 1. Oracle price is used while the feed is fresh
+        assert_eq!(NativePriceProvider::average_price(), INIT_PRICE);
+
+        // 2. No valid values are received for too long, circuit breaker is tripped
+        let stale_feed_threshold =
+            <Runtime as pallet_price_aggregator::Config>::StaleFeedThreshold::get();
+        run_for_blocks(stale_feed_threshold + 1);
+        assert_eq!(
+            FrozenPrice::<Runtime>::get(native_currency_id),
+            Some(INIT_PRICE)
+        );
+        assert_eq!(NativePriceProvider::average_price(), static_price);
+
+        // 3. Tier slots for the next era are calculated using the static price
+        assert_ok!(DappStaking::force(RuntimeOrigin::root(), ForcingType::Era));
+        run_for_blocks(1);
+        assert_eq!(
+            TierConfig::<Runtime>::get().number_of_slots,
+            RuntimeTierSlots::number_of_slots(static_price).max(1)
+        );
+        assert_ne!(
+            RuntimeTierSlots::number_of_slots(static_price),
+            RuntimeTierSlots::number_of_slots(INIT_PRICE),
+            "Sanity check, prices must result in a different number of slots."
+        );
+    })
+}
@@ -23,6 +23,23 @@ use sp_io::hashing::keccak_256;
 
 const AU_CE_GETTER: &'static str = "au_ce_getters";
 
+/*
+
+Mock EIP-1271 smart contract wallet, which accepts the signed hash itself as the signature,
+i.e. `isValidSignature(hash, signature)` returns the magic value `0x1626ba7e` if
+`signature == abi.encodePacked(hash)`, hand-written in EVM assembly:
+
+    PUSH1 0x64 CALLDATALOAD PUSH1 0x04 CALLDATALOAD EQ      // signature[0..32] == hash
+    PUSH1 0x44 CALLDATALOAD PUSH1 0x20 EQ AND               // signature.length == 32
+    PUSH1 0x16 JUMPI
+    PUSH1 0x20 PUSH1 0x00 RETURN                            // return bytes4(0)
+    JUMPDEST
+    PUSH4 0x1626ba7e PUSH1 0xe0 SHL PUSH1 0x00 MSTORE
+    PUSH1 0x20 PUSH1 0x00 RETURN                            // return magic value
+
+ */
+const MOCK_SMART_WALLET: &str = "602780600b6000396000f3606435600435146044356020141660165760206000f35b631626ba7e60e01b60005260206000f3";
+
 #[test]
 fn transfer_to_h160_via_lookup() {
     new_test_ext().execute_with(|| {
@@ -117,3 +134,109 @@ fn unified_accounts_chain_extension_works() {
         );
     });
 }
+
+#[test]
+fn claim_smart_contract_wallet_address_works() {
+    new_test_ext().execute_with(|| {
+        // alith is used for deploying contracts
+        connect_accounts(&ALICE, &alith_secret_key());
+        let wallet = deploy_evm_contract(MOCK_SMART_WALLET);
+
+        // funds held by the default account of wallet
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            MultiAddress::Address20(wallet.into()),
+            UNIT,
+        ));
+
+        // signature rejected by the wallet
+        assert_noop!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(BOB),
+                wallet,
+                UnifiedAccounts::build_signing_payload(&ALICE)
+                    .to_vec()
+                    .try_into()
+                    .unwrap(),
+            )
+            .map_err(|e| e.error),
+            pallet_unified_accounts::Error::<Runtime>::InvalidSignature
+        );
+
+        let bob_balance = Balances::free_balance(&BOB);
+        assert_ok!(UnifiedAccounts::claim_contract_evm_address(
+            RuntimeOrigin::signed(BOB),
+            wallet,
+            UnifiedAccounts::build_signing_payload(&BOB)
+                .to_vec()
+                .try_into()
+                .unwrap(),
+        ));
+
+        // wallet is mapped to BOB, and funds of the default account are transferred
+        assert_eq!(UnifiedAccounts::to_account_id(&wallet), Some(BOB));
+        assert_eq!(UnifiedAccounts::to_h160(&BOB), Some(wallet));
+        assert_eq!(
+            Balances::free_balance(&BOB),
+            bob_balance + UNIT - AccountMappingStorageFee::get()
+        );
+    });
+}
+
+#[test]
+fn sweep_default_account_after_claim_works() {
+    new_test_ext().execute_with(|| {
+        let asset_id = 19;
+        let default_account_id = account_id_from(alith());
+        let lock_amount = <Runtime as pallet_dapp_staking_v3::Config>::MinimumLockedAmount::get();
+
+        // native balance, assets and dApp staking locks held by the default account
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            MultiAddress::Address20(alith().into()),
+            lock_amount + UNIT,
+        ));
+        assert_ok!(DappStaking::lock(
+            RuntimeOrigin::signed(default_account_id.clone()),
+            lock_amount
+        ));
+        assert_ok!(Assets::create(
+            RuntimeOrigin::signed(ALICE),
+            asset_id.into(),
+            ALICE.into(),
+            1,
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(ALICE),
+            asset_id.into(),
+            default_account_id.clone().into(),
+            1_000,
+        ));
+
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        let alice_balance = Balances::total_balance(&ALICE);
+        let default_balance = Balances::total_balance(&default_account_id);
+        assert_ok!(UnifiedAccounts::sweep_default_account(
+            RuntimeOrigin::signed(ALICE),
+            vec![asset_id].try_into().unwrap(),
+            true,
+        ));
+
+        // everything is moved to ALICE, including the locks
+        assert_eq!(Balances::total_balance(&default_account_id), 0);
+        assert_eq!(
+            Balances::total_balance(&ALICE),
+            alice_balance + default_balance
+        );
+        assert_eq!(Assets::balance(asset_id, &default_account_id), 0);
+        assert_eq!(Assets::balance(asset_id, &ALICE), 1_000);
+        assert!(!pallet_dapp_staking_v3::Ledger::<Runtime>::contains_key(
+            &default_account_id
+        ));
+        assert_eq!(
+            pallet_dapp_staking_v3::Ledger::<Runtime>::get(&ALICE).active_locked_amount(),
+            lock_amount
+        );
+    });
+}
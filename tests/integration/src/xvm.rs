@@ -367,30 +367,158 @@ fn calling_evm_payable_from_wasm_works() {
     });
 }
 
+// Build the input of WASM `call_xvm_payable` message.
+fn wasm_call_xvm_payable_input(target: H160, input: Vec<u8>) -> Vec<u8> {
+    hex::decode("0000002a")
+        .expect("invalid selector hex")
+        .iter()
+        .chain(target.as_ref().to_vec().encode().iter())
+        .chain(input.encode().iter())
+        .cloned()
+        .collect::<Vec<_>>()
+}
+
+// Build the input of EVM `call_xvm_payable` function.
+fn evm_call_xvm_payable_input(to: &AccountId32, input: Vec<u8>, value: Balance) -> Vec<u8> {
+    solidity::encode_with_selector(
+        0x4012b914,
+        (
+            UnboundedBytes::from(to.encode()),
+            UnboundedBytes::from(input),
+            U256::from(value),
+        ),
+    )
+}
+
+// Build the input of WASM `call_xvm_payable` message for call path
+// `WASM -> EVM -> WASM -> ... -> EVM -> WASM payable`, which nests `2 * rounds` XVM calls.
+fn nested_xvm_call_input(
+    rounds: u32,
+    wasm_caller: &AccountId32,
+    evm_caller: H160,
+    wasm_payable: &AccountId32,
+    value: Balance,
+) -> Vec<u8> {
+    let deposit_func = hex::decode("0000002a").expect("invalid selector hex");
+    let mut input = wasm_call_xvm_payable_input(
+        evm_caller,
+        evm_call_xvm_payable_input(wasm_payable, deposit_func, value),
+    );
+    for _ in 1..rounds {
+        input = wasm_call_xvm_payable_input(
+            evm_caller,
+            evm_call_xvm_payable_input(wasm_caller, input, 0),
+        );
+    }
+    input
+}
+
+// Unwrap the revert data of nested XVM calls, returning the innermost failure message.
+//
+// On XVM call revert, WASM callers revert with the debug message of the failure, and EVM
+// callers revert with `Error(string)` of the failure, in which the revert data of the callee
+// is formatted as `VmRevert([..])` bytes.
+fn innermost_xvm_failure_message(data: Vec<u8>) -> String {
+    let evm_error_selector = hex::decode("08c379a0").expect("invalid selector hex");
+    let mut data = data;
+    loop {
+        if data.starts_with(&evm_error_selector) {
+            data = solidity::decode_arguments::<UnboundedBytes>(&data[4..])
+                .expect("invalid evm revert data")
+                .into();
+        }
+        let message = String::from_utf8(data).expect("invalid utf8");
+        match message.split_once("VmRevert([") {
+            Some((_, rest)) => {
+                let (bytes, _) = rest.split_once("])").expect("invalid revert data");
+                data = bytes
+                    .split(", ")
+                    .map(|b| b.parse::<u8>().expect("invalid revert data byte"))
+                    .collect();
+            }
+            None => return message,
+        }
+    }
+}
+
 #[test]
-fn reentrance_not_allowed() {
+fn nested_xvm_calls_work() {
     new_test_ext().execute_with(|| {
         // create account mappings
         connect_accounts(&ALICE, &alith_secret_key());
 
-        // Call path: WASM -> EVM -> WASM
+        // Call path: WASM -> EVM -> WASM -> ... -> EVM -> WASM payable, with max call depth.
         let wasm_caller_addr = deploy_wasm_contract(CALL_EVM_PAYBLE_NAME);
         let evm_caller_addr = deploy_evm_contract(CALL_WASM_PAYBLE);
-        let _ = deploy_wasm_contract(WASM_PAYABLE_NAME);
+        let wasm_payable_addr = deploy_wasm_contract(WASM_PAYABLE_NAME);
 
-        // to: 0xa8f69d59df362b69a8d4acdb9001eb3e1b8d067b8fdaa70081aed945bde5c48c
-        // input: 0x0000002a (deposit)
-        // value: 1000000000
-        let call_wasm_payable_input = hex::decode("4012b914000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000000000003b9aca000000000000000000000000000000000000000000000000000000000000000020a8f69d59df362b69a8d4acdb9001eb3e1b8d067b8fdaa70081aed945bde5c48c00000000000000000000000000000000000000000000000000000000000000040000002a00000000000000000000000000000000000000000000000000000000").expect("invalid call input hex");
-        let input = hex::decode("0000002a")
-            .expect("invalid selector hex")
-            .iter()
-            .chain(evm_caller_addr.as_ref().to_vec().encode().iter())
-            .chain(call_wasm_payable_input.encode().iter())
-            .cloned()
-            .collect::<Vec<_>>();
+        // fund the wasm contract address for paying storage fees for
+        // AU mappings.
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            wasm_caller_addr.clone().into(),
+            AccountMappingStorageFee::get()
+        ));
+        // fund the evm contract address for paying the value to wasm payable.
+        let value = 1_000_000_000;
+        let _ = Balances::deposit_creating(
+            &account_id_from(evm_caller_addr.clone()),
+            ExistentialDeposit::get() + value,
+        );
 
-        // assert `ReentranceDenied` error
+        let prev_wasm_payable_balance = Balances::free_balance(&wasm_payable_addr);
+        let input = nested_xvm_call_input(
+            XvmMaxCallDepth::get() / 2,
+            &wasm_caller_addr,
+            evm_caller_addr,
+            &wasm_payable_addr,
+            value,
+        );
+        let result = Contracts::bare_call(
+            ALICE,
+            wasm_caller_addr,
+            0,
+            Weight::from_parts(50_000_000_000, 10 * 1024 * 1024),
+            None,
+            input,
+            DebugInfo::Skip,
+            CollectEvents::Skip,
+            Determinism::Enforced,
+        );
+        match result.result {
+            Ok(ExecReturnValue { flags, .. }) => assert!(!flags.contains(ReturnFlags::REVERT)),
+            _ => panic!("unexpected wasm call result"),
+        }
+
+        let received = Balances::free_balance(&wasm_payable_addr) - prev_wasm_payable_balance;
+        assert_eq!(received, value);
+    });
+}
+
+#[test]
+fn nested_xvm_call_reverts_if_innermost_callee_reverted() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        // Call path: WASM -> EVM -> WASM dummy error
+        let wasm_caller_addr = deploy_wasm_contract(CALL_EVM_PAYBLE_NAME);
+        let evm_caller_addr = deploy_evm_contract(CALL_WASM_PAYBLE);
+        let wasm_callee_addr = deploy_wasm_contract(WASM_DUMMY_ERROR_NAME);
+
+        // fund the wasm contract address for paying storage fees for
+        // AU mappings.
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            wasm_caller_addr.clone().into(),
+            AccountMappingStorageFee::get()
+        ));
+
+        let do_revert_func = hex::decode("0000002a").expect("invalid selector hex");
+        let input = wasm_call_xvm_payable_input(
+            evm_caller_addr,
+            evm_call_xvm_payable_input(&wasm_callee_addr, do_revert_func, 0),
+        );
         let result = Contracts::bare_call(
             ALICE,
             wasm_caller_addr,
@@ -406,9 +534,67 @@ fn reentrance_not_allowed() {
             Ok(ExecReturnValue { flags, data }) => {
                 assert!(flags.contains(ReturnFlags::REVERT));
 
-                let reentrance_msg_error = evm_revert_message_error(&format!("{:?}", FailureError::ReentranceDenied));
+                // The last item `7` of `[0, 1, 7]` indicates the `DummyError` error index.
+                let revert_failure = FailureReason::Revert(FailureRevert::VmRevert(
+                    evm_revert_message_error("FailureRevert::VmRevert([0, 1, 7])"),
+                ));
                 let error_string = String::from_utf8(data).expect("invalid utf8");
-                assert!(error_string.contains(&format!("{:?}", reentrance_msg_error)));
+                assert!(error_string.contains(&format!("{:?}", revert_failure)));
+            }
+            _ => panic!("unexpected wasm call result"),
+        }
+    });
+}
+
+#[test]
+fn nested_xvm_call_fails_if_max_call_depth_exceeded() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        // Call path: WASM -> EVM -> WASM -> ... -> EVM -> WASM payable, exceeding max call depth.
+        let wasm_caller_addr = deploy_wasm_contract(CALL_EVM_PAYBLE_NAME);
+        let evm_caller_addr = deploy_evm_contract(CALL_WASM_PAYBLE);
+        let wasm_payable_addr = deploy_wasm_contract(WASM_PAYABLE_NAME);
+
+        // fund the wasm contract address for paying storage fees for
+        // AU mappings.
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            wasm_caller_addr.clone().into(),
+            AccountMappingStorageFee::get()
+        ));
+
+        let _ = Balances::deposit_creating(
+            &account_id_from(evm_caller_addr.clone()),
+            ExistentialDeposit::get(),
+        );
+
+        let input = nested_xvm_call_input(
+            XvmMaxCallDepth::get() / 2 + 1,
+            &wasm_caller_addr,
+            evm_caller_addr,
+            &wasm_payable_addr,
+            0,
+        );
+        let result = Contracts::bare_call(
+            ALICE,
+            wasm_caller_addr,
+            0,
+            Weight::from_parts(50_000_000_000, 10 * 1024 * 1024),
+            None,
+            input,
+            DebugInfo::Skip,
+            CollectEvents::Skip,
+            Determinism::Enforced,
+        );
+        match result.result {
+            Ok(ExecReturnValue { flags, data }) => {
+                assert!(flags.contains(ReturnFlags::REVERT));
+
+                let depth_limit_failure = FailureReason::Error(FailureError::CallDepthLimitReached);
+                assert!(innermost_xvm_failure_message(data)
+                    .contains(&format!("{:?}", depth_limit_failure)));
             }
             _ => panic!("unexpected wasm call result"),
         }
@@ -561,6 +747,107 @@ fn wasm_call_via_xvm_fails_if_revert() {
     });
 }
 
+#[test]
+fn xvm_call_value_is_refunded_if_callee_reverted() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        let evm_callee_addr = deploy_evm_contract(EVM_DUMMY_ERROR);
+        let wasm_callee_addr = deploy_wasm_contract(WASM_DUMMY_ERROR_NAME);
+        let alice_balance = Balances::free_balance(&ALICE);
+        let evm_callee_balance = Balances::free_balance(account_id_from(evm_callee_addr));
+        let wasm_callee_balance = Balances::free_balance(&wasm_callee_addr);
+
+        let value = UNIT;
+        assert!(Xvm::call(
+            Context {
+                source_vm_id: VmId::Wasm,
+                weight_limit: Weight::from_parts(1_000_000_000, 1024 * 1024),
+            },
+            VmId::Evm,
+            ALICE,
+            evm_callee_addr.as_ref().to_vec(),
+            // Calling `revert_with_err_msg`
+            hex::decode("28fd58ae").expect("invalid selector hex"),
+            value,
+            None,
+        )
+        .is_err());
+        assert!(Xvm::call(
+            Context {
+                source_vm_id: VmId::Evm,
+                weight_limit: Weight::from_parts(10_000_000_000, 1024 * 1024),
+            },
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.clone().encode(),
+            // Calling `do_revert`
+            hex::decode("0000002a").expect("invalid selector hex"),
+            value,
+            None,
+        )
+        .is_err());
+
+        assert_eq!(Balances::free_balance(&ALICE), alice_balance);
+        assert_eq!(
+            Balances::free_balance(account_id_from(evm_callee_addr)),
+            evm_callee_balance
+        );
+        assert_eq!(
+            Balances::free_balance(&wasm_callee_addr),
+            wasm_callee_balance
+        );
+    });
+}
+
+#[test]
+fn xvm_call_fails_if_value_transfer_rejected() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000_000, 1024 * 1024),
+        };
+        let target = H160::repeat_byte(0xAA);
+
+        // Caller cannot transfer its whole balance.
+        let result = Xvm::call(
+            context.clone(),
+            VmId::Evm,
+            ALICE,
+            target.as_ref().to_vec(),
+            vec![],
+            Balances::free_balance(&ALICE),
+            None,
+        );
+        assert_eq!(
+            result.map_err(|f| f.reason),
+            Err(FailureReason::Error(FailureError::InsufficientBalance))
+        );
+
+        // Callee account doesn't exist, value must be at least the existential deposit.
+        let result = Xvm::call(
+            context,
+            VmId::Evm,
+            ALICE,
+            target.as_ref().to_vec(),
+            vec![],
+            ExistentialDeposit::get() - 1,
+            None,
+        );
+        assert_eq!(
+            result.map_err(|f| f.reason),
+            Err(FailureReason::Error(
+                FailureError::ValueBelowExistentialDeposit
+            ))
+        );
+        assert_eq!(Balances::free_balance(account_id_from(target)), 0);
+    });
+}
+
 #[test]
 fn evm_caller_reverts_if_wasm_callee_reverted() {
     new_test_ext().execute_with(|| {
@@ -813,3 +1100,315 @@ fn calling_wasm_from_evm_works_if_sufficient_storage_deposit_limit() {
         }
     });
 }
+
+#[test]
+fn wasm_query_via_xvm_rolls_back_state_changes() {
+    new_test_ext().execute_with(|| {
+        let wasm_callee_addr = deploy_wasm_contract(WASM_SIMPLE_STORAGE_NAME);
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(10_000_000_000, 1024 * 1024),
+        };
+        let store_input = hex::decode("0000002a").expect("invalid selector hex");
+        let get_input = hex::decode("0000002b").expect("invalid selector hex");
+
+        // Storing via query is rolled back.
+        let events_len = System::events().len();
+        assert_ok!(Xvm::query(
+            context.clone(),
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.clone().encode(),
+            store_input.clone(),
+        ));
+        assert_eq!(System::events().len(), events_len);
+        let output = Xvm::query(
+            context.clone(),
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.clone().encode(),
+            get_input.clone(),
+        )
+        .expect("query failed")
+        .output;
+        assert_eq!(output[1], 0);
+
+        // Query reads the state stored via call.
+        assert_ok!(Xvm::call(
+            context.clone(),
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.clone().encode(),
+            store_input,
+            0,
+            None,
+        ));
+        let output = Xvm::query(
+            context,
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.encode(),
+            get_input,
+        )
+        .expect("query failed")
+        .output;
+        assert_eq!(output[1], 42);
+    });
+}
+
+#[test]
+fn wasm_estimate_call_via_xvm_matches_actual_call() {
+    new_test_ext().execute_with(|| {
+        let wasm_callee_addr = deploy_wasm_contract(WASM_SIMPLE_STORAGE_NAME);
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(10_000_000_000, 1024 * 1024),
+        };
+        let store_input = hex::decode("0000002a").expect("invalid selector hex");
+        let get_input = hex::decode("0000002b").expect("invalid selector hex");
+
+        // Dry-run is rolled back.
+        let (estimated, _) = Xvm::estimate_call(
+            context.clone(),
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.clone().encode(),
+            store_input.clone(),
+            0,
+            None,
+        );
+        let estimated = estimated.expect("estimate failed");
+        let output = Xvm::query(
+            context.clone(),
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.clone().encode(),
+            get_input,
+        )
+        .expect("query failed")
+        .output;
+        assert_eq!(output[1], 0);
+
+        // Estimated weight is the weight used by the actual call.
+        let actual = Xvm::call(
+            context,
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.encode(),
+            store_input,
+            0,
+            None,
+        )
+        .expect("call failed");
+        assert_eq!(estimated, actual);
+    });
+}
+
+#[test]
+fn evm_query_via_xvm_works() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        let evm_callee_addr = deploy_evm_contract(EVM_PAYABLE);
+
+        let events_len = System::events().len();
+        let output = Xvm::query(
+            Context {
+                source_vm_id: VmId::Wasm,
+                weight_limit: Weight::from_parts(1_000_000_000, 1024 * 1024),
+            },
+            VmId::Evm,
+            ALICE,
+            evm_callee_addr.as_ref().to_vec(),
+            // `owner` selector
+            hex::decode("8da5cb5b").expect("invalid selector hex"),
+        )
+        .expect("query failed")
+        .output;
+
+        // Returns the contract deployer, without any state changes.
+        assert_eq!(output[12..], alith().as_bytes()[..]);
+        assert_eq!(System::events().len(), events_len);
+    });
+}
+
+// Stores `42` in slot `0` on any call.
+//
+// Runtime code: PUSH1 0x2a PUSH1 0x00 SSTORE STOP
+const EVM_STORE: &str = "600680600b6000396000f3602a60005500";
+
+#[test]
+fn evm_query_via_xvm_fails_if_target_writes_state() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        let evm_callee_addr = deploy_evm_contract(EVM_STORE);
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000_000, 1024 * 1024),
+        };
+
+        // Query is a static call, storing fails.
+        let result = Xvm::query(
+            context.clone(),
+            VmId::Evm,
+            ALICE,
+            evm_callee_addr.as_ref().to_vec(),
+            vec![],
+        );
+        assert!(matches!(
+            result,
+            Err(CallFailure {
+                reason: FailureReason::Error(FailureError::VmError(_)),
+                ..
+            })
+        ));
+        assert_eq!(
+            pallet_evm::AccountStorages::<Runtime>::get(evm_callee_addr, H256::zero()),
+            H256::zero()
+        );
+
+        // Storing via call works.
+        assert_ok!(Xvm::call(
+            context,
+            VmId::Evm,
+            ALICE,
+            evm_callee_addr.as_ref().to_vec(),
+            vec![],
+            0,
+            None,
+        ));
+        assert_eq!(
+            pallet_evm::AccountStorages::<Runtime>::get(evm_callee_addr, H256::zero()),
+            H256::from_low_u64_be(42)
+        );
+    });
+}
+
+// Emits a contract event with the call input as data, built from `event_emitter.wat`.
+const WASM_EVENT_EMITTER_NAME: &'static str = "event_emitter";
+
+#[test]
+fn wasm_contract_events_are_propagated_via_xvm() {
+    new_test_ext().execute_with(|| {
+        let wasm_callee_addr = deploy_wasm_contract(WASM_EVENT_EMITTER_NAME);
+
+        let data = b"xvm event".to_vec();
+        assert_ok!(Xvm::call(
+            Context {
+                source_vm_id: VmId::Evm,
+                weight_limit: Weight::from_parts(10_000_000_000, 1024 * 1024),
+            },
+            VmId::Wasm,
+            ALICE,
+            wasm_callee_addr.clone().encode(),
+            data.clone(),
+            0,
+            None,
+        ));
+
+        assert_eq!(
+            System::events()
+                .iter()
+                .last()
+                .expect("no event found")
+                .event,
+            RuntimeEvent::Xvm(pallet_xvm::Event::WasmContractEmitted {
+                caller: ALICE,
+                target: wasm_callee_addr.clone(),
+                contract: wasm_callee_addr,
+                data,
+            }),
+        );
+    });
+}
+
+#[test]
+fn wasm_contract_events_of_nested_xvm_calls_are_propagated_once() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        // Call path: WASM -> EVM -> WASM -> EVM -> WASM event emitter
+        let wasm_caller_addr = deploy_wasm_contract(CALL_EVM_PAYBLE_NAME);
+        let evm_caller_addr = deploy_evm_contract(CALL_WASM_PAYBLE);
+        let wasm_callee_addr = deploy_wasm_contract(WASM_EVENT_EMITTER_NAME);
+
+        // fund the wasm contract address for paying storage fees for
+        // AU mappings.
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            wasm_caller_addr.clone().into(),
+            AccountMappingStorageFee::get()
+        ));
+
+        let input =
+            nested_xvm_call_input(2, &wasm_caller_addr, evm_caller_addr, &wasm_callee_addr, 0);
+        let result = Contracts::bare_call(
+            ALICE,
+            wasm_caller_addr,
+            0,
+            Weight::from_parts(50_000_000_000, 10 * 1024 * 1024),
+            None,
+            input,
+            DebugInfo::Skip,
+            CollectEvents::Skip,
+            Determinism::Enforced,
+        );
+        match result.result {
+            Ok(ExecReturnValue { flags, .. }) => assert!(!flags.contains(ReturnFlags::REVERT)),
+            _ => panic!("unexpected wasm call result"),
+        }
+
+        // Only re-emitted by the innermost XVM call, with its call context.
+        let propagated_events = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Xvm(event) => Some(event),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            propagated_events,
+            vec![pallet_xvm::Event::WasmContractEmitted {
+                caller: account_id_from(evm_caller_addr),
+                target: wasm_callee_addr.clone(),
+                contract: wasm_callee_addr,
+                data: hex::decode("0000002a").expect("invalid selector hex"),
+            }],
+        );
+    });
+}
+
+#[test]
+fn evm_call_via_xvm_is_recorded_in_ethereum_receipts() {
+    new_test_ext().execute_with(|| {
+        // create account mappings
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        let evm_callee_addr = deploy_evm_contract(EVM_PAYABLE);
+
+        assert_ok!(Xvm::call(
+            Context {
+                source_vm_id: VmId::Wasm,
+                weight_limit: Weight::from_parts(1_000_000_000, 1024 * 1024),
+            },
+            VmId::Evm,
+            ALICE,
+            evm_callee_addr.as_ref().to_vec(),
+            // `deposit` selector
+            hex::decode("d0e30db0").expect("invalid selector hex"),
+            0,
+            None,
+        ));
+
+        // Pending Ethereum transactions are included in the block's receipts on finalize,
+        // along with their logs.
+        let (_, status, _) = pallet_ethereum::Pending::<Runtime>::get()
+            .pop()
+            .expect("no pending ethereum transaction");
+        assert_eq!(status.to, Some(evm_callee_addr));
+    });
+}